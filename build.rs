@@ -1,27 +1,27 @@
-extern crate build_helper;
 extern crate gcc;
 extern crate pkg_config;
 
-use build_helper::Profile;
-use build_helper::cargo::manifest;
 use gcc::Build;
+use std::env;
+use std::fmt::Write as FmtWrite;
 use std::fs;
+use std::os::raw::c_int;
+use std::path::Path;
+
+// The vocabulary tables, which the C arrays in vocab.h are made from.
+#[path = "src/replacement/vocab.rs"]
+#[allow(dead_code)]
+mod vocab;
 
 fn main() {
-    // Copy the dtextc.dat file into the build directory.
-    let mut data_file = manifest::dir();
-    data_file.push("c_src/dtextc.dat");
-    let mut dest = manifest::dir();
-    match build_helper::profile() {
-        Profile::Debug => dest.push("target/debug/dtextc.dat"),
-        Profile::Release => dest.push("target/release/dtextc.dat"),
-    }
-    fs::copy(data_file, dest.clone()).unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let lengths = write_vocab_header(&Path::new(&out_dir).join("vocab.h"));
+    write_vocab_lengths(&Path::new(&out_dir).join("vocab_lengths.rs"), &lengths);
 
     // Compile and link the original C executable as a library.
     let paths = fs::read_dir("./c_src/").unwrap();
 
-    let c_files = paths.map(|entry| entry.unwrap().path()).filter(|ref p| {
+    let c_files = paths.map(|entry| entry.unwrap().path()).filter(|p| {
         if let Some(ext) = p.extension() {
             ext == "c"
         } else {
@@ -29,16 +29,14 @@ fn main() {
         }
     });
 
-    let mut dest_string = "\"".to_string();
-    dest_string.push_str(dest.to_str().unwrap());
-    dest_string.push_str("\"");
     Build::new()
         .files(c_files)
         .include("c_src")
+        .include(&out_dir)                // For the generated vocab.h
         .define("ALLOW_GDT", None)        // Enables the built-in debugger
         .define("MORE_TERMINFO", None)    // Sets the terminal-interaction lib to ncurses
-        .define("TEXTFILE", Some(dest_string.as_str()))
         .define("AS_RUST_LIB", None)
+        .flag("-fexceptions")             // exit_() unwinds through the C frames
         //.flag("-Werror=implicit-function-declaration")
         .flag("-Wno-parentheses")
         .flag("-Wno-unused-parameter")
@@ -50,3 +48,114 @@ fn main() {
     //println!("cargo:rustc-include-lib=dylib=ncurses");
     pkg_config::probe_library("ncurses").unwrap();
 }
+
+// Writes the vocabulary arrays that parse.h includes, laid out the way
+// parse.h used to have them: each word on its own line, with its numbers.
+// Returns the length of each array.
+fn write_vocab_header(path: &Path) -> [usize; 6] {
+    let mut header = String::new();
+    header.push_str("/* vocab.h -- the parser's vocabulary arrays.\n");
+    header.push_str(" *\n");
+    header.push_str(" * Generated by build.rs from src/replacement/vocab.rs. Don't edit it;\n");
+    header.push_str(" * change the tables in vocab.rs instead.\n");
+    header.push_str(" */\n\n");
+
+    let buzzwords = vocab::BUZZWORDS.iter()
+        .map(|buzzword| Entry::new(&[buzzword.name], |array| buzzword.encode(array)))
+        .chain(Some(Entry::end(&[0, 0])));
+    let prepositions = vocab::PREPOSITIONS.iter().flat_map(|preposition| {
+        preposition.names.iter().map(move |name| Entry::new(&[name], |array| {
+            array.extend(&vocab::table_tokens(name));
+            array.push(preposition.id);
+        }))
+    });
+    let directions = vocab::DIRECTIONS.iter()
+        .map(|direction| Entry::new(&[direction.name], |array| direction.encode(array)));
+    let adjectives = vocab::ADJECTIVES.iter()
+        .map(|adjective| Entry::new(&[adjective.name], |array| adjective.encode(array)))
+        .chain(Some(Entry::end(&[-1])));
+    let verbs = vocab::VERBS.iter()
+        .map(|verb| Entry::new(verb.names, |array| verb.encode(array)))
+        .chain(Some(Entry::end(&[-1])));
+    let objects = vocab::OBJECTS.iter()
+        .map(|object| Entry::new(&[object.name], |array| object.encode(array)))
+        .chain(Some(Entry::end(&[-1])));
+
+    let lengths = [
+        write_array(&mut header, "buzvoc_", Some("bvoc"), buzzwords),
+        write_array(&mut header, "prpvoc_", Some("pvoc"), prepositions),
+        write_array(&mut header, "dirvoc_", Some("dvoc"), directions),
+        write_array(&mut header, "avoc", None, adjectives),
+        write_array(&mut header, "vvoc", None, verbs),
+        write_array(&mut header, "ovoc", None, objects),
+    ];
+
+    fs::write(path, header).unwrap();
+    lengths
+}
+
+// Writes the lengths of the vocabulary arrays, in the order vocab.h has
+// them, as Rust constants for src/ffi to declare the arrays with.
+fn write_vocab_lengths(path: &Path, lengths: &[usize; 6]) {
+    let names = ["buzvoc_", "prpvoc_", "dirvoc_", "avoc", "vvoc", "ovoc"];
+    let mut source = String::from("// Generated by build.rs: the lengths of the arrays in vocab.h.\n");
+    for (name, length) in names.iter().zip(lengths) {
+        let name = name.trim_end_matches('_').to_uppercase();
+        source.push_str(&format!("const {}_LENGTH: usize = {};\n", name, length));
+    }
+    fs::write(path, source).unwrap();
+}
+
+// The numbers of one word (or verb) in an array, and the words they are for.
+struct Entry {
+    names: Vec<String>,
+    numbers: Vec<c_int>,
+}
+
+impl Entry {
+    fn new<F: FnOnce(&mut Vec<c_int>)>(names: &[&str], encode: F) -> Entry {
+        let mut numbers = Vec::new();
+        encode(&mut numbers);
+        Entry { names: names.iter().map(|name| name.to_uppercase()).collect(), numbers }
+    }
+
+    // The numbers that end an array.
+    fn end(numbers: &[c_int]) -> Entry {
+        Entry { names: Vec::new(), numbers: numbers.to_vec() }
+    }
+}
+
+// Writes an array, wrapped in a struct as `field` if it has one, and returns
+// its length.
+fn write_array<I>(header: &mut String, name: &str, field: Option<&str>, entries: I) -> usize
+    where I: Iterator<Item = Entry>
+{
+    let mut body = String::new();
+    let mut length = 0;
+    for entry in entries {
+        length += entry.numbers.len();
+
+        // Each word's tokens go on a line with the word, and the rest of the
+        // numbers on the next one.
+        let (tokens, rest) = entry.numbers.split_at(2 * entry.names.len());
+        for (pair, name) in tokens.chunks(2).zip(&entry.names) {
+            writeln!(body, "\t\t{}, {},\t// {}", pair[0], pair[1], name).unwrap();
+        }
+        if !rest.is_empty() {
+            let rest: Vec<String> = rest.iter().map(|number| number.to_string()).collect();
+            writeln!(body, "\t\t\t{},", rest.join(", ")).unwrap();
+        }
+    }
+
+    match field {
+        Some(field) => {
+            writeln!(header, "EXTERN const struct {{\n\tinteger {}[{}];\n}} {}", field, length,
+                     name).unwrap();
+        }
+        None => writeln!(header, "EXTERN const integer {}[]", name).unwrap(),
+    }
+    header.push_str("#ifdef INIT\n\t= {\n");
+    header.push_str(&body);
+    header.push_str("\t}\n#endif\n\t;\n\n");
+    length
+}
@@ -51,6 +51,11 @@ L100:
 
 #endif /* ALLOW_GDT */
 
+#ifdef AS_RUST_LIB
+    supp_command();
+/* 						!A COMMAND STARTS, FOR UNDO. */
+#endif /* AS_RUST_LIB */
+
     ++state_1.moves;
     prsvec_1.prswon = parse_(input_1.inbuf, 1);
     if (! prsvec_1.prswon) {
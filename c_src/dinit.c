@@ -106,6 +106,11 @@ logical init_()
     register integer ch;
     register FILE *indxfile;
     integer mmax, omax, rmax, vmax, amax, cmax, fmax, smax;
+    const char *textfile;
+#ifdef AS_RUST_LIB
+    const char *textdata;
+    integer textsize;
+#endif
 
     more_init();
 
@@ -335,11 +340,31 @@ L10000:
 /* NOW RESTORE FROM EXISTING INDEX FILE. */
 
 #ifdef __AMOS__
+    textfile = TEXTFILE;
     if ((dbfile = fdopen(ropen(LOCALTEXTFILE, 0), BINREAD)) == NULL &&
 	(dbfile = fdopen(ropen(TEXTFILE, 0), BINREAD)) == NULL)
 #else
+#ifdef AS_RUST_LIB
+    if (dbfile != NULL) {
+	(void) fclose(dbfile);
+	dbfile = NULL;
+    }
+/* 						!USE THE FILE GIVEN BY THE USER, */
+/* 						!ELSE THE COPY BUILT INTO THE GAME. */
+    if (supp_textfile() != NULL) {
+	textfile = supp_textfile();
+	dbfile = fopen(textfile, BINREAD);
+    } else {
+	textfile = "the built-in dtextc.dat";
+	textdata = supp_textdata(&textsize);
+	dbfile = fmemopen((void *) textdata, (size_t) textsize, BINREAD);
+    }
+    if (dbfile == NULL)
+#else
+    textfile = TEXTFILE;
     if ((dbfile = fopen(LOCALTEXTFILE, BINREAD)) == NULL &&
 	(dbfile = fopen(TEXTFILE, BINREAD)) == NULL)
+#endif
 #endif
 	goto L1950;
 
@@ -441,14 +466,14 @@ L10000:
 
 L1925:
     more_output(NULL);
-    supp_printf("%s is version %1d.%1d%c.\n", TEXTFILE, i, j, k);
+    supp_printf("%s is version %1d.%1d%c.\n", textfile, i, j, k);
     more_output(NULL);
     supp_printf("I require version %1d.%1d%c.\n", vers_1.vmaj, vers_1.vmin,
 	   vers_1.vedit);
     goto L1975;
 L1950:
     more_output(NULL);
-    supp_printf("I can't open %s.\n", TEXTFILE);
+    supp_printf("I can't open %s.\n", textfile);
 L1975:
     more_output("Suddenly a sinister, wraithlike figure appears before you,");
     more_output("seeming to float in the air.  In a low, sorrowful voice he says,");
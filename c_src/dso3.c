@@ -174,8 +174,12 @@ integer n;
 L100:
     rspeak_(q);
 /* 						!ASK */
+#ifdef AS_RUST_LIB
+    supp_fgets(ans, sizeof ans);
+#else /* ! AS_RUST_LIB */
     (void) fflush(stdout);
     (void) fgets(ans, sizeof ans, stdin);
+#endif /* AS_RUST_LIB */
     more_input();
 /* 						!GET ANSWER */
     if (*ans == 'Y' || *ans == 'y') {
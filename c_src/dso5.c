@@ -5,6 +5,7 @@
 #include "funcs.h"
 #include "vars.h"
 
+#ifndef AS_RUST_LIB
 /* GTTIME-- GET TOTAL TIME PLAYED */
 
 void gttime_(t)
@@ -20,6 +21,7 @@ integer *t;
     *t += time_1.pltime;
     return;
 } /* gttime_ */
+#endif
 
 /* OPNCLS-- PROCESS OPEN/CLOSE FOR DOORS */
 
@@ -14,6 +14,7 @@
 
 extern FILE *dbfile;
 
+#ifndef AS_RUST_LIB
 static void rspsb2nl_ P((integer, integer, integer, logical));
 
 /* RSPEAK-- OUTPUT RANDOM MESSAGE ROUTINE */
@@ -127,6 +128,7 @@ logical nl;
     if (nl)
 	supp_putchar('\n');
 }
+#endif /* !AS_RUST_LIB */
 
 /* OBJACT-- APPLY OBJECTS FROM PARSE VECTOR */
 
@@ -431,6 +433,7 @@ L1100:
     score_(0);
 /* 						!TELL SCORE. */
     (void) fclose(dbfile);
+    dbfile = NULL;
     exit_();
 
 } /* jigsup_ */
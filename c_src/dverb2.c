@@ -12,6 +12,9 @@
 
 static integer cxappl_ P((integer));
 
+/* The Rust library saves and restores games itself. */
+#ifndef AS_RUST_LIB
+
 void savegm_()
 {
     /* Local variables */
@@ -194,6 +197,8 @@ L200:
     (void)fclose(e);
 } /* rstrgm_ */
 
+#endif /* !AS_RUST_LIB */
+
 /* WALK- MOVE IN SPECIFIED DIRECTION */
 
 /* DECLARATIONS */
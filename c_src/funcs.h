@@ -51,6 +51,16 @@ extern
 #endif
 void supp_putchar(char c);
 
+#ifdef AS_RUST_LIB
+extern void supp_write P((const char *));
+extern const char *supp_translate P((const char *));
+extern void supp_fgets P((char *, integer));
+extern void supp_command P((void));
+extern logical c_run_game P((void));
+extern const char *supp_textfile P((void));
+extern const char *supp_textdata P((integer *));
+#endif /* AS_RUST_LIB */
+
 extern logical
 	protected P((void)),
 	wizard P((void));
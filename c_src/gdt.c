@@ -12,6 +12,14 @@
 
 #ifdef ALLOW_GDT
 
+/* READ A LINE FROM THE PLAYER INTO BUF. */
+
+#ifdef AS_RUST_LIB
+#define gdt_getline(buf) supp_fgets((buf), sizeof (buf))
+#else
+#define gdt_getline(buf) ((void) fflush(stdout), (void) fgets((buf), sizeof (buf), stdin))
+#endif
+
 void gdt_()
 {
     /* Initialized data */
@@ -54,8 +62,7 @@ void gdt_()
 L2000:
     supp_printf("GDT>");
 /* 						!OUTPUT PROMPT. */
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     cmd[0] = ' ';
     cmd[1] = ' ';
@@ -103,8 +110,7 @@ L2300:
 L2700:
     supp_printf("Idx,Ary:  ");
 /* 						!TYPE 3, REQUEST ARRAY COORDS. */
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     for (z = buf; *z != '\0'; z++)
 	if (*z == ',')
@@ -117,8 +123,7 @@ L2700:
 L2600:
     supp_printf("Limits:   ");
 /* 						!TYPE 2, READ BOUNDS. */
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     for (z = buf; *z != '\0'; z++)
 	if (*z == ',')
@@ -134,8 +139,7 @@ L2600:
 L2500:
     supp_printf("Entry:    ");
 /* 						!TYPE 1, READ ENTRY NO. */
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     j = 0;
     sscanf(buf, "%d", &j);
@@ -401,8 +405,7 @@ L20000:
 /* 						!ENTRY NO VALID? */
     supp_printf("Old= %c      New= ", flags[j - 1] ? 'T' : 'F');
 /* 						!TYPE OLD, GET NEW. */
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     for (z = buf; *z != '\0'; z++) {
 	if (! isspace(*z)) {
@@ -538,8 +541,7 @@ L32000:
 /* 						!INDICES VALID? */
     supp_printf("Old = %6d      New = ", eqr[j + k * 200 - 201]);
 /* 						!TYPE OLD, GET NEW. */
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &eqr[j + k * 200 - 201]);
     goto L2000;
@@ -553,8 +555,7 @@ L33000:
     }
 /* 						!INDICES VALID? */
     supp_printf("Old = %6d      New = ", eqo[j + k * 200 - 201]);
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &eqo[j + k * 220 - 221]);
     goto L2000;
@@ -567,8 +568,7 @@ L34000:
     }
 /* 						!INDICES VALID? */
     supp_printf("Old = %6d      New = ", eqa[j + (k << 2) - 5]);
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &eqa[j + (k << 2) - 5]);
     goto L2000;
@@ -585,15 +585,13 @@ L35000:
     }
 /* 						!FLAGS ENTRY? */
     supp_printf("Old = %6d      New = ", eqc[j + k * 25 - 26]);
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &eqc[j + k * 25 - 26]);
     goto L2000;
 
 L35500:
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     for (z = buf; *z != '\0'; z++) {
 	if (! isspace(*z)) {
@@ -615,8 +613,7 @@ L36000:
     }
 /* 						!ENTRY NO VALID? */
     supp_printf("Old= %6d     New= ", exits_1.travel[j - 1]);
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &exits_1.travel[j - 1]);
     goto L2000;
@@ -630,8 +627,7 @@ L37000:
     }
 /* 						!INDICES VALID? */
     supp_printf("Old = %6d      New= ", eqv[j + (k << 2) - 5]);
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &eqv[j + (k << 2) - 5]);
     goto L2000;
@@ -677,8 +673,7 @@ L40000:
     }
 /* 						!VALID ENTRY? */
     supp_printf("Old= %6d      New= ", switch_[j - 1]);
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &switch_[j - 1]);
     goto L2000;
@@ -717,8 +712,7 @@ L42000:
 
 L43000:
     supp_printf("Old= %6d      New= ", play_1.here);
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &play_1.here);
     eqa[0] = play_1.here;
@@ -747,8 +741,7 @@ L44000:
 L45000:
     supp_printf("Old= %6d      New= ", debug_1.prsflg);
 /* 						!TYPE OLD, GET NEW. */
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &debug_1.prsflg);
     goto L2000;
@@ -777,8 +770,7 @@ L47000:
 /* 						!VALID ENTRY? */
     supp_printf("Old= %6d      New= ", puzzle_1.cpvec[j - 1]);
 /* 						!OUTPUT OLD, */
-    (void) fflush(stdout);
-    (void) fgets(buf, sizeof buf, stdin);
+    gdt_getline(buf);
     more_input();
     sscanf(buf, "%d", &puzzle_1.cpvec[j - 1]);
     goto L2000;
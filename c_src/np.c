@@ -13,11 +13,11 @@
 
 extern int system P((const char *));
 
-//#ifdef AS_RUST_LIB
-//extern
-//#else
+#ifdef AS_RUST_LIB
+extern
+#else
 static
-//#endif /* AS_RUST_LIB */
+#endif /* AS_RUST_LIB */
 logical lex_ P((char *, integer *, integer *, logical));
 
 
@@ -159,6 +159,12 @@ integer o5;
 
 /* THIS ROUTINE DETAILS ON BIT 1 OF PRSFLAG */
 //#ifndef AS_RUST_LIB
+#ifdef AS_RUST_LIB
+/* lex_ is defined in Rust.  This one is kept as c_lex_, so the two can be
+ * compared.
+ */
+#define lex_ c_lex_
+#endif
 logical lex_(inbuf, outbuf, op, vbflag)
 char *inbuf;
 integer *outbuf;
@@ -301,4 +307,7 @@ L6000:
 /* 						!YES, ADV OP. */
 
 } /* lex_ */
+#ifdef AS_RUST_LIB
+#undef lex_
+#endif
 //#endif /* ! AS_RUST_LIB */
@@ -15,6 +15,12 @@
 
 /* THIS ROUTINE DETAILS ON BIT 2 OF PRSFLG */
 
+#ifdef AS_RUST_LIB
+/* sparse_ is defined in Rust.  This one is kept as c_sparse_, so the two can
+ * be compared.
+ */
+#define sparse_ c_sparse_
+#endif
 integer sparse_(lbuf, llnt, vbflag)
 const integer *lbuf;
 integer llnt;
@@ -413,3 +419,5 @@ L12000:
     goto L1750;
 
 } /* sparse_ */
+
+#undef sparse_
@@ -15,6 +15,12 @@ static logical takeit_ P((integer, integer));
 
 /* THIS ROUTINE DETAILS ON BIT 4 OF PRSFLG */
 
+#ifdef AS_RUST_LIB
+/* synmch_ is defined in Rust.  This one is kept as c_synmch_, so the two can
+ * be compared.
+ */
+#define synmch_ c_synmch_
+#endif
 logical synmch_()
 {
     /* Initialized data */
@@ -202,6 +208,8 @@ L5000:
 
 } /* synmch_ */
 
+#undef synmch_
+
 /* UNPACK-	UNPACK SYNTAX SPECIFICATION, ADV POINTER */
 
 /* DECLARATIONS */
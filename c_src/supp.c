@@ -29,6 +29,34 @@ extern int rand P((void));
 extern time_t time P((time_t *));
 extern struct tm *localtime ();
 
+#ifdef AS_RUST_LIB
+// Wrapper for printf. The format is first swapped for its translation, if
+// there is one, and the formatted text is handed to supp_write. Both are
+// defined in Rust.
+void supp_printf(const char* fmt, ...) {
+	char buf[1024];
+	va_list args;
+	va_start(args, fmt);
+	vsnprintf(buf, sizeof buf, supp_translate(fmt), args);
+	va_end(args);
+
+	supp_write(buf);
+}
+
+/* Initialize and play the game until it ends.  Instead of terminating
+ * the process, exit_ unwinds back through here to the Rust code that
+ * called it, so this file is built with -fexceptions.  Returns FALSE_
+ * if initialization failed.
+ */
+
+logical c_run_game()
+{
+    if (! init_())
+	return FALSE_;
+    game_();
+    return TRUE_;
+}
+#else /* ! AS_RUST_LIB */
 // Wrapper for printf. It will flush stdout every call.
 void supp_printf(const char* fmt, ...) {
 	va_list args;
@@ -39,7 +67,6 @@ void supp_printf(const char* fmt, ...) {
 	fflush(stdout);
 }
 
-#ifndef AS_RUST_LIB
 void supp_putchar(char c) {
 	putchar(c);
 	fflush(stdout);
@@ -55,6 +82,7 @@ void exit_()
 }
 #endif /* AS_RUST_LIB */
 
+#ifndef AS_RUST_LIB
 /* Get time in hours, minutes and seconds */
 
 void itime_(hrptr, minptr, secptr)
@@ -80,6 +108,7 @@ integer maxval;
 {
 	return rand() % maxval;
 }
+#endif
 
 /* Terminal support routines for dungeon */
 /* By Ian Lance Taylor ian@airs.com or uunet!airs!ian */
@@ -0,0 +1,10 @@
+// What the command-line tools share.
+
+use std::process;
+
+// Reports a bad command line, or something the tool couldn't do, and exits.
+pub fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    eprintln!("Try --help for more information.");
+    process::exit(2)
+}
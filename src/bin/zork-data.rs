@@ -0,0 +1,99 @@
+extern crate getopts;
+extern crate zork;
+
+mod common;
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use getopts::{Matches, Options};
+use zork::datafile::DataFile;
+use zork::files;
+use common::fail;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let opts = options();
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(err) => fail(&err.to_string()),
+    };
+
+    if matches.opt_present("help") {
+        let brief = format!("Usage: {0} decompile [options]\n       {0} compile SOURCE -o FILE", args[0]);
+        print!("{}", opts.usage(&brief));
+        return;
+    }
+
+    match matches.free.first().map(String::as_str) {
+        Some("decompile") => decompile(&matches),
+        Some("compile") => compile(&matches),
+        Some(command) => fail(&format!("Unknown command: {}", command)),
+        None => fail("Missing command: decompile or compile"),
+    }
+}
+
+// Writes the data file as TOML.
+fn decompile(matches: &Matches) {
+    if matches.free.len() > 1 {
+        fail(&format!("Unexpected argument: {}", matches.free[1]));
+    }
+    let data = match matches.opt_str("data") {
+        Some(path) => match fs::read(&path) {
+            Ok(data) => data,
+            Err(err) => fail(&format!("Unable to read {}: {}", path, err)),
+        },
+        None => files::BUILT_IN_DATA.to_vec(),
+    };
+    let file = match DataFile::read(&data) {
+        Ok(file) => file,
+        Err(err) => fail(&err.to_string()),
+    };
+
+    let text = file.to_toml();
+    let written = match matches.opt_str("output") {
+        Some(path) => fs::write(&path, text),
+        None => io::stdout().write_all(text.as_bytes()),
+    };
+    if let Err(err) = written {
+        fail(&format!("Unable to write the source: {}", err));
+    }
+}
+
+// Compiles TOML source into a data file.
+fn compile(matches: &Matches) {
+    let source = match matches.free.len() {
+        2 => &matches.free[1],
+        1 => fail("Missing the source file to compile"),
+        _ => fail(&format!("Unexpected argument: {}", matches.free[2])),
+    };
+    let output = match matches.opt_str("output") {
+        Some(output) => output,
+        None => fail("Missing the file to write (-o FILE)"),
+    };
+    if matches.opt_present("data") {
+        fail("--data is only for decompile");
+    }
+
+    let text = match fs::read_to_string(source) {
+        Ok(text) => text,
+        Err(err) => fail(&format!("Unable to read {}: {}", source, err)),
+    };
+    let data = match DataFile::from_toml(&text).and_then(|file| file.write()) {
+        Ok(data) => data,
+        Err(err) => fail(&format!("{}: {}", source, err)),
+    };
+    if let Err(err) = fs::write(&output, data) {
+        fail(&format!("Unable to write {}: {}", output, err));
+    }
+}
+
+fn options() -> Options {
+    let mut opts = Options::new();
+    opts.optopt("o", "output", "write to FILE (the source from decompile goes \
+                 to stdout otherwise)", "FILE");
+    opts.optopt("", "data", "decompile FILE instead of the built-in \
+                 dtextc.dat", "FILE");
+    opts.optflag("h", "help", "print this help and exit");
+    opts
+}
@@ -0,0 +1,68 @@
+extern crate getopts;
+extern crate zork;
+
+mod common;
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use getopts::Options;
+use zork::files;
+use zork::map::Map;
+use zork::Game;
+use common::fail;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let opts = options();
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(err) => fail(&err.to_string()),
+    };
+
+    if matches.opt_present("help") {
+        print!("{}", opts.usage(&format!("Usage: {} [options]", args[0])));
+        return;
+    }
+    if !matches.free.is_empty() {
+        fail(&format!("Unexpected argument: {}", matches.free[0]));
+    }
+
+    let format = matches.opt_str("format").unwrap_or_else(|| "dot".to_string());
+    if format != "dot" && format != "json" {
+        fail(&format!("Not a valid format: {}", format));
+    }
+    if let Some(path) = matches.opt_str("data") {
+        files::set_data_file(Some(path));
+    }
+
+    let game = match Game::new() {
+        Ok(game) => game,
+        Err(err) => fail(&err.to_string()),
+    };
+    let map = match Map::build() {
+        Ok(map) => map,
+        Err(err) => fail(&err.to_string()),
+    };
+    drop(game);
+
+    let text = if format == "json" { map.to_json() } else { map.to_dot() };
+    let written = match matches.opt_str("output") {
+        Some(path) => fs::write(&path, text),
+        None => io::stdout().write_all(text.as_bytes()),
+    };
+    if let Err(err) = written {
+        fail(&format!("Unable to write the map: {}", err));
+    }
+}
+
+fn options() -> Options {
+    let mut opts = Options::new();
+    opts.optopt("", "format", "write the map as dot (Graphviz) or json \
+                 (default: dot)", "FORMAT");
+    opts.optopt("o", "output", "write the map to FILE instead of stdout", "FILE");
+    opts.optopt("", "data", "read the game text from FILE instead of the \
+                 built-in dtextc.dat", "FILE");
+    opts.optflag("h", "help", "print this help and exit");
+    opts
+}
@@ -0,0 +1,87 @@
+extern crate getopts;
+extern crate zork;
+
+mod common;
+
+use std::env;
+use getopts::Options;
+use zork::lexicon::{self, WordKind};
+use zork::replacement::vocab;
+use common::fail;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let opts = options();
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(err) => fail(&err.to_string()),
+    };
+
+    if matches.opt_present("help") {
+        let brief = format!("Usage: {} [options] [SECTION...]\n\n\
+                             Lists the parser's vocabulary. The sections are {};\n\
+                             without any, all of them are listed.", args[0],
+                            WordKind::ALL.iter().map(|kind| kind.section())
+                                .collect::<Vec<_>>().join(", "));
+        print!("{}", opts.usage(&brief));
+        return;
+    }
+
+    let mut kinds = Vec::new();
+    for section in &matches.free {
+        match WordKind::from_section(section) {
+            Some(kind) => kinds.push(kind),
+            None => fail(&format!("Not a section: {}", section)),
+        }
+    }
+
+    // Each thing asked for is a block of text, with a blank line between them.
+    let mut blocks = Vec::new();
+    for tokens in matches.opt_strs("decode") {
+        let tokens: Vec<_> = tokens.split(',').map(|token| token.trim().parse()).collect();
+        match tokens[..] {
+            [Ok(first), Ok(second)] => blocks.push(format!("{}\n", vocab::decode([first, second]))),
+            _ => fail("Not a pair of tokens: expected two numbers, like 26335,5005"),
+        }
+    }
+    for word in matches.opt_strs("search") {
+        match lexicon::lookup(&word) {
+            Some(lookup) => blocks.push(lookup.to_string()),
+            None => fail(&format!("Not a word the parser can read: {}", word)),
+        }
+    }
+    if matches.opt_present("collisions") {
+        let mut block = String::from("Words the parser can't tell apart, in the order it \
+                                      tries them:\n");
+        for meanings in lexicon::collisions() {
+            let words: Vec<String> = meanings.iter()
+                .map(|meaning| format!("{} {}", meaning.kind, meaning.word))
+                .collect();
+            block.push_str(&format!("    {}\n", words.join(", ")));
+        }
+        blocks.push(block);
+    }
+
+    // The sections are listed if they're asked for, or nothing else was.
+    if kinds.is_empty() && blocks.is_empty() {
+        kinds.extend(WordKind::ALL.iter().cloned());
+    }
+    for kind in kinds {
+        let section = kind.section();
+        blocks.push(format!("{}{}:\n{}", section[..1].to_uppercase(), &section[1..],
+                            lexicon::listing(kind)));
+    }
+    print!("{}", blocks.join("\n"));
+}
+
+fn options() -> Options {
+    let mut opts = Options::new();
+    opts.optmulti("s", "search", "show what the parser makes of WORD, and the words \
+                  it can't tell apart from it", "WORD");
+    opts.optflag("", "collisions", "list the words the parser can't tell apart: those \
+                  with the same first six characters, and those in more than one section");
+    opts.optmulti("", "decode", "decode a pair of Radix-50 tokens, like 26335,5005",
+                  "TOKEN,TOKEN");
+    opts.optflag("h", "help", "print this help and exit");
+    opts
+}
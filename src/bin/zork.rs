@@ -1,27 +1,96 @@
 
-
+extern crate getopts;
 extern crate zork;
 
 #[macro_use]
 extern crate log;
 extern crate simplelog;
 
-use std::fs::File;
-use simplelog::{CombinedLogger, Config, LevelFilter, WriteLogger};
+mod common;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+use getopts::{Matches, Options};
+use simplelog::{CombinedLogger, Config, LevelFilter, SharedLogger, WriteLogger};
+use zork::input::{self, ChainSource, InputSource, NoPrompt, ScriptSource, StdinSource};
+use zork::translation::{self, Translation};
+use zork::vocabulary::{self, VocabularyPack};
+use zork::{files, random, recording};
+use common::fail;
 
 fn main() {
-    CombinedLogger::init(vec![
-        WriteLogger::new(
-            LevelFilter::Debug,
-            Config::default(),
-            File::create("log.txt").unwrap(),
-        ),
-        WriteLogger::new(
-            LevelFilter::Trace,
-            Config::default(),
-            File::create("trace_log.txt").unwrap(),
-        ),
-    ]).unwrap();
+    let args: Vec<String> = env::args().collect();
+    let opts = options();
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(err) => fail(&err.to_string()),
+    };
+
+    if matches.opt_present("help") {
+        print!("{}", opts.usage(&format!("Usage: {} [options]", args[0])));
+        return;
+    }
+    if !matches.free.is_empty() {
+        fail(&format!("Unexpected argument: {}", matches.free[0]));
+    }
+
+    init_logging(&matches);
+
+    // Read the replay first, since it may set the seed.
+    let replay = matches.opt_str("replay").map(|path| read_replay(Path::new(&path)));
+    if let Some((seed, _)) = replay {
+        random::set_seed(seed);
+    }
+    if let Some(seed) = matches.opt_str("seed") {
+        match seed.parse() {
+            Ok(seed) => random::set_seed(seed),
+            Err(_) => fail(&format!("Not a valid seed: {}", seed)),
+        }
+    }
+
+    if let Some(path) = matches.opt_str("data") {
+        files::set_data_file(Some(path));
+    }
+    if let Some(path) = matches.opt_str("translation") {
+        match Translation::load(&path) {
+            Ok(loaded) => translation::set(Some(loaded)),
+            Err(err) => fail(&err.to_string()),
+        }
+    }
+    if let Some(path) = matches.opt_str("vocabulary") {
+        match VocabularyPack::load(&path) {
+            Ok(pack) => vocabulary::set(Some(pack)),
+            Err(err) => fail(&err.to_string()),
+        }
+    }
+    if let Some(dir) = matches.opt_str("save-dir") {
+        if let Err(err) = fs::create_dir_all(&dir) {
+            fail(&format!("Unable to create save directory {}: {}", dir, err));
+        }
+        files::set_save_dir(dir);
+    }
+
+    let batch = matches.opt_present("batch");
+    let source: Box<dyn InputSource> = match (replay, batch) {
+        (Some((_, moves)), true) => Box::new(NoPrompt::new(moves)),
+        (Some((_, moves)), false) => Box::new(ChainSource::new(moves, StdinSource)),
+        (None, true) => Box::new(NoPrompt::new(StdinSource)),
+        (None, false) => Box::new(StdinSource),
+    };
+    input::set_source(source);
+
+    if !matches.opt_present("no-record") {
+        match matches.opt_str("record-to") {
+            Some(path) => {
+                if let Err(err) = recording::start_recording_to(&path) {
+                    fail(&format!("Unable to record to {}: {}", path, err));
+                }
+            }
+            None => recording::start_recording(),
+        }
+    }
 
     trace!("Starting c_main()");
 
@@ -30,4 +99,79 @@ fn main() {
     }
 }
 
+fn options() -> Options {
+    let mut opts = Options::new();
+    opts.optopt("", "data", "read the game text from FILE instead of the \
+                 built-in dtextc.dat", "FILE");
+    opts.optopt("", "translation", "replace the game's text with the \
+                 translations in FILE (TOML)", "FILE");
+    opts.optopt("", "vocabulary", "also understand the words in FILE (TOML), \
+                 as the English words they stand for", "FILE");
+    opts.optopt("", "log-dir", "write log.txt and trace_log.txt to DIR \
+                 (default: the working directory)", "DIR");
+    opts.optopt("", "log-level", "the level of log.txt: off, error, warn, \
+                 info, debug or trace (default: debug). trace_log.txt always \
+                 logs everything, unless this is off", "LEVEL");
+    opts.optflag("", "no-record", "don't record the moves to a playback file");
+    opts.optopt("", "record-to", "record the moves to FILE instead of a new \
+                 file in ./playback", "FILE");
+    opts.optopt("", "seed", "start the random number generator from SEED",
+                "SEED");
+    opts.optopt("", "save-dir", "keep saved games in DIR (default: the working \
+                 directory)", "DIR");
+    opts.optopt("", "replay", "replay the moves recorded in FILE, with its \
+                 seed (or a fixed one, if it has none), then keep playing", "FILE");
+    opts.optflag("", "batch", "don't print the prompt, and exit when the input \
+                  runs out");
+    opts.optflag("h", "help", "print this help and exit");
+    opts
+}
+
+// Sets up log.txt and trace_log.txt as asked.
+fn init_logging(matches: &Matches) {
+    let level = match matches.opt_str("log-level") {
+        Some(level) => match level.parse::<LevelFilter>() {
+            Ok(level) => level,
+            Err(_) => fail(&format!("Not a valid log level: {}", level)),
+        },
+        None => LevelFilter::Debug,
+    };
+    if level == LevelFilter::Off {
+        return;
+    }
+
+    let dir = PathBuf::from(matches.opt_str("log-dir").unwrap_or_else(|| ".".to_string()));
+    if let Err(err) = fs::create_dir_all(&dir) {
+        fail(&format!("Unable to create log directory {}: {}", dir.display(), err));
+    }
+
+    let loggers: Vec<Box<dyn SharedLogger>> = vec![
+        WriteLogger::new(level, Config::default(), create_log(&dir.join("log.txt"))),
+        WriteLogger::new(LevelFilter::Trace, Config::default(), create_log(&dir.join("trace_log.txt"))),
+    ];
+    CombinedLogger::init(loggers).unwrap();
+}
+
+fn create_log(path: &Path) -> File {
+    match File::create(path) {
+        Ok(file) => file,
+        Err(err) => fail(&format!("Unable to create {}: {}", path.display(), err)),
+    }
+}
+
+// Reads a playback file. Returns the seed it was recorded with (or the fixed
+// one for files without a seed line), and the moves to replay.
+fn read_replay(path: &Path) -> (u64, ScriptSource<Cursor<String>>) {
+    let mut playback = String::new();
+    if let Err(err) = File::open(path).and_then(|mut file| file.read_to_string(&mut playback)) {
+        fail(&format!("Unable to read {}: {}", path.display(), err));
+    }
+
+    let seed = playback.lines().next().and_then(recording::parse_seed);
+    if seed.is_some() {
+        let start = playback.find('\n').map_or(playback.len(), |end| end + 1);
+        playback.drain(..start);
+    }
 
+    (seed.unwrap_or(recording::LEGACY_SEED), ScriptSource::new(Cursor::new(playback)).echo())
+}
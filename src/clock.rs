@@ -0,0 +1,100 @@
+
+//! The time of day, as seen by the game.
+//!
+//! The game reads the clock when it starts, and again for the TIME command and
+//! when saving, to work out how long it has been played. By default that is
+//! the real time; a `VirtualClock` makes it depend only on the number of moves.
+
+use std::mem;
+use std::sync::{Mutex, MutexGuard};
+use chrono::{Local, Timelike};
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// A source of the time of day.
+pub trait Clock: Send {
+    /// The current time of day, as (hours, minutes, seconds).
+    fn time_of_day(&mut self) -> (u32, u32, u32);
+
+    /// Called once for every command the player gives.
+    fn tick(&mut self) {}
+}
+
+/// The system's local time. This is the default clock.
+#[derive(Debug, Default)]
+pub struct WallClock;
+
+/// A clock that only moves when the player does.
+#[derive(Clone, Debug)]
+pub struct VirtualClock {
+    // Seconds since midnight.
+    now: u32,
+    seconds_per_move: u32,
+}
+
+lazy_static! {
+    static ref CLOCK: Mutex<Box<dyn Clock>> = Mutex::new(Box::new(WallClock));
+}
+
+/// Installs a new clock, and returns the old one.
+pub fn set_clock(clock: Box<dyn Clock>) -> Box<dyn Clock> {
+    mem::replace(&mut *lock(), clock)
+}
+
+/// The current time of day, as (hours, minutes, seconds).
+pub fn time_of_day() -> (u32, u32, u32) {
+    lock().time_of_day()
+}
+
+/// Tells the clock that the player gave a command.
+pub fn tick() {
+    lock().tick();
+}
+
+fn lock() -> MutexGuard<'static, Box<dyn Clock>> {
+    CLOCK.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+impl Clock for Box<dyn Clock> {
+    fn time_of_day(&mut self) -> (u32, u32, u32) {
+        (**self).time_of_day()
+    }
+
+    fn tick(&mut self) {
+        (**self).tick()
+    }
+}
+
+impl Clock for WallClock {
+    fn time_of_day(&mut self) -> (u32, u32, u32) {
+        let now = Local::now();
+        (now.hour(), now.minute(), now.second())
+    }
+}
+
+impl VirtualClock {
+    /// A clock starting at midnight, which advances `seconds_per_move` every
+    /// time the player gives a command.
+    pub fn new(seconds_per_move: u32) -> VirtualClock {
+        VirtualClock {
+            now: 0,
+            seconds_per_move,
+        }
+    }
+
+    /// Sets the time the clock starts at.
+    pub fn starting_at(mut self, hours: u32, minutes: u32, seconds: u32) -> VirtualClock {
+        self.now = (hours * 60 * 60 + minutes * 60 + seconds) % SECONDS_PER_DAY;
+        self
+    }
+}
+
+impl Clock for VirtualClock {
+    fn time_of_day(&mut self) -> (u32, u32, u32) {
+        (self.now / (60 * 60), self.now / 60 % 60, self.now % 60)
+    }
+
+    fn tick(&mut self) {
+        self.now = (self.now + self.seconds_per_move % SECONDS_PER_DAY) % SECONDS_PER_DAY;
+    }
+}
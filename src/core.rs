@@ -1,76 +1,182 @@
 
-
-use std::io::{self, Write};
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
 use std::process::{self, Command, Stdio};
+use libc::c_int;
 use log;
-use replacement::{PromptType, supp};
+use clock;
+use ffi;
+use input::{self, EndOfInput};
+use output;
+use replacement::{PromptType, dsub, supp};
 use recording;
+use saves;
+use state_diff;
+use textdb;
+use undo;
+use vocabulary;
+
+// "I don't understand that."
+const DONT_UNDERSTAND: c_int = 601;
+
+thread_local! {
+    // True while c_run_game() is running on this thread.
+    static RECOVERABLE: Cell<bool> = const { Cell::new(false) };
+}
+
+// The payload `exit_program()` unwinds with, back to `run_game()`.
+struct GameExit;
+
+/// Runs init_() and game_() through `c_run_game()`, so that exiting the game
+/// returns here instead of ending the process. Returns false if init_() failed.
+pub fn run_game() -> bool {
+    undo::clear();
+    state_diff::reset();
+    textdb::reset();
+    let previous = RECOVERABLE.with(|cell| cell.replace(true));
+    trace!("Starting c_run_game()");
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe { ffi::c_run_game() }));
+    RECOVERABLE.with(|cell| cell.set(previous));
+    let initialized = match result {
+        Ok(initialized) => initialized.into(),
+        Err(payload) if payload.is::<GameExit>() => true,
+        Err(payload) => panic::resume_unwind(payload),
+    };
+    trace!("c_run_game() returned {}", initialized);
+    initialized
+}
 
 /// Prints the prompt and reads a line of input. If the input is a shell command
 /// (prefixed by "!"), execute it and read again.
 pub fn read_line(who: PromptType) -> String {
-    // Print the prompt.
-    if who == PromptType::InCharacter {
-        info!("Printing prompt");
-        print!(">");
-        io::stdout().flush().unwrap();
+    loop {
+        if who == PromptType::InCharacter {
+            state_diff::log_turn();
+        }
+
+        let input = next_line(who);
+
+        // Trim whitespace from the input.
+        let trimmed = input.trim();
+
+        // If there was no input, try again.
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        // Check if this is a system command.
+        if let Some(command) = trimmed.strip_prefix('!') {
+            // Execute the command, then read again.
+            execute_shell_command(command);
+            continue;
+        }
+
+        // Convert the string to uppercase.
+        let mut ret = trimmed.to_string();
+        ret.make_ascii_uppercase();
+
+        // Commands are rewritten in the parser's words before they're
+        // recorded, so that they replay without the vocabulary pack.
+        if who == PromptType::InCharacter {
+            ret = vocabulary::rewrite(&ret);
+
+            // A word the pack doesn't have is as unknown to the parser as any
+            // other, even if the C code can't take it.
+            if !ret.is_ascii() {
+                debug!("Not in the vocabulary: {:?}", ret);
+                dsub::rspeak_(DONT_UNDERSTAND);
+                continue;
+            }
+        }
+        check_ascii(&ret);
+
+        // Record this line.
+        recording::record_move(&ret);
+
+        // SAVES and UNDO are handled here, and take no time. Named saves are
+        // turned into commands the parser knows.
+        if who == PromptType::InCharacter {
+            ret = match saves::handle_command(&ret) {
+                Some(ret) => ret,
+                None => continue,
+            };
+            if undo::handle_command(&ret) {
+                continue;
+            }
+
+            // A line of commands takes time. Each command is snapshotted for
+            // UNDO as game_() starts it, see supp_command().
+            clock::tick();
+        }
+
+        return ret;
     }
+}
 
-    // Read from stdin until a newline.
-    let mut input = String::with_capacity(80);
-    let res = io::stdin().read_line(&mut input);
-    if let Err(err) = res {
-        error!("Error reading string: {:?}", &err);
-        exit_program();
+/// Reads the answer to an out-of-character question, such as yesno_() or a
+/// GDT prompt. Unlike `read_line()`, an empty answer is returned as is.
+pub fn read_answer() -> String {
+    let input = next_line(PromptType::OutOfCharacter);
+    let answer = input.trim().to_string();
+    check_ascii(&answer);
+    recording::record_move(&answer);
+    answer
+}
+
+// Prints the prompt and reads a line from the input source. Ends the game if
+// there is no more input.
+fn next_line(who: PromptType) -> String {
+    // Print the prompt.
+    if who == PromptType::InCharacter && input::shows_prompt() {
+        info!("Printing prompt");
+        output::write(">");
     }
+    output::flush();
+
+    // Read until a newline.
+    let input = match input::read_line() {
+        Ok(Some(line)) => line,
+        Ok(None) => end_of_input(),
+        Err(err) => {
+            error!("Error reading string: {:?}", &err);
+            exit_program();
+        }
+    };
     debug!("Read string: {:?}", &input);
 
     // Update some global variables.
     trace!("calling more_input()");
     supp::more_input();
 
-    // Ensure that the input is ascii.
+    input
+}
+
+// Ends the game if the input isn't ASCII, which is all the C code takes.
+fn check_ascii(input: &str) {
     if !input.is_ascii() {
         error!("Input string is not valid ascii.");
         exit_program();
     }
+}
 
-    // Trim whitespace from the input.
-    let trimmed = input.trim();
-
-    // If there was no input, try again.
-    if trimmed.len() == 0 {
-        return read_line(who);
-    }
-
-    // Check if this is a system command.
-    if trimmed.starts_with("!") {
-        // Execute the command.
-        execute_shell_command(&trimmed[1..]);
-
-        // Read again.
-        return read_line(who);
-    } else {
-        // Convert the string to uppercase.
-        let mut ret = trimmed.to_string();
-        ret.make_ascii_uppercase();
-
-        // Record this line.
-        recording::record_move(&ret);
+// The input has run out; end the game as configured.
+fn end_of_input() -> ! {
+    let action = input::end_of_input();
+    info!("End of input, {:?}", action);
 
-        // Return.
-        ret
+    if action == EndOfInput::SaveAndExit {
+        saves::save_command(None);
     }
+    exit_program()
 }
 
-
 /// Executes a shell command, and waits for it to return.
 fn execute_shell_command(command: &str) {
     // Forward this command to the shell, minus the first char.
     trace!("Calling shell with command {:?}", &command);
     let res = Command::new("bash")
         .arg("-c")
-        .arg(&command)
+        .arg(command)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .stdin(Stdio::inherit())
@@ -90,12 +196,20 @@ fn execute_shell_command(command: &str) {
 }
 
 /// Does some cleanup and exits the program.
+///
+/// If the game was started by `run_game()`, this unwinds back to it instead,
+/// through the C frames in between, and the process keeps running.
 pub fn exit_program() -> ! {
-    println!("The game is over.\n");
-    io::stdout().flush().unwrap();
+    output::write("The game is over.\n\n");
+    output::flush();
 
     info!("Exiting game.");
     log::logger().flush();
 
-    process::exit(0)
+    if RECOVERABLE.with(|cell| cell.get()) {
+        // resume_unwind() doesn't run the panic hook, so nothing is printed.
+        panic::resume_unwind(Box::new(GameExit))
+    } else {
+        process::exit(0)
+    }
 }
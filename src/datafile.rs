@@ -0,0 +1,723 @@
+//! The text database (dtextc.dat) as an editable source, and back.
+//!
+//! `DataFile::read()` reads a database the way init_() in dinit.c does: its
+//! header, then rooms, exits, objects, multi-room objects, clock events,
+//! villains, adventurers and the message index, each as `rdint()`s (two-byte
+//! big-endian integers), `rdpartialints()` (index/value pairs for the entries
+//! that aren't 0) or `rdflags()` (one byte each). The messages follow,
+//! decrypted as they are by the text database (see `textdb`).
+//!
+//! The result can be written out as TOML with `to_toml()`, edited, read back
+//! with `from_toml()`, and compiled into a database again with `write()`.
+//! Writing a database that was read without changes gives back the same
+//! bytes.
+//!
+//! Messages are listed in the order they're stored, each with the pointer
+//! (a negative message number) it had when it was read. Everything that names
+//! a message by pointer (room and object descriptions, the message index and
+//! the messages of exits) uses these, so messages can be made longer or
+//! shorter: `write()` lays them out again and moves the pointers along.
+
+use std::collections::HashMap;
+use std::fmt;
+use libc::c_int;
+use serde::{Deserialize, Serialize};
+use toml;
+use textdb;
+use travel::{self, ExitKind};
+
+// The sizes of the arrays init_() reads into (see ffi).
+const MAX_ROOMS: usize = 200;
+const MAX_EXITS: usize = 900;
+const MAX_OBJECTS: usize = 220;
+const MAX_MULTI_ROOM_OBJECTS: usize = 20;
+const MAX_CLOCK_EVENTS: usize = 25;
+const MAX_VILLAINS: usize = 4;
+const MAX_ADVENTURERS: usize = 4;
+const MAX_MESSAGES: usize = 1050;
+
+/// A whole text database.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataFile {
+    pub header: Header,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rooms: Vec<Room>,
+    pub exits: Exits,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub objects: Vec<Object>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multi_room_objects: Vec<MultiRoomObject>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clock_events: Vec<ClockEvent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub villains: Vec<Villain>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub adventurers: Vec<Adventurer>,
+    pub messages: Messages,
+}
+
+/// The numbers at the start of the database, and mbase, which is stored
+/// just before the message index.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    /// The version of the game the database is for. init_() refuses a
+    /// database whose major or minor version differs from its own.
+    pub major: c_int,
+    pub minor: c_int,
+    pub edit: c_int,
+    pub max_score: c_int,
+    pub star_bit: c_int,
+    pub endgame_max_score: c_int,
+    pub mbase: c_int,
+}
+
+/// A room, from rooms_.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Room {
+    pub description_1: c_int,
+    pub description_2: c_int,
+
+    /// Where the room's exits start in the travel table, counting from 1.
+    pub exits: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub action: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub value: c_int,
+    pub flags: c_int,
+}
+
+/// The travel table (exits_), which the rooms' exits point into.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Exits {
+    #[serde(default)]
+    pub travel: Vec<c_int>,
+}
+
+/// An object, from objcts_.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Object {
+    pub description_1: c_int,
+    pub description_2: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub desco: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub action: c_int,
+    pub flags_1: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub flags_2: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub fval: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub tval: c_int,
+    pub size: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub capacity: c_int,
+    pub room: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub adventurer: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub container: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub read: c_int,
+}
+
+/// An object that is in more than one room, from oroom2_.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiRoomObject {
+    pub object: c_int,
+    pub room: c_int,
+}
+
+/// A clock event, from cevent_.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClockEvent {
+    pub ticks: c_int,
+    pub action: c_int,
+    pub running: bool,
+}
+
+/// A villain, from vill_.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Villain {
+    pub villain: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub probability: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub opponent: c_int,
+    pub best_weapon: c_int,
+    pub melee: c_int,
+}
+
+/// An adventurer, from advs_.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Adventurer {
+    pub room: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub score: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub vehicle: c_int,
+    pub object: c_int,
+    pub action: c_int,
+    pub strength: c_int,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub flags: c_int,
+}
+
+/// The messages, and the index (rtext) that numbers some of them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Messages {
+    /// The pointers of the numbered messages: message n is `index[n - 1]`.
+    #[serde(default)]
+    pub index: Vec<c_int>,
+    #[serde(default)]
+    pub entries: Vec<Message>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Message {
+    /// The message's pointer: -(offset / 8) - 1, where offset is where it was
+    /// stored in the message area.
+    pub pointer: c_int,
+    pub text: String,
+}
+
+/// A database that can't be read or written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DataFileError {
+    /// The database ends in the middle of something.
+    Truncated,
+
+    /// There are more of something than the game has room for. Holds what
+    /// there are too many of, and how many there are.
+    TooMany(&'static str, usize),
+
+    /// A partial array has an entry past its end. Holds the array's name and
+    /// the index.
+    BadIndex(&'static str, c_int),
+
+    /// A clock event's flag is neither 0 nor 1. Holds the event, from 1.
+    BadFlag(usize),
+
+    /// A number doesn't fit in two bytes. Holds what it's for, and the number.
+    OutOfRange(&'static str, c_int),
+
+    /// A message has something other than ASCII in it, or a NUL. Holds its
+    /// pointer.
+    BadText(c_int),
+
+    /// The bytes after a message aren't the usual padding, so it couldn't be
+    /// written back the same. Holds its pointer.
+    BadPadding(c_int),
+
+    /// Two messages have the same pointer.
+    DuplicateMessage(c_int),
+
+    /// A pointer doesn't name any of the messages.
+    UnknownMessage(c_int),
+
+    /// The travel table can't be decoded.
+    Travel(travel::TravelError),
+
+    /// The TOML source can't be parsed.
+    Toml(String),
+}
+
+impl DataFile {
+    /// Reads a text database.
+    pub fn read(data: &[u8]) -> Result<DataFile, DataFileError> {
+        let mut reader = Reader { data, position: 0 };
+
+        let major = reader.int()?;
+        let minor = reader.int()?;
+        let edit = reader.int()?;
+        let max_score = reader.int()?;
+        let star_bit = reader.int()?;
+        let endgame_max_score = reader.int()?;
+
+        let count = reader.count("rooms", MAX_ROOMS)?;
+        let description_1 = reader.ints(count)?;
+        let description_2 = reader.ints(count)?;
+        let exits = reader.ints(count)?;
+        let action = reader.partial_ints("room actions", count)?;
+        let value = reader.partial_ints("room values", count)?;
+        let flags = reader.ints(count)?;
+        let rooms = (0..count).map(|i| Room {
+            description_1: description_1[i],
+            description_2: description_2[i],
+            exits: exits[i],
+            action: action[i],
+            value: value[i],
+            flags: flags[i],
+        }).collect();
+
+        let count = reader.count("exits", MAX_EXITS)?;
+        let travel = reader.ints(count)?;
+
+        let count = reader.count("objects", MAX_OBJECTS)?;
+        let description_1 = reader.ints(count)?;
+        let description_2 = reader.ints(count)?;
+        let desco = reader.partial_ints("object descriptions", count)?;
+        let action = reader.partial_ints("object actions", count)?;
+        let flags_1 = reader.ints(count)?;
+        let flags_2 = reader.partial_ints("object flags", count)?;
+        let fval = reader.partial_ints("object values", count)?;
+        let tval = reader.partial_ints("object treasure values", count)?;
+        let size = reader.ints(count)?;
+        let capacity = reader.partial_ints("object capacities", count)?;
+        let room = reader.ints(count)?;
+        let adventurer = reader.partial_ints("object adventurers", count)?;
+        let container = reader.partial_ints("object containers", count)?;
+        let read = reader.partial_ints("object texts", count)?;
+        let objects = (0..count).map(|i| Object {
+            description_1: description_1[i],
+            description_2: description_2[i],
+            desco: desco[i],
+            action: action[i],
+            flags_1: flags_1[i],
+            flags_2: flags_2[i],
+            fval: fval[i],
+            tval: tval[i],
+            size: size[i],
+            capacity: capacity[i],
+            room: room[i],
+            adventurer: adventurer[i],
+            container: container[i],
+            read: read[i],
+        }).collect();
+
+        let count = reader.count("multi-room objects", MAX_MULTI_ROOM_OBJECTS)?;
+        let object = reader.ints(count)?;
+        let room = reader.ints(count)?;
+        let multi_room_objects = (0..count).map(|i| MultiRoomObject {
+            object: object[i],
+            room: room[i],
+        }).collect();
+
+        let count = reader.count("clock events", MAX_CLOCK_EVENTS)?;
+        let ticks = reader.ints(count)?;
+        let action = reader.ints(count)?;
+        let running = reader.flags(count)?;
+        let clock_events = (0..count).map(|i| ClockEvent {
+            ticks: ticks[i],
+            action: action[i],
+            running: running[i],
+        }).collect();
+
+        let count = reader.count("villains", MAX_VILLAINS)?;
+        let villain = reader.ints(count)?;
+        let probability = reader.partial_ints("villain probabilities", count)?;
+        let opponent = reader.partial_ints("villain opponents", count)?;
+        let best_weapon = reader.ints(count)?;
+        let melee = reader.ints(count)?;
+        let villains = (0..count).map(|i| Villain {
+            villain: villain[i],
+            probability: probability[i],
+            opponent: opponent[i],
+            best_weapon: best_weapon[i],
+            melee: melee[i],
+        }).collect();
+
+        let count = reader.count("adventurers", MAX_ADVENTURERS)?;
+        let room = reader.ints(count)?;
+        let score = reader.partial_ints("adventurer scores", count)?;
+        let vehicle = reader.partial_ints("adventurer vehicles", count)?;
+        let object = reader.ints(count)?;
+        let action = reader.ints(count)?;
+        let strength = reader.ints(count)?;
+        let flags = reader.partial_ints("adventurer flags", count)?;
+        let adventurers = (0..count).map(|i| Adventurer {
+            room: room[i],
+            score: score[i],
+            vehicle: vehicle[i],
+            object: object[i],
+            action: action[i],
+            strength: strength[i],
+            flags: flags[i],
+        }).collect();
+
+        let mbase = reader.int()?;
+        let count = reader.count("messages", MAX_MESSAGES)?;
+        let index = reader.ints(count)?;
+        let entries = read_messages(&data[reader.position..])?;
+
+        Ok(DataFile {
+            header: Header { major, minor, edit, max_score, star_bit, endgame_max_score, mbase },
+            rooms,
+            exits: Exits { travel },
+            objects,
+            multi_room_objects,
+            clock_events,
+            villains,
+            adventurers,
+            messages: Messages { index, entries },
+        })
+    }
+
+    /// Compiles the database. The messages are laid out in the order they're
+    /// listed, and every pointer to them is changed to match.
+    pub fn write(&self) -> Result<Vec<u8>, DataFileError> {
+        let (area, pointers) = write_messages(&self.messages.entries)?;
+        let mut file = self.clone();
+        file.relocate(&pointers)?;
+
+        let mut writer = Writer { data: Vec::new() };
+        let header = &file.header;
+        writer.int("the version", header.major)?;
+        writer.int("the version", header.minor)?;
+        writer.int("the version", header.edit)?;
+        writer.int("the maximum score", header.max_score)?;
+        writer.int("the star bit", header.star_bit)?;
+        writer.int("the endgame maximum score", header.endgame_max_score)?;
+
+        let rooms = &file.rooms;
+        writer.count("rooms", rooms.len(), MAX_ROOMS)?;
+        writer.ints("room descriptions", rooms.iter().map(|room| room.description_1))?;
+        writer.ints("room descriptions", rooms.iter().map(|room| room.description_2))?;
+        writer.ints("room exits", rooms.iter().map(|room| room.exits))?;
+        writer.partial_ints("room actions", rooms.iter().map(|room| room.action))?;
+        writer.partial_ints("room values", rooms.iter().map(|room| room.value))?;
+        writer.ints("room flags", rooms.iter().map(|room| room.flags))?;
+
+        let travel = &file.exits.travel;
+        writer.count("exits", travel.len(), MAX_EXITS)?;
+        writer.ints("the travel table", travel.iter().cloned())?;
+
+        let objects = &file.objects;
+        writer.count("objects", objects.len(), MAX_OBJECTS)?;
+        writer.ints("object descriptions", objects.iter().map(|object| object.description_1))?;
+        writer.ints("object descriptions", objects.iter().map(|object| object.description_2))?;
+        writer.partial_ints("object descriptions", objects.iter().map(|object| object.desco))?;
+        writer.partial_ints("object actions", objects.iter().map(|object| object.action))?;
+        writer.ints("object flags", objects.iter().map(|object| object.flags_1))?;
+        writer.partial_ints("object flags", objects.iter().map(|object| object.flags_2))?;
+        writer.partial_ints("object values", objects.iter().map(|object| object.fval))?;
+        writer.partial_ints("object treasure values", objects.iter().map(|object| object.tval))?;
+        writer.ints("object sizes", objects.iter().map(|object| object.size))?;
+        writer.partial_ints("object capacities", objects.iter().map(|object| object.capacity))?;
+        writer.ints("object rooms", objects.iter().map(|object| object.room))?;
+        writer.partial_ints("object adventurers", objects.iter().map(|object| object.adventurer))?;
+        writer.partial_ints("object containers", objects.iter().map(|object| object.container))?;
+        writer.partial_ints("object texts", objects.iter().map(|object| object.read))?;
+
+        let multi_room_objects = &file.multi_room_objects;
+        writer.count("multi-room objects", multi_room_objects.len(), MAX_MULTI_ROOM_OBJECTS)?;
+        writer.ints("multi-room objects", multi_room_objects.iter().map(|entry| entry.object))?;
+        writer.ints("multi-room objects", multi_room_objects.iter().map(|entry| entry.room))?;
+
+        let clock_events = &file.clock_events;
+        writer.count("clock events", clock_events.len(), MAX_CLOCK_EVENTS)?;
+        writer.ints("clock event ticks", clock_events.iter().map(|event| event.ticks))?;
+        writer.ints("clock event actions", clock_events.iter().map(|event| event.action))?;
+        writer.flags(clock_events.iter().map(|event| event.running));
+
+        let villains = &file.villains;
+        writer.count("villains", villains.len(), MAX_VILLAINS)?;
+        writer.ints("villains", villains.iter().map(|villain| villain.villain))?;
+        writer.partial_ints("villain probabilities", villains.iter().map(|villain| villain.probability))?;
+        writer.partial_ints("villain opponents", villains.iter().map(|villain| villain.opponent))?;
+        writer.ints("villain weapons", villains.iter().map(|villain| villain.best_weapon))?;
+        writer.ints("villain melee", villains.iter().map(|villain| villain.melee))?;
+
+        let adventurers = &file.adventurers;
+        writer.count("adventurers", adventurers.len(), MAX_ADVENTURERS)?;
+        writer.ints("adventurer rooms", adventurers.iter().map(|adventurer| adventurer.room))?;
+        writer.partial_ints("adventurer scores", adventurers.iter().map(|adventurer| adventurer.score))?;
+        writer.partial_ints("adventurer vehicles", adventurers.iter().map(|adventurer| adventurer.vehicle))?;
+        writer.ints("adventurer objects", adventurers.iter().map(|adventurer| adventurer.object))?;
+        writer.ints("adventurer actions", adventurers.iter().map(|adventurer| adventurer.action))?;
+        writer.ints("adventurer strengths", adventurers.iter().map(|adventurer| adventurer.strength))?;
+        writer.partial_ints("adventurer flags", adventurers.iter().map(|adventurer| adventurer.flags))?;
+
+        writer.int("mbase", file.header.mbase)?;
+        let index = &file.messages.index;
+        writer.count("messages", index.len(), MAX_MESSAGES)?;
+        writer.ints("the message index", index.iter().cloned())?;
+
+        let mut data = writer.data;
+        data.extend(area);
+        Ok(data)
+    }
+
+    /// The database as TOML.
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("data files are always serializable")
+    }
+
+    /// Reads a database written by `to_toml()`.
+    pub fn from_toml(source: &str) -> Result<DataFile, DataFileError> {
+        toml::from_str(source).map_err(|err| DataFileError::Toml(err.to_string()))
+    }
+
+    // Changes every pointer to a message to the one in `pointers`.
+    fn relocate(&mut self, pointers: &HashMap<c_int, c_int>) -> Result<(), DataFileError> {
+        // The messages of exits are in the travel table, after the exit's
+        // first word. Only the exits the rooms point to are looked at, so
+        // nothing else in the table is taken for a message.
+        let mut messages = Vec::new();
+        for room in &self.rooms {
+            let mut index = room.exits as usize;
+            for exit in travel::decode(&self.exits.travel, index).map_err(DataFileError::Travel)? {
+                let length = match exit.kind {
+                    ExitKind::Normal => 1,
+                    ExitKind::No(_) => 2,
+                    ExitKind::Conditional(..) | ExitKind::Door(..) => 3,
+                };
+                if length > 1 {
+                    messages.push(index);
+                }
+                index += length;
+            }
+        }
+        messages.sort();
+        messages.dedup();
+        for index in messages {
+            relocate(pointers, &mut self.exits.travel[index])?;
+        }
+
+        for room in &mut self.rooms {
+            relocate(pointers, &mut room.description_1)?;
+            relocate(pointers, &mut room.description_2)?;
+        }
+        for object in &mut self.objects {
+            relocate(pointers, &mut object.description_1)?;
+            relocate(pointers, &mut object.description_2)?;
+            relocate(pointers, &mut object.desco)?;
+            relocate(pointers, &mut object.read)?;
+        }
+        for pointer in &mut self.messages.index {
+            relocate(pointers, pointer)?;
+        }
+        Ok(())
+    }
+}
+
+// Changes a pointer to a message (a negative number) to the one in
+// `pointers`. Other numbers are left as they are.
+fn relocate(pointers: &HashMap<c_int, c_int>, value: &mut c_int) -> Result<(), DataFileError> {
+    if *value < 0 {
+        *value = match pointers.get(value) {
+            Some(&pointer) => pointer,
+            None => return Err(DataFileError::UnknownMessage(*value)),
+        };
+    }
+    Ok(())
+}
+
+// The pointer of the message that starts `offset` bytes into the message area.
+fn pointer(offset: usize) -> c_int {
+    -((offset / 8) as c_int) - 1
+}
+
+// Reads the messages from the message area: each one starts at a multiple of
+// 8 bytes and ends with a NUL, and the bytes up to the next one are padding.
+fn read_messages(area: &[u8]) -> Result<Vec<Message>, DataFileError> {
+    let text: Vec<u8> = area.iter().enumerate().map(|(x, &byte)| textdb::crypt(x, byte)).collect();
+    let mut messages = Vec::new();
+    let mut offset = 0;
+    while offset < text.len() {
+        let pointer = pointer(offset);
+        let end = match text[offset..].iter().position(|&byte| byte == 0) {
+            Some(length) => offset + length,
+            None => return Err(DataFileError::Truncated),
+        };
+        if !text[offset..end].is_ascii() {
+            return Err(DataFileError::BadText(pointer));
+        }
+        let next = (end + 8) & !7;
+        if next > text.len() {
+            return Err(DataFileError::Truncated);
+        }
+        if (end + 1..next).any(|x| area[x] != x as u8) {
+            return Err(DataFileError::BadPadding(pointer));
+        }
+        messages.push(Message {
+            pointer,
+            text: text[offset..end].iter().map(|&byte| byte as char).collect(),
+        });
+        offset = next;
+    }
+    Ok(messages)
+}
+
+// Lays out the messages in a message area. Returns the area, and the new
+// pointer of each message by its old one.
+fn write_messages(messages: &[Message]) -> Result<(Vec<u8>, HashMap<c_int, c_int>), DataFileError> {
+    let mut area = Vec::new();
+    let mut pointers = HashMap::new();
+    for message in messages {
+        if !message.text.is_ascii() || message.text.contains('\0') {
+            return Err(DataFileError::BadText(message.pointer));
+        }
+        if pointers.insert(message.pointer, pointer(area.len())).is_some() {
+            return Err(DataFileError::DuplicateMessage(message.pointer));
+        }
+        for byte in message.text.bytes().chain(Some(0)) {
+            let x = area.len();
+            area.push(textdb::crypt(x, byte));
+        }
+        while area.len() % 8 != 0 {
+            let x = area.len();
+            area.push(x as u8);
+        }
+    }
+    Ok((area, pointers))
+}
+
+fn is_zero(value: &c_int) -> bool {
+    *value == 0
+}
+
+// Reads the parts of a database, as init_() does.
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn byte(&mut self) -> Result<u8, DataFileError> {
+        let byte = *self.data.get(self.position).ok_or(DataFileError::Truncated)?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    // rdint(): a signed two-byte integer, high byte first.
+    fn int(&mut self) -> Result<c_int, DataFileError> {
+        let high = self.byte()? as i8 as c_int;
+        let low = self.byte()? as c_int;
+        Ok(high * 256 + low)
+    }
+
+    // The number of entries in an array, which has room for `max`.
+    fn count(&mut self, what: &'static str, max: usize) -> Result<usize, DataFileError> {
+        let count = self.int()?;
+        if count < 0 || count as usize > max {
+            return Err(DataFileError::TooMany(what, count.max(0) as usize));
+        }
+        Ok(count as usize)
+    }
+
+    // rdints()
+    fn ints(&mut self, count: usize) -> Result<Vec<c_int>, DataFileError> {
+        (0..count).map(|_| self.int()).collect()
+    }
+
+    // rdpartialints(): (index, value) pairs, with one-byte indexes ending in
+    // 255 for arrays with fewer than 255 entries, and rdint() ones ending in
+    // -1 otherwise. The rest of the array is 0.
+    fn partial_ints(&mut self, what: &'static str, count: usize) -> Result<Vec<c_int>, DataFileError> {
+        let mut values = vec![0; count];
+        loop {
+            let index = if count < 255 {
+                match self.byte()? {
+                    255 => return Ok(values),
+                    index => index as c_int,
+                }
+            } else {
+                match self.int()? {
+                    -1 => return Ok(values),
+                    index => index,
+                }
+            };
+            if index < 0 || index as usize >= count {
+                return Err(DataFileError::BadIndex(what, index));
+            }
+            values[index as usize] = self.int()?;
+        }
+    }
+
+    // rdflags()
+    fn flags(&mut self, count: usize) -> Result<Vec<bool>, DataFileError> {
+        (0..count).map(|i| match self.byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(DataFileError::BadFlag(i + 1)),
+        }).collect()
+    }
+}
+
+// Writes the parts of a database, so that init_() reads them back.
+struct Writer {
+    data: Vec<u8>,
+}
+
+impl Writer {
+    fn int(&mut self, what: &'static str, value: c_int) -> Result<(), DataFileError> {
+        if value < i16::MIN as c_int || value > i16::MAX as c_int {
+            return Err(DataFileError::OutOfRange(what, value));
+        }
+        self.data.extend(&(value as i16).to_be_bytes());
+        Ok(())
+    }
+
+    fn count(&mut self, what: &'static str, count: usize, max: usize) -> Result<(), DataFileError> {
+        if count > max {
+            return Err(DataFileError::TooMany(what, count));
+        }
+        self.int(what, count as c_int)
+    }
+
+    fn ints<I>(&mut self, what: &'static str, values: I) -> Result<(), DataFileError>
+        where I: Iterator<Item = c_int>
+    {
+        for value in values {
+            self.int(what, value)?;
+        }
+        Ok(())
+    }
+
+    // Writes the entries that aren't 0, in order.
+    fn partial_ints<I>(&mut self, what: &'static str, values: I) -> Result<(), DataFileError>
+        where I: ExactSizeIterator<Item = c_int>
+    {
+        let small = values.len() < 255;
+        for (index, value) in values.enumerate() {
+            if value == 0 {
+                continue;
+            }
+            if small {
+                self.data.push(index as u8);
+            } else {
+                self.int(what, index as c_int)?;
+            }
+            self.int(what, value)?;
+        }
+        if small {
+            self.data.push(255);
+            Ok(())
+        } else {
+            self.int(what, -1)
+        }
+    }
+
+    fn flags<I: Iterator<Item = bool>>(&mut self, flags: I) {
+        self.data.extend(flags.map(|flag| flag as u8));
+    }
+}
+
+impl fmt::Display for DataFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DataFileError::Truncated => write!(f, "The data file ends too soon."),
+            DataFileError::TooMany(what, count) => write!(f, "There are too many {} ({}).", what, count),
+            DataFileError::BadIndex(what, index) => {
+                write!(f, "The {} have an entry out of range ({}).", what, index)
+            }
+            DataFileError::BadFlag(event) => write!(f, "Clock event {} has a bad flag.", event),
+            DataFileError::OutOfRange(what, value) => {
+                write!(f, "A number in {} is out of range ({}).", what, value)
+            }
+            DataFileError::BadText(pointer) => {
+                write!(f, "Message {} has a character that can't be stored.", pointer)
+            }
+            DataFileError::BadPadding(pointer) => {
+                write!(f, "Message {} is followed by unusual padding.", pointer)
+            }
+            DataFileError::DuplicateMessage(pointer) => {
+                write!(f, "There is more than one message {}.", pointer)
+            }
+            DataFileError::UnknownMessage(pointer) => write!(f, "There is no message {}.", pointer),
+            DataFileError::Travel(ref err) => write!(f, "{}", err),
+            DataFileError::Toml(ref err) => write!(f, "{}", err),
+        }
+    }
+}
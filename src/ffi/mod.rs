@@ -1,26 +1,60 @@
 
 use libc::{c_char, c_int};
+use std::fmt;
 use std::ops::Range;
+use std::slice;
+use bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_big_array::BigArray;
 use core;
 
 pub mod objects;
+pub mod rooms;
 use self::objects::{Objects, GlobalObjects};
+use self::rooms::Rooms;
 
 pub use self::prsvec_ as parse_vec;
 pub use self::objcts_ as objects;
 pub use self::play_ as player;
 pub use self::advs_ as adventurers;
 pub use self::star_ as global_items;
-
+pub use self::state_ as game_state;
+pub use self::time_ as play_time;
+pub use self::rooms_ as rooms;
+pub use self::hack_ as hack;
+pub use self::puzzle_ as puzzle;
+pub use self::screen_ as screen;
+pub use self::findex_ as game_flags;
+pub use self::vill_ as villains;
+pub use self::cevent_ as clock_events;
+pub use self::vers_ as version;
+pub use self::orphs_ as orphans;
+pub use self::last_ as last_it;
+pub use self::oroom2_ as multi_room_objects;
+pub use self::debug_ as debug_flags;
+pub use self::curxt_ as current_exit;
+pub use self::input_ as input_buffer;
+pub use self::rmsg_ as messages;
+pub use self::exits_ as exits;
+pub use self::pv_ as parsed_words;
+pub use self::syntax_ as syntax_vec;
+
+// The lengths of the vocabulary arrays, from build.rs.
+include!(concat!(env!("OUT_DIR"), "/vocab_lengths.rs"));
 
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct Logical(c_int);
 
 #[link(name = "c_zork")]
-extern "C" {
+extern "C-unwind" {
     pub fn c_main();
 
+    // supp.c
+    // Runs init_() and game_() until the game calls exit_(). Returns false if
+    // init_() failed.
+    pub fn c_run_game() -> Logical;
+
     ///////////////////////////////////////////////////////////////////////////
     // Defined in func.h
 
@@ -45,9 +79,9 @@ extern "C" {
     pub fn fightd_();
     pub fn game_();
     pub fn gdt_();
-    pub fn gttime_(a1: *mut c_int);
+    //pub fn gttime_(a1: *mut c_int);
     pub fn invent_(a1: c_int);
-    pub fn itime_(a1: *mut c_int, a2: *mut c_int, a3: *mut c_int);
+    //pub fn itime_(a1: *mut c_int, a2: *mut c_int, a3: *mut c_int);
     pub fn jigsup_(a1: c_int);
     pub fn newsta_(a1: c_int, a2: c_int, a3: c_int, a4: c_int, a5: c_int);
     pub fn orphan_(a1: c_int, a2: c_int, a3: c_int, a4: c_int, a5: c_int);
@@ -66,11 +100,11 @@ extern "C" {
     // np2.c
     //pub fn schlst_(a1: c_int, a2: c_int, a3: c_int, a4: c_int, a5: c_int, a6: c_int) -> c_int;
 
-    pub fn rspeak_(a1: c_int);
-    pub fn rspsb2_(a1: c_int, a2: c_int, a3: c_int);
-    pub fn rspsub_(a1: c_int, a2: c_int);
-    pub fn rstrgm_();
-    pub fn savegm_();
+    //pub fn rspeak_(a1: c_int);
+    //pub fn rspsb2_(a1: c_int, a2: c_int, a3: c_int);
+    //pub fn rspsub_(a1: c_int, a2: c_int);
+    //pub fn rstrgm_();
+    //pub fn savegm_();
     pub fn score_(a1: Logical);
     pub fn scrupd_(a1: c_int);
     pub fn swordd_();
@@ -83,10 +117,9 @@ extern "C" {
     pub fn getobj_(a1: c_int, a2: c_int, a3: c_int) -> c_int;
     pub fn mrhere_(a1: c_int) -> c_int;
     pub fn oactor_(a1: c_int) -> c_int;
-    pub fn rnd_(a1: c_int) -> c_int;
     pub fn robadv_(a1: c_int, a2: c_int, a3: c_int, a4: c_int) -> c_int;
     pub fn robrm_(a1: c_int, a2: c_int, a3: c_int, a4: c_int, a5: c_int) -> c_int;
-    pub fn sparse_(a1: *const c_int, a2: c_int, a3: Logical) -> c_int;
+    //pub fn sparse_(a1: *const c_int, a2: c_int, a3: Logical) -> c_int;
     pub fn vilstr_(a1: c_int) -> c_int;
     pub fn weight_(a1: c_int, a2: c_int, a3: c_int) -> c_int;
 
@@ -117,7 +150,7 @@ extern "C" {
     pub fn rmdesc_(a1: c_int) -> Logical;
     pub fn sobjs_(a1: c_int, a2: c_int) -> Logical;
     pub fn sverbs_(a1: c_int) -> Logical;
-    pub fn synmch_() -> Logical;
+    //pub fn synmch_() -> Logical;
     pub fn take_(a1: Logical) -> Logical;
     pub fn thiefp_(a1: c_int) -> Logical;
     pub fn trollp_(a1: c_int) -> Logical;
@@ -136,6 +169,39 @@ extern "C" {
     pub static mut play_: Player;
     pub static mut advs_: Adventurers;
     pub static mut star_: GlobalObjects;
+    pub static mut state_: Counters;
+    pub static mut time_: PlayTime;
+    pub static mut rooms_: Rooms;
+    pub static mut hack_: Hack;
+    pub static mut puzzle_: Puzzle;
+    pub static mut screen_: Screen;
+    pub static mut findex_: Flags;
+    pub static mut vill_: Villains;
+    pub static mut cevent_: ClockEvents;
+    pub static vers_: Version;
+    pub static mut orphs_: Orphans;
+    pub static mut last_: LastIt;
+    pub static mut oroom2_: MultiRoomObjects;
+    pub static mut debug_: DebugFlags;
+    pub static mut curxt_: CurrentExit;
+    pub static mut input_: InputBuffer;
+    pub static mut rmsg_: Messages;
+    pub static mut exits_: Exits;
+
+    // Defined in parse.h
+
+    pub static mut pv_: ParsedWords;
+    pub static mut syntax_: SyntaxVec;
+
+    // The vocabulary, which build.rs generates from replacement::vocab, with
+    // the lengths it writes to vocab_lengths.rs. The first three are structs
+    // holding only the array.
+    static buzvoc_: [c_int; BUZVOC_LENGTH];
+    static prpvoc_: [c_int; PRPVOC_LENGTH];
+    static dirvoc_: [c_int; DIRVOC_LENGTH];
+    static avoc: [c_int; AVOC_LENGTH];
+    static vvoc: [c_int; VVOC_LENGTH];
+    static ovoc: [c_int; OVOC_LENGTH];
 
     ///////////////////////////////////////////////////////////////////////////
     // Defined elsewhere
@@ -144,6 +210,19 @@ extern "C" {
     // np.c
     //pub fn lex_(a1: *mut c_char, a2: *mut c_int, a3: *mut c_int, a4: Logical) -> Logical;
 
+    // The C lex_(), which the Rust one replaces.
+    pub fn c_lex_(a1: *const c_char, a2: *mut c_int, a3: *mut c_int, a4: Logical) -> Logical;
+
+    ////////////
+    // np1.c
+    // The C sparse_(), which the Rust one replaces.
+    pub fn c_sparse_(a1: *const c_int, a2: c_int, a3: Logical) -> c_int;
+
+    ////////////
+    // np3.c
+    // The C synmch_(), which the Rust one replaces.
+    pub fn c_synmch_() -> Logical;
+
     ////////////
     // np2.c
     pub fn thisit_ (a1: c_int, a2: c_int, a3: c_int, a4: c_int) -> Logical;
@@ -156,30 +235,242 @@ extern "C" {
 
 // Info about the player.
 #[repr(C)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Player {
     pub winner: c_int,
     pub current_room: c_int,
     pub tel_flag: Logical
 }
 
+// Counters for the game as a whole.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Counters {
+    pub moves: c_int,
+    pub deaths: c_int,
+    pub raw_score: c_int,
+    pub max_score: c_int,
+    pub max_load: c_int,
+    pub light_shift: c_int,
+    pub balloon_location: c_int,
+    pub munged_room: c_int,
+    pub hello_sailor: c_int,
+    pub endgame_score: c_int,
+    pub endgame_max_score: c_int
+}
+
+// When the game was started, and how long it was played before being saved.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayTime {
+    // Minutes played before the last restore.
+    pub play_time: c_int,
+    pub start_hour: c_int,
+    pub start_minute: c_int,
+    pub start_second: c_int
+}
+
+// A command that was missing a word, to be completed by the next one.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Orphans {
+    pub flag: c_int,
+    pub action: c_int,
+    pub slot: c_int,
+    pub preposition: c_int,
+    pub name: c_int
+}
+
+// The object that "it" refers to.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastIt {
+    pub last_it: c_int
+}
+
+// The thief and the sword.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hack {
+    pub thief_position: c_int,
+    pub thief_flag: Logical,
+    pub thief_active: Logical,
+    pub sword_active: Logical,
+    pub sword_state: c_int
+}
+
+// The Royal Puzzle.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Puzzle {
+    pub directions: [c_int; 16],
+    pub walls: [c_int; 8],
+
+    // The contents of each square of the puzzle.
+    #[serde(with = "BigArray")]
+    pub squares: [c_int; 64]
+}
+
+// The rose room's rotating wall (the "scol").
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Screen {
+    pub from_direction: c_int,
+    pub scol_room: c_int,
+    pub scol_active: c_int,
+    pub scol_directions: [c_int; 8],
+    pub scol_walls: [c_int; 12]
+}
+
+// The story flags (trollf, cagesf, ...) and switches (btief, binff, ...).
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Flags {
+    #[serde(with = "BigArray")]
+    pub flags: [Logical; 46],
+    pub switches: [c_int; 22]
+}
+
+// Villains, like the troll and the thief.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Villains {
+    limit: c_int,
+    pub villains: [c_int; 4],
+
+    // The chance of the villain waking up.
+    pub probability: [c_int; 4],
+    pub opponents: [c_int; 4],
+    pub best_weapon: [c_int; 4],
+    pub melee: [c_int; 4]
+}
+
+// Events that happen after a number of turns.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClockEvents {
+    limit: c_int,
+
+    // Turns until the event happens.
+    pub ticks: [c_int; 25],
+    pub actions: [c_int; 25],
+
+    // Whether the event is running.
+    pub flags: [Logical; 25]
+}
+
+// Objects that are in more than one room, like the river.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiRoomObjects {
+    limit: c_int,
+    pub objects: [c_int; 20],
+    pub rooms: [c_int; 20]
+}
+
+// Debugging switches, set by GDT.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DebugFlags {
+    pub debug_flag: c_int,
+    pub parse_flag: c_int,
+    pub gdt_flag: c_int
+}
+
+// The exit found by the last findxt_().
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurrentExit {
+    pub kind: c_int,
+    pub room: c_int,
+    pub message: c_int,
+    pub action: c_int,
+    pub object: c_int
+}
+
+// The last line of input.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputBuffer {
+    pub length: c_int,
+    #[serde(with = "BigArray")]
+    pub buffer: [c_char; 78]
+}
+
+// Where each message is in the text database.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Messages {
+    limit: c_int,
+    pub location: c_int,
+    #[serde(with = "BigArray")]
+    pub text: [c_int; 1050]
+}
+
+// The travel table, which the rooms' exits point into.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Exits {
+    limit: c_int,
+    #[serde(with = "BigArray")]
+    pub travel: [c_int; 900]
+}
+
+// The words of the command being parsed: its verb, objects and prepositions.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParsedWords {
+    pub action: c_int,
+    pub object_1: c_int,
+    pub object_2: c_int,
+    pub preposition_1: c_int,
+    pub preposition_2: c_int
+}
+
+// The syntax of the verb being parsed, unpacked by unpack_().
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyntaxVec {
+    pub verb_flags: c_int,
+    pub direct_object: c_int,
+    pub direct_flags_1: c_int,
+    pub direct_flags_2: c_int,
+    pub direct_fwim_1: c_int,
+    pub direct_fwim_2: c_int,
+    pub indirect_object: c_int,
+    pub indirect_flags_1: c_int,
+    pub indirect_flags_2: c_int,
+    pub indirect_fwim_1: c_int,
+    pub indirect_fwim_2: c_int
+}
+
+// The version of the game, which the text database and saved games must match.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct Version {
+    pub major: c_int,
+    pub minor: c_int,
+    pub edit: c_int
+}
+
 // Info about all adventurers. (There are multiple...?)
 #[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Adventurers {
     limit: c_int,
     
     // The current room for this adventurer.
-    rooms: [c_int; 4],
+    pub rooms: [c_int; 4],
     
     // The adventurer's score.
-    scores: [c_int; 4],
-    vehicles: [c_int; 4],
+    pub scores: [c_int; 4],
+    pub vehicles: [c_int; 4],
     
     // The current object referred to by the word "it".
-    current_its: [c_int; 4],
-    actions: [c_int; 4],
-    strengths: [c_int; 4],
-    flags: [c_int; 4]
+    pub current_its: [c_int; 4],
+    pub actions: [c_int; 4],
+    pub strengths: [c_int; 4],
+    pub flags: [c_int; 4]
 }
 
 // Info about a particular adventurer.
@@ -201,6 +492,16 @@ pub struct AdventurerEntry {
     pub flags: c_int
 }
 
+impl Messages {
+    pub fn len(&self) -> usize {
+        self.limit as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 impl Adventurers {
     // Get an adventurer by their ID. Ids start at 1.
     pub fn get(&self, id: usize) -> AdventurerEntry {
@@ -242,6 +543,10 @@ impl Adventurers {
     pub fn len(&self) -> usize {
         self.limit as usize
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 impl AdventurerEntry {
@@ -253,7 +558,7 @@ impl AdventurerEntry {
 
 // A structure that stores info during parsing.
 #[repr(C)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParseVec {
     pub parse_a: c_int,
     pub parse_i: c_int,
@@ -265,6 +570,30 @@ pub struct ParseVec {
     pub parse_continue: c_int,
 }
 
+impl Logical {
+    pub const TRUE: Logical = Logical(1);
+    pub const FALSE: Logical = Logical(0);
+
+    /// Like `bool::from()`, but any nonzero value is true instead of a panic.
+    /// For values that didn't come from the C code.
+    pub fn is_true(self) -> bool {
+        self.0 != 0
+    }
+}
+
+// Saved as a bool, so that serialized state is readable.
+impl Serialize for Logical {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(self.is_true())
+    }
+}
+
+impl<'de> Deserialize<'de> for Logical {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Logical, D::Error> {
+        bool::deserialize(deserializer).map(Logical::from)
+    }
+}
+
 impl From<bool> for Logical {
     fn from(other: bool) -> Logical {
         if other {
@@ -285,8 +614,36 @@ impl From<Logical> for bool {
     }
 }
 
+/// The vocabulary arrays the C code has, in the order parse.h had them:
+/// buzvoc_, prpvoc_, dirvoc_, avoc, vvoc and ovoc.
+pub fn vocab_arrays() -> [&'static [c_int]; 6] {
+    unsafe { [&buzvoc_, &prpvoc_, &dirvoc_, &avoc, &vvoc, &ovoc] }
+}
+
 /// Returns true if the input is nonzero.
 pub fn c_int_to_bool(input: c_int) -> bool {
     input != 0
 }
 
+// Writes the names of the set flags, like "TAKEBT|VISIBT", for the Debug impls
+// of flag sets. Bits without a name are written as a number.
+pub(crate) fn fmt_flags<F>(flags: &F, f: &mut fmt::Formatter) -> fmt::Result
+    where F: bitflags::Flags<Bits = c_int>
+{
+    let mut names: Vec<String> = flags.iter_names().map(|(name, _)| name.to_string()).collect();
+    let mut unknown = flags.bits() & !F::all().bits();
+    // Flag words with bit 15 set are stored as negative 16-bit numbers in the
+    // data file, so the upper bits are just copies of bit 15.
+    if flags.bits() as i16 as c_int == flags.bits() {
+        unknown &= 0xffff;
+    }
+    if unknown != 0 {
+        names.push(format!("{:#x}", unknown));
+    }
+    if names.is_empty() {
+        write!(f, "(empty)")
+    } else {
+        write!(f, "{}", names.join("|"))
+    }
+}
+
@@ -1,36 +1,54 @@
 
 use libc::{c_char, c_int};
+use std::fmt;
 use std::ops::Range;
+use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
 use core;
 use ffi;
 
 // All the metadata about all objects. Stored as parallel arrays.
 #[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Objects {
     limit: c_int,
+    #[serde(with = "BigArray")]
     pub description_1: [c_int; 220],
+    #[serde(with = "BigArray")]
     pub description_2: [c_int; 220],
+    #[serde(with = "BigArray")]
     pub desco: [c_int; 220],
+    #[serde(with = "BigArray")]
     pub action: [c_int; 220],
+    #[serde(with = "BigArray")]
     pub flags_1: [c_int; 220],
+    #[serde(with = "BigArray")]
     pub flags_2: [c_int; 220],
+    #[serde(with = "BigArray")]
     pub fval: [c_int; 220],
+    #[serde(with = "BigArray")]
     pub tval: [c_int; 220],
 
     // The object's size.
+    #[serde(with = "BigArray")]
     pub size: [c_int; 220],
 
     // The amount the object can hold, if it can hold anything.
+    #[serde(with = "BigArray")]
     pub capacity: [c_int; 220],
 
     // The room the object is in.
+    #[serde(with = "BigArray")]
     pub room: [c_int; 220],
 
     // The adventurer that is holding this object, or 0 otherwise.
+    #[serde(with = "BigArray")]
     pub adventurer: [c_int; 220],
 
     // The container this object is in, or 0 otherwise.
+    #[serde(with = "BigArray")]
     pub container: [c_int; 220],
+    #[serde(with = "BigArray")]
     pub read: [c_int; 220]
 }
 
@@ -43,8 +61,8 @@ pub struct ObjectEntry {
     pub description_2: c_int,
     pub desco: c_int,
     pub action: c_int,
-    pub flags_1: c_int,
-    pub flags_2: c_int,
+    pub flags_1: ObjectFlags1,
+    pub flags_2: ObjectFlags2,
     pub fval: c_int,
     pub tval: c_int,
 
@@ -65,9 +83,66 @@ pub struct ObjectEntry {
     pub read: c_int
 }
 
+bitflags! {
+    /// The bits of oflag1, named as in vars.h.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct ObjectFlags1: c_int {
+        const VISIBT = 1 << 15;
+        const READBT = 1 << 14;
+        const TAKEBT = 1 << 13;
+        const DOORBT = 1 << 12;
+        /// The object's contents can be seen even when it's closed.
+        const TRANBT = 1 << 11;
+        const FOODBT = 1 << 10;
+        /// The object isn't described when the room is.
+        const NDSCBT = 1 << 9;
+        const DRNKBT = 1 << 8;
+        const CONTBT = 1 << 7;
+        /// The object gives off light when it's on.
+        const LITEBT = 1 << 6;
+        /// The object can be attacked (attacking it is a crime).
+        const VICTBT = 1 << 5;
+        const BURNBT = 1 << 4;
+        /// The object is on fire.
+        const FLAMBT = 1 << 3;
+        const TOOLBT = 1 << 2;
+        const TURNBT = 1 << 1;
+        const ONBT = 1 << 0;
+    }
+}
+
+bitflags! {
+    /// The bits of oflag2, named as in vars.h.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct ObjectFlags2: c_int {
+        /// The object can be reached from inside a vehicle.
+        const FINDBT = 1 << 15;
+        const SLEPBT = 1 << 14;
+        /// The thief won't take the object.
+        const SCRDBT = 1 << 13;
+        const TIEBT = 1 << 12;
+        const CLMBBT = 1 << 11;
+        const ACTRBT = 1 << 10;
+        const WEAPBT = 1 << 9;
+        const FITEBT = 1 << 8;
+        const VILLBT = 1 << 7;
+        const STAGBT = 1 << 6;
+        /// TAKE is tried on the object even though it isn't takeable, so its
+        /// action can handle it.
+        const TRYBT = 1 << 5;
+        /// Not tested anywhere in the game.
+        const NOCHBT = 1 << 4;
+        const OPENBT = 1 << 3;
+        /// The object has been touched (taken or moved) by the player.
+        const TCHBT = 1 << 2;
+        const VEHBT = 1 << 1;
+        const SCHBT = 1 << 0;
+    }
+}
+
 // This struct has info about which objects are "global" (objects 193+)
 #[repr(C)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GlobalObjects {
     pub mbase: c_int,
     start_index: c_int
@@ -106,8 +181,8 @@ impl Objects {
             description_2: self.description_2[index],
             desco: self.desco[index],
             action: self.action[index],
-            flags_1: self.flags_1[index],
-            flags_2: self.flags_2[index],
+            flags_1: ObjectFlags1::from_bits_retain(self.flags_1[index]),
+            flags_2: ObjectFlags2::from_bits_retain(self.flags_2[index]),
             fval: self.fval[index],
             tval: self.tval[index],
             size: self.size[index],
@@ -126,8 +201,8 @@ impl Objects {
         self.description_2[index] = values.description_2;
         self.desco[index] = values.desco;
         self.action[index] = values.action;
-        self.flags_1[index] = values.flags_1;
-        self.flags_2[index] = values.flags_2;
+        self.flags_1[index] = values.flags_1.bits();
+        self.flags_2[index] = values.flags_2.bits();
         self.fval[index] = values.fval;
         self.tval[index] = values.tval;
         self.size[index] = values.size;
@@ -142,19 +217,23 @@ impl Objects {
         self.limit as usize
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     // Returns an iterator over all objects.
-    pub fn iter(&self) -> Iter {
+    pub fn iter(&self) -> Iter<'_> {
         Iter {
-            parent: &self,
+            parent: self,
             inner: 0 .. self.len()
         }
     }
 
     // Returns an iterator over global objects.
-    pub fn global_ids(&self) -> Iter {
+    pub fn global_ids(&self) -> Iter<'_> {
         let start = unsafe { ffi::global_items.get_start_id() };
         Iter {
-            parent: &self,
+            parent: self,
             inner: (start - 1) .. self.len()
         }
     }
@@ -166,31 +245,6 @@ impl ObjectEntry {
         self.index + 1
     }
 
-    // This bit is set if the object is visible.
-    pub fn is_visible(&self) -> bool {
-        ffi::c_int_to_bool(self.flags_1 & (1 << 15))
-    }
-
-    // This bit is set if the object is transparent.
-    pub fn is_transparent(&self) -> bool {
-        ffi::c_int_to_bool(self.flags_1 & (1 << 11))
-    }
-    
-    // This bit is set if the object can be reached (by the player for grabbing).
-    pub fn get_find_bit(&self) -> bool {
-        ffi::c_int_to_bool(self.flags_2 & (1 << 15))
-    }
-
-    // This bit is set if the object is a container and it's open.
-    pub fn is_open(&self) -> bool {
-        ffi::c_int_to_bool(self.flags_2 & (1 << 3))
-    }
-
-    // This bit is set if the object is searchable.
-    pub fn is_searchable(&self) -> bool {
-        ffi::c_int_to_bool(self.flags_2 & (1 << 0))
-    }
-
     // True if the given noun/adjective pair describes this object.
     pub fn matches(&self, noun: usize, adjective: usize) -> bool {
         trace!("calling thisit_({}, {}, {}, {})", noun, adjective, self.get_id(), 0);
@@ -207,7 +261,63 @@ impl ObjectEntry {
         trace!("qhere_ returned {}", ret);
         ret
     }
+}
 
+// Defines a getter and a setter for each flag. Setters change this entry only;
+// use Objects::set to write it back.
+macro_rules! flag_accessors {
+    ($($field:ident: $flags:ident { $($get:ident, $set:ident => $flag:ident;)* })*) => {
+        impl ObjectEntry {
+            $($(
+                pub fn $get(&self) -> bool {
+                    self.$field.contains($flags::$flag)
+                }
+
+                pub fn $set(&mut self, value: bool) {
+                    self.$field.set($flags::$flag, value)
+                }
+            )*)*
+        }
+    }
+}
+
+flag_accessors! {
+    flags_1: ObjectFlags1 {
+        is_visible, set_visible => VISIBT;
+        is_readable, set_readable => READBT;
+        is_takeable, set_takeable => TAKEBT;
+        is_door, set_door => DOORBT;
+        is_transparent, set_transparent => TRANBT;
+        is_food, set_food => FOODBT;
+        is_undescribed, set_undescribed => NDSCBT;
+        is_drinkable, set_drinkable => DRNKBT;
+        is_container, set_container => CONTBT;
+        is_light_source, set_light_source => LITEBT;
+        is_victim, set_victim => VICTBT;
+        is_burnable, set_burnable => BURNBT;
+        is_burning, set_burning => FLAMBT;
+        is_tool, set_tool => TOOLBT;
+        is_turnable, set_turnable => TURNBT;
+        is_on, set_on => ONBT;
+    }
+    flags_2: ObjectFlags2 {
+        is_findable, set_findable => FINDBT;
+        is_sleeping, set_sleeping => SLEPBT;
+        is_sacred, set_sacred => SCRDBT;
+        is_tieable, set_tieable => TIEBT;
+        is_climbable, set_climbable => CLMBBT;
+        is_actor, set_actor => ACTRBT;
+        is_weapon, set_weapon => WEAPBT;
+        is_fighting, set_fighting => FITEBT;
+        is_villain, set_villain => VILLBT;
+        is_staggered, set_staggered => STAGBT;
+        is_try_take, set_try_take => TRYBT;
+        is_no_check, set_no_check => NOCHBT;
+        is_open, set_open => OPENBT;
+        is_touched, set_touched => TCHBT;
+        is_vehicle, set_vehicle => VEHBT;
+        is_searchable, set_searchable => SCHBT;
+    }
 }
 
 impl<'a> Iterator for Iter<'a> {
@@ -224,3 +334,15 @@ impl<'a> Iterator for Iter<'a> {
 }
 
 impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl fmt::Debug for ObjectFlags1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ffi::fmt_flags(self, f)
+    }
+}
+
+impl fmt::Debug for ObjectFlags2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ffi::fmt_flags(self, f)
+    }
+}
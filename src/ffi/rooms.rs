@@ -0,0 +1,181 @@
+use libc::c_int;
+use std::fmt;
+use std::ops::Range;
+use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
+use core;
+use ffi;
+
+// All the metadata about all rooms. Stored as parallel arrays.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rooms {
+    limit: c_int,
+    #[serde(with = "BigArray")]
+    pub description_1: [c_int; 200],
+
+    // The message with the room's short name.
+    #[serde(with = "BigArray")]
+    pub description_2: [c_int; 200],
+
+    // Where the room's exits start in the travel table (exits_).
+    #[serde(with = "BigArray")]
+    pub exits: [c_int; 200],
+    #[serde(with = "BigArray")]
+    pub action: [c_int; 200],
+
+    // The points for entering the room, if any are left.
+    #[serde(with = "BigArray")]
+    pub value: [c_int; 200],
+    #[serde(with = "BigArray")]
+    pub flags: [c_int; 200]
+}
+
+// All the metadata about one room.
+#[derive(Debug, Clone)]
+pub struct RoomEntry {
+    index: usize,
+
+    pub description_1: c_int,
+
+    // The message with the room's short name.
+    pub description_2: c_int,
+
+    // Where the room's exits start in the travel table (exits_).
+    pub exits: c_int,
+    pub action: c_int,
+
+    // The points for entering the room, if any are left.
+    pub value: c_int,
+    pub flags: RoomFlags
+}
+
+bitflags! {
+    /// The bits of rflag, named as in vars.h.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct RoomFlags: c_int {
+        /// The player has been here.
+        const RSEEN = 1 << 15;
+        /// The room is lit without a lamp.
+        const RLIGHT = 1 << 14;
+        const RLAND = 1 << 13;
+        const RWATER = 1 << 12;
+        const RAIR = 1 << 11;
+        /// The thief doesn't go here.
+        const RSACRD = 1 << 10;
+        /// Water can be taken from here.
+        const RFILL = 1 << 9;
+        /// The room was destroyed; rrand holds the message that says so.
+        const RMUNG = 1 << 8;
+        /// The room is inside the bucket.
+        const RBUCK = 1 << 7;
+        const RHOUSE = 1 << 6;
+        const RNWALL = 1 << 5;
+        /// Part of the endgame.
+        const REND = 1 << 4;
+    }
+}
+
+pub struct Iter<'a> {
+    parent: &'a Rooms,
+    inner: Range<usize>
+}
+
+impl Rooms {
+    /// Gets a room by its id. Room ids start at 1.
+    pub fn get(&self, id: usize) -> RoomEntry {
+        if id > self.len() {
+            error!("Room id {} is greater than room count ({}).", id, self.len());
+            core::exit_program();
+        }
+        if id == 0 {
+            error!("Room id cannot be 0.");
+            core::exit_program();
+        }
+
+        let index = id - 1;
+
+        RoomEntry {
+            index,
+
+            description_1: self.description_1[index],
+            description_2: self.description_2[index],
+            exits: self.exits[index],
+            action: self.action[index],
+            value: self.value[index],
+            flags: RoomFlags::from_bits_retain(self.flags[index])
+        }
+    }
+
+    pub fn set(&mut self, values: &RoomEntry) {
+        let index = values.index;
+
+        self.description_1[index] = values.description_1;
+        self.description_2[index] = values.description_2;
+        self.exits[index] = values.exits;
+        self.action[index] = values.action;
+        self.value[index] = values.value;
+        self.flags[index] = values.flags.bits();
+    }
+
+    pub fn len(&self) -> usize {
+        self.limit as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Returns an iterator over all rooms.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            parent: self,
+            inner: 0 .. self.len()
+        }
+    }
+}
+
+impl RoomEntry {
+    // Get the ID for this room.
+    pub fn get_id(&self) -> usize {
+        self.index + 1
+    }
+
+    // The room is lit by itself. It may still be lit by a lamp or another light
+    // source that is in it (see lit_()).
+    pub fn is_lit(&self) -> bool {
+        self.flags.contains(RoomFlags::RLIGHT)
+    }
+
+    // The player has been in the room.
+    pub fn is_seen(&self) -> bool {
+        self.flags.contains(RoomFlags::RSEEN)
+    }
+
+    // The room is part of the endgame.
+    pub fn is_endgame(&self) -> bool {
+        self.flags.contains(RoomFlags::REND)
+    }
+
+    pub fn is_sacred(&self) -> bool {
+        self.flags.contains(RoomFlags::RSACRD)
+    }
+
+    pub fn is_munged(&self) -> bool {
+        self.flags.contains(RoomFlags::RMUNG)
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = RoomEntry;
+
+    fn next(&mut self) -> Option<RoomEntry> {
+        self.inner.next().map(|index| self.parent.get(index + 1))
+    }
+}
+
+impl fmt::Debug for RoomFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ffi::fmt_flags(self, f)
+    }
+}
@@ -0,0 +1,93 @@
+
+//! Where the game's files are: the text database, and saved games.
+
+use std::ffi::CString;
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::{Mutex, MutexGuard};
+use crc32fast;
+use libc::c_char;
+
+/// The text database the game was built with.
+pub static BUILT_IN_DATA: &[u8] = include_bytes!("../c_src/dtextc.dat");
+
+/// The name of the saved game, in the save directory.
+pub const SAVE_FILE: &str = "dsave.dat";
+
+struct Files {
+    data_file: Option<PathBuf>,
+    save_dir: PathBuf,
+
+    // The same path, for the C code. The pointer handed out stays valid until
+    // the path is changed.
+    data_file_c: Option<CString>,
+}
+
+lazy_static! {
+    static ref FILES: Mutex<Files> = Mutex::new(Files {
+        data_file: None,
+        save_dir: PathBuf::from("."),
+        data_file_c: None,
+    });
+}
+
+/// Uses the text database (dtextc.dat) at `path` instead of the built-in copy.
+/// With `None`, the built-in copy is used again.
+pub fn set_data_file<P: AsRef<Path>>(path: Option<P>) {
+    let mut files = lock();
+    files.data_file = path.map(|path| path.as_ref().to_path_buf());
+    files.data_file_c = files.data_file.as_ref().map(|path| to_c_string(path));
+}
+
+/// The text database given to `set_data_file()`, if any.
+pub fn data_file() -> Option<PathBuf> {
+    lock().data_file.clone()
+}
+
+/// Keeps saved games in `dir`, instead of the working directory.
+pub fn set_save_dir<P: AsRef<Path>>(dir: P) {
+    lock().save_dir = dir.as_ref().to_path_buf();
+}
+
+/// The directory saved games are kept in.
+pub fn save_dir() -> PathBuf {
+    lock().save_dir.clone()
+}
+
+/// The path of the saved game in the default slot.
+pub fn save_file() -> PathBuf {
+    lock().save_dir.join(SAVE_FILE)
+}
+
+/// The CRC-32 of the text database in use. Saved games record it, since they
+/// refer to messages by their offset in the database.
+///
+/// This reads the whole file each time; `textdb::data_hash()` keeps the hash
+/// for the running game.
+pub fn data_hash() -> io::Result<u32> {
+    match data_file() {
+        Some(path) => Ok(crc32fast::hash(&fs::read(path)?)),
+        None => Ok(crc32fast::hash(BUILT_IN_DATA)),
+    }
+}
+
+/// The text database path for the C code, or null to use the built-in copy.
+pub fn data_file_ptr() -> *const c_char {
+    match lock().data_file_c {
+        Some(ref path) => path.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+fn lock() -> MutexGuard<'static, Files> {
+    FILES.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+// Paths can't contain NUL on unix, so this only fails for nonsense input.
+fn to_c_string(path: &Path) -> CString {
+    CString::new(path.as_os_str().as_bytes())
+        .unwrap_or_else(|_| panic!("Path contains a NUL byte: {:?}", path))
+}
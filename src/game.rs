@@ -0,0 +1,271 @@
+
+//! Runs the game in-process, one command at a time.
+//!
+//! The C code is written as one long loop that reads its own input, so the
+//! game runs on a thread of its own. `Game::step()` hands that thread a line
+//! of input and waits until the game asks for the next one (or ends).
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::io;
+use core;
+use ffi;
+use input::{self, InputSource};
+use output::{self, BufferSink, OutputSink, TeeSink};
+
+// The C globals can only hold one game at a time.
+static GAME_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Everything the game printed in response to some input, plus a summary of
+/// the player's situation afterwards.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TurnOutput {
+    /// The text printed since the last input, without the prompt.
+    pub text: String,
+
+    /// The room the player is in.
+    pub room: usize,
+
+    /// The player's score.
+    pub score: i32,
+
+    /// The number of moves taken so far.
+    pub moves: i32,
+
+    /// True if the game has ended (by death, quitting, or winning).
+    pub game_over: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GameError {
+    /// Another `Game` is still running in this process.
+    AlreadyRunning,
+
+    /// init_() failed. Contains the text it printed.
+    InitFailed(String),
+
+    /// The game has already ended.
+    GameOver,
+}
+
+// Messages sent from the game thread to the `Game`.
+#[derive(Debug)]
+enum HostEvent {
+    // The game printed some text and is now waiting for a line of input.
+    WaitingForInput(String),
+
+    // The game has ended. `initialized` is false if init_() failed.
+    Finished { text: String, initialized: bool },
+}
+
+// The game thread's input. Hands the text printed so far to the `Game`, and
+// waits for the next command.
+struct HostSource {
+    output: BufferSink,
+    commands: Receiver<String>,
+    events: Sender<HostEvent>,
+}
+
+/// A game of Dungeon, driven by calling `step()`.
+pub struct Game {
+    commands: Option<Sender<String>>,
+    events: Receiver<HostEvent>,
+    thread: Option<JoinHandle<()>>,
+    intro: TurnOutput,
+    over: bool,
+}
+
+impl Game {
+    /// Initializes a new game, and runs it until it waits for the first
+    /// command. Only one game can exist at a time.
+    pub fn new() -> Result<Game, GameError> {
+        Game::start(None)
+    }
+
+    /// Like `new()`, but the game's text is also written to `sink` (for
+    /// example, a transcript file).
+    pub fn with_sink(sink: Box<dyn OutputSink>) -> Result<Game, GameError> {
+        Game::start(Some(sink))
+    }
+
+    fn start(sink: Option<Box<dyn OutputSink>>) -> Result<Game, GameError> {
+        if GAME_RUNNING.swap(true, Ordering::SeqCst) {
+            return Err(GameError::AlreadyRunning);
+        }
+
+        let (command_sender, command_receiver) = mpsc::channel();
+        let (event_sender, event_receiver) = mpsc::channel();
+
+        let thread = thread::Builder::new()
+            .name("zork".to_string())
+            .spawn(move || run_game(command_receiver, event_sender, sink))
+            .expect("Unable to spawn game thread");
+
+        let mut game = Game {
+            commands: Some(command_sender),
+            events: event_receiver,
+            thread: Some(thread),
+            intro: TurnOutput::default(),
+            over: false,
+        };
+
+        game.intro = match game.events.recv() {
+            Ok(HostEvent::WaitingForInput(text)) => game.turn_output(text),
+            Ok(HostEvent::Finished { text, initialized: true }) => {
+                game.finish();
+                game.turn_output(text)
+            }
+            Ok(HostEvent::Finished { text, initialized: false }) => {
+                game.finish();
+                return Err(GameError::InitFailed(text));
+            }
+            Err(_) => {
+                game.finish();
+                return Err(GameError::InitFailed(String::new()));
+            }
+        };
+
+        Ok(game)
+    }
+
+    /// The text printed when the game started (the welcome message and the
+    /// first room description).
+    pub fn intro(&self) -> &TurnOutput {
+        &self.intro
+    }
+
+    /// True once the game has ended. No more commands can be given.
+    pub fn is_over(&self) -> bool {
+        self.over
+    }
+
+    /// Gives the game a line of input, and returns everything it printed
+    /// before asking for the next one.
+    ///
+    /// Some commands ask a question before finishing (like QUIT); in that case
+    /// the answer is the next step.
+    pub fn step(&mut self, command: &str) -> Result<TurnOutput, GameError> {
+        if self.over {
+            return Err(GameError::GameOver);
+        }
+
+        debug!("Game::step({:?})", command);
+        let sent = match self.commands {
+            Some(ref commands) => commands.send(command.to_string()).is_ok(),
+            None => false,
+        };
+        if !sent {
+            self.finish();
+            return Err(GameError::GameOver);
+        }
+
+        match self.events.recv() {
+            Ok(HostEvent::WaitingForInput(text)) => Ok(self.turn_output(text)),
+            Ok(HostEvent::Finished { text, .. }) => {
+                self.finish();
+                Ok(self.turn_output(text))
+            }
+            Err(_) => {
+                self.finish();
+                Err(GameError::GameOver)
+            }
+        }
+    }
+
+    // Summarizes the state of the game. The game thread must be waiting for
+    // input, or finished.
+    fn turn_output(&self, text: String) -> TurnOutput {
+        let (room, score, moves) = unsafe {
+            let winner = ffi::player.winner as usize;
+            let score = if winner >= 1 && winner <= ffi::adventurers.len() {
+                ffi::adventurers.get(winner).score
+            } else {
+                0
+            };
+            (ffi::player.current_room as usize, score, ffi::game_state.moves)
+        };
+
+        TurnOutput {
+            text,
+            room,
+            score,
+            moves,
+            game_over: self.over,
+        }
+    }
+
+    // Waits for the game thread to stop, and frees up the C globals for the
+    // next game.
+    fn finish(&mut self) {
+        self.over = true;
+        self.commands = None;
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                error!("Game thread panicked.");
+            }
+            GAME_RUNNING.store(false, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Drop for Game {
+    fn drop(&mut self) {
+        // Dropping the command channel makes the game thread exit the next
+        // time it asks for input.
+        self.finish();
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GameError::AlreadyRunning => write!(f, "Another game is already running."),
+            GameError::InitFailed(ref text) => write!(f, "Initialization failed: {}", text),
+            GameError::GameOver => write!(f, "The game is over."),
+        }
+    }
+}
+
+impl InputSource for HostSource {
+    // The pending output has already been flushed to `self.output`. If the
+    // `Game` has been dropped, the input has ended.
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let text = self.output.take();
+        if self.events.send(HostEvent::WaitingForInput(text)).is_err() {
+            return Ok(None);
+        }
+        Ok(self.commands.recv().ok())
+    }
+
+    fn shows_prompt(&self) -> bool {
+        false
+    }
+}
+
+// The body of the game thread.
+fn run_game(commands: Receiver<String>, events: Sender<HostEvent>,
+            extra_sink: Option<Box<dyn OutputSink>>) {
+    // Capture the game's text for the duration of the game.
+    let buffer = BufferSink::new();
+    let previous_sink = match extra_sink {
+        Some(sink) => output::set_sink(Box::new(TeeSink::new(buffer.clone(), sink))),
+        None => output::set_sink(Box::new(buffer.clone())),
+    };
+
+    let previous_source = input::set_source(Box::new(HostSource {
+        output: buffer.clone(),
+        commands,
+        events: events.clone(),
+    }));
+
+    let initialized = core::run_game();
+
+    input::set_source(previous_source);
+    output::set_sink(previous_sink);
+    let _ = events.send(HostEvent::Finished {
+        text: buffer.take(),
+        initialized,
+    });
+}
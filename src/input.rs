@@ -0,0 +1,271 @@
+
+//! Where the game's input comes from.
+//!
+//! Every line the game reads (commands, answers to yes/no questions, and GDT
+//! prompts) is taken from the installed `InputSource`. When the source runs
+//! out, the game ends as configured by `set_end_of_input()`.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::mem;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::{Mutex, MutexGuard};
+use output;
+
+/// Something that provides lines of input.
+pub trait InputSource: Send {
+    /// Reads the next line, without the line ending. Returns `Ok(None)` once
+    /// there is no more input.
+    fn read_line(&mut self) -> io::Result<Option<String>>;
+
+    /// True if the ">" prompt should be printed before reading a command.
+    fn shows_prompt(&self) -> bool {
+        true
+    }
+}
+
+/// What to do when the input runs out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EndOfInput {
+    /// End the game.
+    #[default]
+    Exit,
+
+    /// Save the game (as the SAVE command does), then end it.
+    SaveAndExit,
+}
+
+/// Reads from stdin. This is the default source.
+#[derive(Debug, Default)]
+pub struct StdinSource;
+
+/// Reads lines from a file or any other `BufRead`.
+#[derive(Debug)]
+pub struct ScriptSource<R> {
+    reader: R,
+    echo: bool,
+}
+
+/// Takes lines from an iterator.
+pub struct IterSource<I> {
+    lines: I,
+}
+
+/// Takes lines from a channel. Input ends when every sender is dropped.
+#[derive(Debug)]
+pub struct ChannelSource {
+    receiver: Receiver<String>,
+}
+
+/// Reads from `first` until it runs out, then from `second`.
+#[derive(Debug)]
+pub struct ChainSource<A, B> {
+    first: Option<A>,
+    second: B,
+}
+
+/// Reads from another source, but never shows the prompt. Used when nobody is
+/// watching, such as batch runs.
+#[derive(Debug)]
+pub struct NoPrompt<S> {
+    source: S,
+}
+
+lazy_static! {
+    // The installed source. Its lock is held while a line is read, which can
+    // take as long as the player does, so nothing else is kept under it.
+    static ref SOURCE: Mutex<Box<dyn InputSource>> = Mutex::new(Box::new(StdinSource));
+}
+
+// Whether the installed source shows the prompt, as of the last time it was
+// installed or read from.
+static SHOWS_PROMPT: AtomicBool = AtomicBool::new(true);
+
+// What to do once the source is exhausted.
+static END_OF_INPUT: Mutex<EndOfInput> = Mutex::new(EndOfInput::Exit);
+
+/// Installs a new source, and returns the old one. If a line is being read
+/// from the old one, this waits until it has been read.
+pub fn set_source(source: Box<dyn InputSource>) -> Box<dyn InputSource> {
+    let mut installed = lock_source();
+    SHOWS_PROMPT.store(source.shows_prompt(), Ordering::SeqCst);
+    mem::replace(&mut *installed, source)
+}
+
+/// Sets what happens when the input runs out, and returns the old setting.
+pub fn set_end_of_input(end_of_input: EndOfInput) -> EndOfInput {
+    mem::replace(&mut *lock_end_of_input(), end_of_input)
+}
+
+/// What happens when the input runs out.
+pub fn end_of_input() -> EndOfInput {
+    *lock_end_of_input()
+}
+
+/// Reads the next line from the installed source.
+pub fn read_line() -> io::Result<Option<String>> {
+    let mut source = lock_source();
+    let line = source.read_line();
+    SHOWS_PROMPT.store(source.shows_prompt(), Ordering::SeqCst);
+    line
+}
+
+/// True if the installed source wants the ">" prompt. Doesn't wait for a
+/// line being read.
+pub fn shows_prompt() -> bool {
+    SHOWS_PROMPT.load(Ordering::SeqCst)
+}
+
+// A poisoned lock only means a panic while reading; the source is still there.
+fn lock_source() -> MutexGuard<'static, Box<dyn InputSource>> {
+    SOURCE.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+fn lock_end_of_input() -> MutexGuard<'static, EndOfInput> {
+    END_OF_INPUT.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+// Reads a line from `reader` and strips the line ending.
+fn read_buffered_line<R: BufRead>(reader: &mut R) -> io::Result<Option<String>> {
+    let mut line = String::with_capacity(80);
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    if line.ends_with('\n') {
+        line.pop();
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+    Ok(Some(line))
+}
+
+impl InputSource for Box<dyn InputSource> {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        (**self).read_line()
+    }
+
+    fn shows_prompt(&self) -> bool {
+        (**self).shows_prompt()
+    }
+}
+
+impl InputSource for StdinSource {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        read_buffered_line(&mut io::stdin().lock())
+    }
+}
+
+impl<R: BufRead + Send> ScriptSource<R> {
+    pub fn new(reader: R) -> ScriptSource<R> {
+        ScriptSource {
+            reader,
+            echo: false,
+        }
+    }
+
+    /// Prints every line as it is read, as if it had been typed.
+    pub fn echo(mut self) -> ScriptSource<R> {
+        self.echo = true;
+        self
+    }
+}
+
+impl ScriptSource<BufReader<File>> {
+    /// Reads the script in the file at `path`, one command per line.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<ScriptSource<BufReader<File>>> {
+        Ok(ScriptSource::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead + Send> InputSource for ScriptSource<R> {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let line = read_buffered_line(&mut self.reader)?;
+        if self.echo {
+            if let Some(ref line) = line {
+                output::write(line);
+                output::write("\n");
+            }
+        }
+        Ok(line)
+    }
+}
+
+impl<I: Iterator<Item = String> + Send> IterSource<I> {
+    pub fn new<T>(lines: T) -> IterSource<I>
+        where T: IntoIterator<IntoIter = I, Item = String>
+    {
+        IterSource { lines: lines.into_iter() }
+    }
+}
+
+impl<I: Iterator<Item = String> + Send> InputSource for IterSource<I> {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        Ok(self.lines.next())
+    }
+}
+
+impl<I> fmt::Debug for IterSource<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IterSource")
+    }
+}
+
+impl ChannelSource {
+    pub fn new(receiver: Receiver<String>) -> ChannelSource {
+        ChannelSource { receiver }
+    }
+}
+
+impl InputSource for ChannelSource {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        Ok(self.receiver.recv().ok())
+    }
+}
+
+impl<A: InputSource, B: InputSource> ChainSource<A, B> {
+    pub fn new(first: A, second: B) -> ChainSource<A, B> {
+        ChainSource {
+            first: Some(first),
+            second,
+        }
+    }
+}
+
+impl<A: InputSource, B: InputSource> InputSource for ChainSource<A, B> {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        if let Some(ref mut first) = self.first {
+            if let Some(line) = first.read_line()? {
+                return Ok(Some(line));
+            }
+        }
+        self.first = None;
+        self.second.read_line()
+    }
+
+    fn shows_prompt(&self) -> bool {
+        match self.first {
+            Some(ref first) => first.shows_prompt(),
+            None => self.second.shows_prompt(),
+        }
+    }
+}
+
+impl<S: InputSource> NoPrompt<S> {
+    pub fn new(source: S) -> NoPrompt<S> {
+        NoPrompt { source }
+    }
+}
+
+impl<S: InputSource> InputSource for NoPrompt<S> {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        self.source.read_line()
+    }
+
+    fn shows_prompt(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,367 @@
+//! The parser's vocabulary in readable form: listings of the tables in
+//! `replacement::vocab` (which parse.h's arrays are made from), and lookups
+//! that show what a word means to the parser.
+//!
+//! Only the first six characters of a word count, so words that share them
+//! are the same word to the parser; `lookup()` and `collisions()` report
+//! those. And the lexer
+//! doesn't take '0', so the few words in the tables with one (like PDP10)
+//! are never read at all.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use libc::c_int;
+use ffi::objects::{ObjectFlags1, ObjectFlags2};
+use names;
+use replacement::vocab::{self, Syntax, Verb, VerbObjectDesc};
+use travel;
+
+/// The kinds of word, in the order the parser tries them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WordKind {
+    Buzzword,
+    Verb,
+    Direction,
+    Preposition,
+    Adjective,
+    Object,
+}
+
+impl WordKind {
+    pub const ALL: [WordKind; 6] = [
+        WordKind::Buzzword, WordKind::Verb, WordKind::Direction, WordKind::Preposition,
+        WordKind::Adjective, WordKind::Object,
+    ];
+
+    /// The name of the kind's section, like "verbs".
+    pub fn section(self) -> &'static str {
+        match self {
+            WordKind::Buzzword => "buzzwords",
+            WordKind::Verb => "verbs",
+            WordKind::Direction => "directions",
+            WordKind::Preposition => "prepositions",
+            WordKind::Adjective => "adjectives",
+            WordKind::Object => "objects",
+        }
+    }
+
+    /// The kind whose section this is, if there is one.
+    pub fn from_section(section: &str) -> Option<WordKind> {
+        WordKind::ALL.iter().cloned().find(|kind| kind.section() == section)
+    }
+}
+
+impl fmt::Display for WordKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let section = self.section();
+        write!(f, "{}", &section[..section.len() - 1])
+    }
+}
+
+/// A word of the vocabulary, and what it means.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Meaning {
+    pub kind: WordKind,
+
+    /// The word as the table spells it, in uppercase.
+    pub word: String,
+
+    /// What the word means, one line at a time.
+    pub lines: Vec<String>,
+
+    /// Whether the lexer ever gives the word's tokens. It doesn't for words
+    /// with a '0' in their first six characters.
+    pub readable: bool,
+}
+
+/// What the parser makes of a word, from `lookup()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lookup {
+    /// The word as it was typed, in uppercase.
+    pub word: String,
+
+    /// The word as the parser sees it: its first six characters.
+    pub truncated: String,
+    pub tokens: [c_int; 2],
+
+    /// Whether the lexer takes the word. It doesn't take '0', so the game
+    /// answers any command with one with "I don't understand that."
+    pub readable: bool,
+
+    /// The words in the vocabulary with the same tokens, in the order the
+    /// parser tries them.
+    pub meanings: Vec<Meaning>,
+}
+
+impl Lookup {
+    /// The words in the vocabulary that are spelled differently, but are the
+    /// same word to the parser.
+    pub fn collisions(&self) -> Vec<&str> {
+        let mut words: Vec<&str> = Vec::new();
+        for meaning in &self.meanings {
+            if meaning.word != self.word && !words.contains(&&meaning.word[..]) {
+                words.push(&meaning.word);
+            }
+        }
+        words
+    }
+}
+
+impl fmt::Display for Lookup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} is {} to the parser (tokens {}, {}).", self.word, self.truncated,
+                 self.tokens[0], self.tokens[1])?;
+        if !self.readable {
+            writeln!(f, "The lexer doesn't take '0', so the game never reads {}; it answers \
+                         \"I don't understand that.\"", self.word)?;
+        }
+        if self.meanings.is_empty() {
+            return writeln!(f, "It isn't in the vocabulary.");
+        }
+        for meaning in &self.meanings {
+            match meaning.lines.first() {
+                Some(line) => writeln!(f, "{} {}: {}", meaning.kind, meaning.word, line)?,
+                None => writeln!(f, "{} {} (ignored)", meaning.kind, meaning.word)?,
+            }
+            for line in meaning.lines.iter().skip(1) {
+                writeln!(f, "    {}", line)?;
+            }
+        }
+        let collisions = self.collisions();
+        if !collisions.is_empty() {
+            writeln!(f, "Only the first six characters count, so {} is the same word as {}.",
+                     self.word, collisions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// What the parser makes of `word`, or `None` if it isn't a word the
+/// vocabulary could have (it's empty, or has a character other than a
+/// letter, digit or '-').
+pub fn lookup(word: &str) -> Option<Lookup> {
+    let tokens = vocab::encode_name(word)?;
+    let meanings = meanings().into_iter()
+        .filter(|&(word_tokens, _)| word_tokens == tokens)
+        .map(|(_, meaning)| meaning)
+        .collect();
+    Some(Lookup {
+        word: word.to_uppercase(),
+        truncated: vocab::decode(tokens),
+        tokens,
+        readable: vocab::encode(word).is_some(),
+        meanings,
+    })
+}
+
+/// Every set of words in the vocabulary that the parser can't tell apart:
+/// different words with the same first six characters, and words that are
+/// in more than one table. Each set is in the order the parser tries them.
+pub fn collisions() -> Vec<Vec<Meaning>> {
+    let mut by_tokens: BTreeMap<[c_int; 2], Vec<Meaning>> = BTreeMap::new();
+    for (tokens, meaning) in meanings() {
+        by_tokens.entry(tokens).or_default().push(meaning);
+    }
+    by_tokens.into_values().filter(|meanings| meanings.len() > 1).collect()
+}
+
+/// The kinds of word `word` is in the vocabulary, in the order the parser
+/// tries them. It is none of them if the lexer doesn't take it.
+pub fn kinds(word: &str) -> Vec<WordKind> {
+    let tokens = match vocab::encode(word) {
+        Some(tokens) => tokens,
+        None => return Vec::new(),
+    };
+    let is = |name: &str| vocab::table_tokens(name) == tokens;
+    let found = [
+        (WordKind::Buzzword, vocab::BUZZWORDS.iter().any(|buzzword| is(buzzword.name))),
+        (WordKind::Verb, vocab::VERBS.iter().any(|verb| verb.names.iter().any(|name| is(name)))),
+        (WordKind::Direction, vocab::DIRECTIONS.iter().any(|direction| is(direction.name))),
+        (WordKind::Preposition,
+         vocab::PREPOSITIONS.iter().any(|preposition| preposition.names.iter().any(|name| is(name)))),
+        (WordKind::Adjective, vocab::ADJECTIVES.iter().any(|adjective| is(adjective.name))),
+        (WordKind::Object, vocab::OBJECTS.iter().any(|object| is(object.name))),
+    ];
+    found.iter().filter(|&&(_, found)| found).map(|&(kind, _)| kind).collect()
+}
+
+/// A listing of one kind of word, with what each one means.
+pub fn listing(kind: WordKind) -> String {
+    let mut text = String::new();
+    if kind == WordKind::Verb {
+        // Synonyms share their syntaxes, so they're listed together.
+        for verb in vocab::VERBS {
+            let names: Vec<String> = verb.names.iter().map(|name| name.to_uppercase()).collect();
+            writeln!(text, "{}", names.join(", ")).unwrap();
+            for line in describe_verb(verb) {
+                writeln!(text, "    {}", line).unwrap();
+            }
+        }
+        return text;
+    }
+
+    for (_, meaning) in meanings().into_iter().filter(|(_, meaning)| meaning.kind == kind) {
+        match meaning.lines.first() {
+            Some(line) => writeln!(text, "{:<12} {}", meaning.word, line).unwrap(),
+            None => writeln!(text, "{}", meaning.word).unwrap(),
+        }
+        if !meaning.readable {
+            writeln!(text, "    (the lexer doesn't take '0', so this is never read)").unwrap();
+        }
+    }
+    text
+}
+
+/// Every word in the vocabulary, with its tokens, in the order the parser
+/// tries them.
+fn meanings() -> Vec<([c_int; 2], Meaning)> {
+    let mut meanings = Vec::new();
+    let mut add = |kind, name: &str, lines: Vec<String>| {
+        let tokens = vocab::table_tokens(name);
+        let readable = vocab::is_readable(tokens);
+        meanings.push((tokens, Meaning { kind, word: name.to_uppercase(), lines, readable }));
+    };
+
+    for buzzword in vocab::BUZZWORDS {
+        add(WordKind::Buzzword, buzzword.name, Vec::new());
+    }
+    for verb in vocab::VERBS {
+        for name in verb.names {
+            let names: Vec<String> = verb.names.iter().map(|name| name.to_uppercase()).collect();
+            let mut lines = vec![names.join(", ")];
+            lines.extend(describe_verb(verb));
+            add(WordKind::Verb, name, lines);
+        }
+    }
+    for direction in vocab::DIRECTIONS {
+        let line = match travel::Direction::from_code(direction.id << 10) {
+            Some(travel_direction) => format!("{} ({})", direction.id, travel_direction.name()),
+            None => direction.id.to_string(),
+        };
+        add(WordKind::Direction, direction.name, vec![line]);
+    }
+    for preposition in vocab::PREPOSITIONS {
+        let names: Vec<String> =
+            preposition.names.iter().map(|name| name.to_uppercase()).collect();
+        for name in preposition.names {
+            let line = format!("{} ({})", preposition.id, names.join(", "));
+            add(WordKind::Preposition, name, vec![line]);
+        }
+    }
+    for adjective in vocab::ADJECTIVES {
+        add(WordKind::Adjective, adjective.name, vec![describe_objects(adjective.objects)]);
+    }
+    for object in vocab::OBJECTS {
+        add(WordKind::Object, object.name, vec![describe_objects(object.objects)]);
+    }
+    meanings
+}
+
+// The objects an adjective or noun describes, with their names from oindex_.
+fn describe_objects(objects: &[c_int]) -> String {
+    let objects: Vec<String> = objects.iter().map(|&object| match names::object(object) {
+        Some(name) => format!("{} ({})", object, name),
+        None => object.to_string(),
+    }).collect();
+    objects.join(", ")
+}
+
+// A verb's syntaxes, a line for the syntax and one for each of its objects.
+fn describe_verb(verb: &Verb) -> Vec<String> {
+    let mut lines = Vec::new();
+    for syntax in verb.syntaxes {
+        lines.push(describe_syntax(verb.names[0], syntax));
+        if let Some(direct) = syntax.direct {
+            lines.push(format!("    direct object: {}", describe_object(&direct)));
+        }
+        if let Some(indirect) = syntax.indirect {
+            lines.push(format!("    indirect object: {}", describe_object(&indirect)));
+        }
+    }
+    if let Some(count) = verb.slot_count {
+        lines.push(format!("(its entry has only {} slots, so the parser reads the next verb's \
+                            words as the rest)", count));
+    }
+    lines
+}
+
+// A syntax as it would be typed, like "PUT <direct> IN <indirect>", and its
+// action.
+fn describe_syntax(name: &str, syntax: &Syntax) -> String {
+    let mut words = vec![name.to_uppercase()];
+    for &(object, placeholder) in &[(syntax.direct, "<direct>"), (syntax.indirect, "<indirect>")] {
+        if let Some(object) = object {
+            if object.preposition != 0 {
+                words.push(preposition_name(object.preposition));
+            }
+            words.push(placeholder.to_string());
+        }
+    }
+
+    let mut line = format!("{}: action {}", words.join(" "), syntax.action);
+    if syntax.flip {
+        line.push_str(", swapped (the second object is the direct one)");
+    }
+    if syntax.driver {
+        line.push_str(", the one to fall back on");
+    }
+    line
+}
+
+// The first word for a preposition, or its number if it has none.
+fn preposition_name(id: c_int) -> String {
+    match vocab::PREPOSITIONS.iter().find(|preposition| preposition.id == id) {
+        Some(preposition) => preposition.names[0].to_uppercase(),
+        None => format!("<preposition {}>", id),
+    }
+}
+
+// Where the parser looks for an object, what it does with it, and the flags
+// it wants it to have.
+fn describe_object(object: &VerbObjectDesc) -> String {
+    if *object == VerbObjectDesc::STANDARD {
+        return "standard (search adventurer, search room, reachable)".to_string();
+    }
+
+    let mut parts: Vec<String> = [
+        (object.search_adventurer, "search adventurer"),
+        (object.search_room, "search room"),
+        (object.take, "take"),
+        (object.must_have, "must have"),
+        (object.reachable, "reachable"),
+    ].iter().filter(|&&(set, _)| set).map(|&(_, part)| part.to_string()).collect();
+
+    // Without VEBIT, the flags are only what get-what-I-mean looks for.
+    if object.flags != [-1, -1] {
+        let flags = describe_flags(object.flags);
+        if object.qualified {
+            parts.push(format!("must be one of {}", flags));
+        } else {
+            parts.push(format!("prefers {}", flags));
+        }
+    }
+
+    if parts.is_empty() {
+        "anything".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+// An object description's pair of flag words, as the names of their bits.
+fn describe_flags(flags: [c_int; 2]) -> String {
+    let mut names = Vec::new();
+    for (index, &word) in flags.iter().enumerate() {
+        match word {
+            -1 => names.push(format!("any oflag{}", index + 1)),
+            0 => (),
+            _ if index == 0 => names.push(format!("{:?}", ObjectFlags1::from_bits_retain(word))),
+            _ => names.push(format!("{:?}", ObjectFlags2::from_bits_retain(word))),
+        }
+    }
+    if names.is_empty() {
+        "no flags".to_string()
+    } else {
+        names.join("|")
+    }
+}
@@ -1,17 +1,46 @@
+#![allow(static_mut_refs)]
+
 #[macro_use]
 extern crate log;
 
 #[macro_use]
 extern crate lazy_static;
 
+#[macro_use]
+extern crate bitflags;
 extern crate chrono;
+extern crate crc32fast;
 extern crate libc;
+extern crate rand_core;
+extern crate rand_pcg;
+extern crate serde;
+extern crate serde_big_array;
+extern crate serde_json;
 extern crate simplelog;
+extern crate toml;
 
 #[allow(unused)]
 pub mod ffi;
 pub mod replacement;
 pub mod core;
 pub mod recording;
+pub mod files;
+pub mod random;
+pub mod clock;
+pub mod input;
+pub mod output;
+pub mod game;
+pub mod saves;
+pub mod undo;
+pub mod state;
+pub mod state_diff;
+pub mod names;
+pub mod travel;
+pub mod map;
+pub mod textdb;
+pub mod datafile;
+pub mod translation;
+pub mod vocabulary;
+pub mod lexicon;
 
-
+pub use game::{Game, GameError, TurnOutput};
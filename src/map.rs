@@ -0,0 +1,198 @@
+//! A map of the dungeon: every room and the exits between them, built from
+//! rooms_ and the travel table, and written out as Graphviz DOT or JSON.
+//!
+//! The text comes from the running game's text database, so a game has to be
+//! running (see `Game`) when the map is built.
+
+use std::fmt::Write;
+use libc::c_int;
+use serde::{Deserialize, Serialize};
+use serde_json;
+use ffi;
+use names;
+use textdb;
+use travel::{self, ExitKind, TravelError};
+
+/// Every room, with its exits.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Map {
+    pub rooms: Vec<MapRoom>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapRoom {
+    pub id: usize,
+
+    /// The room's name in the C code (from rindex_), if it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The room's short description, like "West of House".
+    pub description: String,
+    pub exits: Vec<MapExit>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapExit {
+    /// The direction's name, like "north".
+    pub direction: String,
+    pub kind: MapExitKind,
+
+    /// The room the exit leads to. Exits that can't be taken, and some that
+    /// are worked out by an action, don't have one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<usize>,
+
+    /// The text shown when the exit can't be taken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// For conditional exits, the name of the flag in findex_ that opens them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flag: Option<String>,
+
+    /// For doors, the door's object id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub door: Option<usize>,
+
+    /// For doors, the door's short description, like "kitchen window".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub door_description: Option<String>,
+
+    /// The routine in cxappl_() that decides where the exit goes, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<c_int>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MapExitKind {
+    Normal,
+    No,
+    Conditional,
+    Door,
+}
+
+impl Map {
+    /// Builds the map of the running game.
+    pub fn build() -> Result<Map, TravelError> {
+        let count = unsafe { ffi::rooms.len() };
+        let mut rooms = Vec::with_capacity(count);
+        for id in 1..count + 1 {
+            let room = unsafe { ffi::rooms.get(id) };
+            let exits = travel::room_exits(id)?.into_iter()
+                .map(|exit| map_exit(id, exit))
+                .collect::<Result<_, _>>()?;
+            rooms.push(MapRoom {
+                id,
+                name: names::room(id as c_int).map(str::to_string),
+                description: message(room.description_2),
+                exits,
+            });
+        }
+        Ok(Map { rooms })
+    }
+
+    /// The map as a Graphviz digraph. Rooms are labeled with their short
+    /// descriptions and exits with their directions; conditional exits are
+    /// dashed and doors are bold. Exits that lead nowhere are left out.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph dungeon {\n");
+        dot.push_str("    node [shape=box];\n");
+        for room in &self.rooms {
+            let _ = writeln!(dot, "    r{} [label=\"{}\"];", room.id, escape(&room.description));
+        }
+        for room in &self.rooms {
+            for exit in &room.exits {
+                let target = match exit.target {
+                    Some(target) => target,
+                    None => continue,
+                };
+                let (label, style) = match exit.kind {
+                    MapExitKind::Normal | MapExitKind::No => (exit.direction.clone(), ""),
+                    MapExitKind::Conditional => {
+                        let flag = exit.flag.as_ref().map_or("?", |flag| flag.as_str());
+                        (format!("{} (if {})", exit.direction, flag), ", style=dashed")
+                    }
+                    MapExitKind::Door => {
+                        let door = exit.door_description.as_ref().map_or("door", |door| door.as_str());
+                        (format!("{} ({})", exit.direction, door), ", style=bold")
+                    }
+                };
+                let _ = writeln!(dot, "    r{} -> r{} [label=\"{}\"{}];",
+                                 room.id, target, escape(&label), style);
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The map as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).expect("maps are always serializable");
+        json.push('\n');
+        json
+    }
+}
+
+// The exit as it goes on the map. `room` is the room it's in.
+fn map_exit(room: usize, exit: travel::Exit) -> Result<MapExit, TravelError> {
+    let mut map_exit = MapExit {
+        direction: exit.direction.name().to_string(),
+        kind: MapExitKind::Normal,
+        target: if exit.target == 0 { None } else { Some(exit.target as usize) },
+        message: None,
+        flag: None,
+        door: None,
+        door_description: None,
+        action: if exit.action == 0 { None } else { Some(exit.action) },
+    };
+
+    let text = match exit.kind {
+        ExitKind::Normal => 0,
+        ExitKind::No(text) => {
+            map_exit.kind = MapExitKind::No;
+            text
+        }
+        ExitKind::Conditional(flag, text) => {
+            map_exit.kind = MapExitKind::Conditional;
+            map_exit.flag = Some(flag_name(flag));
+            text
+        }
+        ExitKind::Door(door, text) => {
+            // objects.get() would end the program, and the tool isn't a game.
+            if door < 1 || door as usize > unsafe { ffi::objects.len() } {
+                return Err(TravelError::NoSuchDoor(room, door));
+            }
+            map_exit.kind = MapExitKind::Door;
+            map_exit.door = Some(door as usize);
+            map_exit.door_description = Some(message(unsafe {
+                ffi::objects.get(door as usize).description_2
+            }));
+            text
+        }
+    };
+    if text != 0 {
+        map_exit.message = Some(message(text));
+    }
+    Ok(map_exit)
+}
+
+// The name of a flag in findex_, counting from 1.
+fn flag_name(flag: c_int) -> String {
+    let name = names::FLAGS.get((flag - 1) as usize).filter(|_| flag >= 1);
+    match name {
+        Some(name) => name.to_string(),
+        None => format!("flag {}", flag),
+    }
+}
+
+// The text of a message, without the line breaks around it.
+fn message(id: c_int) -> String {
+    textdb::current().message(id).trim().to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
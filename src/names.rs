@@ -0,0 +1,101 @@
+
+//! The names the C code gives to objects, rooms, etc., taken from the index
+//! blocks in vars.h (oindex_, rindex_, ...).
+
+use libc::c_int;
+
+/// Objects, from oindex_.
+pub const OBJECTS: &[(usize, &str)] = &[
+    (2, "GARLI"), (3, "FOOD"), (4, "GUNK"), (5, "COAL"), (7, "MACHI"), (8, "DIAMO"), (9, "TCASE"),
+    (10, "BOTTL"), (11, "WATER"), (12, "ROPE"), (13, "KNIFE"), (14, "SWORD"), (15, "LAMP"),
+    (16, "BLAMP"), (17, "RUG"), (18, "LEAVE"), (19, "TROLL"), (20, "AXE"), (21, "RKNIF"),
+    (23, "KEYS"), (26, "BAR"), (30, "ICE"), (33, "COFFI"), (34, "TORCH"), (35, "TBASK"),
+    (36, "FBASK"), (39, "IRBOX"), (42, "GHOST"), (45, "TRUNK"), (46, "BELL"), (47, "BOOK"),
+    (48, "CANDL"), (51, "MATCH"), (54, "TUBE"), (55, "PUTTY"), (56, "WRENC"), (57, "SCREW"),
+    (58, "CYCLO"), (59, "CHALI"), (61, "THIEF"), (62, "STILL"), (63, "WINDO"), (65, "GRATE"),
+    (66, "DOOR"), (71, "HPOLE"), (75, "RAILI"), (78, "LEAK"), (79, "RBUTT"), (85, "POT"),
+    (86, "STATU"), (87, "IBOAT"), (88, "DBOAT"), (89, "PUMP"), (90, "RBOAT"), (92, "STICK"),
+    (94, "BUOY"), (96, "SHOVE"), (97, "GUANO"), (98, "BALLO"), (99, "RECEP"), (101, "BROPE"),
+    (102, "HOOK1"), (103, "HOOK2"), (105, "SAFE"), (107, "SSLOT"), (109, "BRICK"), (110, "FUSE"),
+    (111, "GNOME"), (112, "BLABE"), (113, "DBALL"), (119, "TOMB"), (123, "LCASE"), (124, "CAGE"),
+    (125, "RCAGE"), (126, "SPHER"), (127, "SQBUT"), (132, "FLASK"), (133, "POOL"), (134, "SAFFR"),
+    (137, "BUCKE"), (138, "ECAKE"), (139, "ORICE"), (140, "RDICE"), (141, "BLICE"), (142, "ROBOT"),
+    (145, "FTREE"), (148, "BILLS"), (149, "PORTR"), (151, "SCOL"), (152, "ZGNOM"), (154, "EGG"),
+    (155, "BEGG"), (156, "BAUBL"), (157, "CANAR"), (158, "BCANA"), (159, "YLWAL"), (161, "RDWAL"),
+    (164, "PINDR"), (171, "RBEAM"), (172, "ODOOR"), (173, "QDOOR"), (175, "CDOOR"), (178, "NUM1"),
+    (185, "NUM8"), (186, "WARNI"), (187, "CSLIT"), (188, "GCARD"), (189, "STLDR"), (192, "ITOBJ"),
+    (193, "OPLAY"), (194, "EVERY"), (195, "VALUA"), (196, "SAILO"), (197, "TEETH"), (198, "WALL"),
+    (200, "HANDS"), (201, "LUNGS"), (202, "AVIAT"), (205, "WNORT"), (209, "GWATE"), (215, "MASTER"),
+];
+
+/// Rooms, from rindex_.
+pub const ROOMS: &[(usize, &str)] = &[
+    (2, "WHOUS"), (8, "LROOM"), (9, "CELLA"), (10, "MTROL"), (11, "MAZE1"), (25, "MGRAT"),
+    (30, "MAZ15"), (31, "FORE1"), (33, "FORE3"), (36, "CLEAR"), (40, "RESER"), (42, "STREA"),
+    (44, "EGYPT"), (49, "ECHOR"), (61, "TSHAF"), (76, "BSHAF"), (77, "MMACH"), (79, "DOME"),
+    (80, "MTORC"), (83, "CAROU"), (91, "RIDDL"), (94, "LLD2"), (96, "TEMP1"), (97, "TEMP2"),
+    (100, "MAINT"), (101, "MCYCL"), (102, "BLROO"), (103, "TREAS"), (107, "RIVR1"), (108, "RIVR2"),
+    (109, "RIVR3"), (112, "RIVR4"), (113, "RIVR5"), (114, "FCHMP"), (119, "MBARR"), (120, "FALLS"),
+    (121, "MRAIN"), (122, "POG"), (126, "VLBOT"), (127, "VAIR1"), (128, "VAIR2"), (129, "VAIR3"),
+    (130, "VAIR4"), (131, "LEDG2"), (132, "LEDG3"), (133, "LEDG4"), (135, "MSAFE"), (140, "CAGER"),
+    (141, "CAGED"), (142, "TWELL"), (143, "BWELL"), (144, "ALICE"), (145, "ALISM"), (146, "ALITR"),
+    (147, "MTREE"), (148, "BKENT"), (151, "BKVW"), (153, "BKTWI"), (154, "BKVAU"), (155, "BKBOX"),
+    (157, "CRYPT"), (158, "TSTRS"), (159, "MRANT"), (160, "MREYE"), (161, "MRA"), (162, "MRB"),
+    (163, "MRC"), (164, "MRG"), (165, "MRD"), (166, "FDOOR"), (167, "MRAE"), (171, "MRCE"),
+    (172, "MRCW"), (173, "MRGE"), (174, "MRGW"), (176, "MRDW"), (177, "INMIR"), (179, "SCORR"),
+    (182, "NCORR"), (183, "PARAP"), (184, "CELL"), (185, "PCELL"), (186, "NCELL"), (188, "CPANT"),
+    (189, "CPOUT"), (190, "CPUZZ"),
+];
+
+/// Clock events, from cindex_.
+pub const CLOCK_EVENTS: &[(usize, &str)] = &[
+    (1, "CEVCUR"), (2, "CEVMNT"), (3, "CEVLNT"), (4, "CEVMAT"), (5, "CEVCND"), (6, "CEVBAL"),
+    (7, "CEVBRN"), (8, "CEVFUS"), (9, "CEVLED"), (10, "CEVSAF"), (11, "CEVVLG"), (12, "CEVGNO"),
+    (13, "CEVBUC"), (14, "CEVSPH"), (15, "CEVEGH"), (16, "CEVFOR"), (17, "CEVSCL"), (18, "CEVZGI"),
+    (19, "CEVZGO"), (20, "CEVSTE"), (21, "CEVMRS"), (22, "CEVPIN"), (23, "CEVINQ"), (24, "CEVFOL"),
+];
+
+/// Adventurers, from aindex_.
+pub const ADVENTURERS: &[(usize, &str)] = &[
+    (1, "PLAYER"), (2, "AROBOT"), (3, "AMASTR"),
+];
+
+/// The flags in findex_, in order.
+pub const FLAGS: &[&str] = &[
+    "trollf", "cagesf", "bucktf", "caroff", "carozf", "lwtidf", "domef", "glacrf", "echof",
+    "riddlf", "lldf", "cyclof", "magicf", "litldf", "safef", "gnomef", "gnodrf", "mirrmf", "egyptf",
+    "onpolf", "blabf", "brieff", "superf", "buoyf", "grunlf", "gatef", "rainbf", "cagetf", "empthf",
+    "deflaf", "glacmf", "frobzf", "endgmf", "badlkf", "thfenf", "singsf", "mrpshf", "mropnf",
+    "wdopnf", "mr1f", "mr2f", "inqstf", "follwf", "spellf", "cpoutf", "cpushf",
+];
+
+/// The switches in findex_, after the flags.
+pub const SWITCHES: &[&str] = &[
+    "btief", "binff", "rvmnt", "rvclr", "rvcyc", "rvsnd", "rvgua", "orrug", "orcand", "ormtch",
+    "orlamp", "mdir", "mloc", "poleuf", "quesno", "nqatt", "corrct", "lcell", "pnumb", "acell",
+    "dcell", "cphere",
+];
+
+/// The name of object `index` (1-based), if it has one.
+pub fn object(index: c_int) -> Option<&'static str> {
+    find(OBJECTS, index)
+}
+
+/// The name of room `index` (1-based), if it has one.
+pub fn room(index: c_int) -> Option<&'static str> {
+    find(ROOMS, index)
+}
+
+/// The name of clock event `index` (1-based), if it has one.
+pub fn clock_event(index: c_int) -> Option<&'static str> {
+    find(CLOCK_EVENTS, index)
+}
+
+/// The name of adventurer `index` (1-based), if it has one.
+pub fn adventurer(index: c_int) -> Option<&'static str> {
+    find(ADVENTURERS, index)
+}
+
+fn find(names: &'static [(usize, &'static str)], index: c_int) -> Option<&'static str> {
+    names.iter().find(|&&(i, _)| i as c_int == index).map(|&(_, name)| name)
+}
@@ -0,0 +1,225 @@
+
+//! Where the game's text goes.
+//!
+//! All output from the C code (supp_putchar, more_output, supp_printf) is
+//! collected here, and handed to the installed `OutputSink` only when the game
+//! waits for input or exits.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::mem;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Something that receives the game's text.
+pub trait OutputSink: Send {
+    /// Writes some game text. Called once per prompt, with everything printed
+    /// since the last one.
+    fn write_str(&mut self, text: &str) -> io::Result<()>;
+
+    /// Makes sure the text written so far has been delivered.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes to stdout. This is the default sink.
+#[derive(Debug, Default)]
+pub struct StdoutSink;
+
+/// Collects the text in memory. Clones share the same buffer, so one can be
+/// installed while another is used to read the text back.
+#[derive(Clone, Debug, Default)]
+pub struct BufferSink {
+    buffer: Arc<Mutex<String>>,
+}
+
+/// Writes to two sinks; usually a terminal and a transcript file.
+#[derive(Debug)]
+pub struct TeeSink<A, B> {
+    first: A,
+    second: B,
+}
+
+/// Writes to any `io::Write`, such as a transcript file.
+#[derive(Debug)]
+pub struct WriterSink<W> {
+    writer: W,
+}
+
+/// Passes the text to a function.
+pub struct CallbackSink<F> {
+    callback: F,
+}
+
+// The installed sink, and the text that hasn't been given to it yet.
+struct Output {
+    sink: Box<dyn OutputSink>,
+    pending: String,
+}
+
+lazy_static! {
+    static ref OUTPUT: Mutex<Output> = Mutex::new(Output {
+        sink: Box::new(StdoutSink),
+        pending: String::new(),
+    });
+}
+
+/// Installs a new sink, and returns the old one. Any pending text is flushed to
+/// the old sink first.
+pub fn set_sink(sink: Box<dyn OutputSink>) -> Box<dyn OutputSink> {
+    let mut output = lock();
+    output.flush();
+    mem::replace(&mut output.sink, sink)
+}
+
+/// Queues some game text. It is delivered on the next `flush()`.
+pub fn write(text: &str) {
+    lock().pending.push_str(text);
+}
+
+/// Runs `f`, and returns the text it printed instead of delivering it. Used to
+/// get at the game's messages without showing them.
+pub fn capture<F: FnOnce()>(f: F) -> String {
+    let saved = mem::take(&mut lock().pending);
+    f();
+    mem::replace(&mut lock().pending, saved)
+}
+
+/// Delivers all pending text to the sink. Called at every prompt.
+pub fn flush() {
+    lock().flush();
+}
+
+// The game is single threaded, so a poisoned lock only means a panic while
+// writing; the buffer itself is still usable.
+fn lock() -> MutexGuard<'static, Output> {
+    OUTPUT.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+impl Output {
+    fn flush(&mut self) {
+        if !self.pending.is_empty() {
+            let text = mem::take(&mut self.pending);
+            if let Err(err) = self.sink.write_str(&text) {
+                error!("Error writing output: {:?}", err);
+            }
+        }
+        if let Err(err) = self.sink.flush() {
+            error!("Error flushing output: {:?}", err);
+        }
+    }
+}
+
+impl OutputSink for Box<dyn OutputSink> {
+    fn write_str(&mut self, text: &str) -> io::Result<()> {
+        (**self).write_str(text)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (**self).flush()
+    }
+}
+
+impl OutputSink for StdoutSink {
+    fn write_str(&mut self, text: &str) -> io::Result<()> {
+        io::stdout().write_all(text.as_bytes())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+}
+
+impl BufferSink {
+    pub fn new() -> BufferSink {
+        BufferSink::default()
+    }
+
+    /// Removes and returns everything written so far.
+    pub fn take(&self) -> String {
+        mem::take(&mut *self.buffer.lock().unwrap_or_else(|err| err.into_inner()))
+    }
+
+    /// Returns a copy of everything written so far.
+    pub fn contents(&self) -> String {
+        self.buffer.lock().unwrap_or_else(|err| err.into_inner()).clone()
+    }
+}
+
+impl OutputSink for BufferSink {
+    fn write_str(&mut self, text: &str) -> io::Result<()> {
+        self.buffer.lock().unwrap_or_else(|err| err.into_inner()).push_str(text);
+        Ok(())
+    }
+}
+
+impl<A: OutputSink, B: OutputSink> TeeSink<A, B> {
+    pub fn new(first: A, second: B) -> TeeSink<A, B> {
+        TeeSink { first, second }
+    }
+}
+
+impl<A: OutputSink> TeeSink<A, WriterSink<File>> {
+    /// Writes to `first`, and keeps a transcript in the file at `path`.
+    pub fn transcript<P: AsRef<Path>>(first: A, path: P)
+        -> io::Result<TeeSink<A, WriterSink<File>>>
+    {
+        Ok(TeeSink::new(first, WriterSink::new(File::create(path)?)))
+    }
+}
+
+impl<A: OutputSink, B: OutputSink> OutputSink for TeeSink<A, B> {
+    fn write_str(&mut self, text: &str) -> io::Result<()> {
+        // Write to both, even if the first fails.
+        let first = self.first.write_str(text);
+        let second = self.second.write_str(text);
+        first.and(second)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let first = self.first.flush();
+        let second = self.second.flush();
+        first.and(second)
+    }
+}
+
+impl<W: Write + Send> WriterSink<W> {
+    pub fn new(writer: W) -> WriterSink<W> {
+        WriterSink { writer }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write + Send> OutputSink for WriterSink<W> {
+    fn write_str(&mut self, text: &str) -> io::Result<()> {
+        self.writer.write_all(text.as_bytes())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<F: FnMut(&str) + Send> CallbackSink<F> {
+    pub fn new(callback: F) -> CallbackSink<F> {
+        CallbackSink { callback }
+    }
+}
+
+impl<F: FnMut(&str) + Send> OutputSink for CallbackSink<F> {
+    fn write_str(&mut self, text: &str) -> io::Result<()> {
+        (self.callback)(text);
+        Ok(())
+    }
+}
+
+impl<F> fmt::Debug for CallbackSink<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CallbackSink")
+    }
+}
@@ -0,0 +1,61 @@
+
+//! The game's random numbers.
+//!
+//! rnd_() used to call libc's rand(), which was never seeded, so the sequence
+//! depended on the libc in use. It now draws from a seeded PCG generator; the
+//! same seed and the same input always play out the same way.
+
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+use rand_core::{RngCore, SeedableRng};
+use rand_pcg::Pcg32;
+
+struct Random {
+    seed: u64,
+    rng: Pcg32,
+}
+
+lazy_static! {
+    static ref RANDOM: Mutex<Random> = {
+        let seed = clock_seed();
+        Mutex::new(Random {
+            seed,
+            rng: Pcg32::seed_from_u64(seed),
+        })
+    };
+}
+
+/// Restarts the generator from `seed`.
+pub fn set_seed(seed: u64) {
+    debug!("Random seed: {}", seed);
+    let mut random = lock();
+    random.seed = seed;
+    random.rng = Pcg32::seed_from_u64(seed);
+}
+
+/// The seed the generator was last started from. Unless `set_seed()` is
+/// called, it is picked from the system clock.
+pub fn seed() -> u64 {
+    lock().seed
+}
+
+/// Returns a number from 0 to `max - 1`. Returns 0 if `max` is not positive.
+pub fn below(max: i32) -> i32 {
+    if max <= 0 {
+        warn!("Random number requested below {}", max);
+        return 0;
+    }
+    (lock().rng.next_u32() % max as u32) as i32
+}
+
+fn lock() -> MutexGuard<'static, Random> {
+    RANDOM.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+// A seed for games that weren't given one.
+fn clock_seed() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() ^ u64::from(duration.subsec_nanos()),
+        Err(_) => 0,
+    }
+}
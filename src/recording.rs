@@ -1,11 +1,45 @@
 
 use std::fs::{self, File};
-use std::io::Write;
+use std::io::{self, Write};
 use std::path::Path;
 use std::sync::Mutex;
+use random;
+
+/// The first line of a playback file records the random seed, so that the
+/// game can be replayed exactly: "# seed: 1234".
+pub const SEED_PREFIX: &str = "# seed: ";
+
+/// The seed a playback file is replayed with if it has no seed line (it was
+/// recorded before the seed was), so that it still plays out the same way
+/// every time.
+pub const LEGACY_SEED: u64 = 0;
 
 lazy_static! {
-    static ref PLAYBACK_FILE: Mutex<Option<File>> = Mutex::new(Some(create_playback_file()));
+    static ref PLAYBACK_FILE: Mutex<Option<File>> = Mutex::new(None);
+}
+
+/// Starts recording the player's moves to a new playback file, beginning with
+/// the current random seed. Nothing is recorded until this is called.
+pub fn start_recording() {
+    start_recording_to_file(create_playback_file()).unwrap();
+}
+
+/// Like `start_recording()`, but records to the file at `path`, replacing it
+/// if it exists.
+pub fn start_recording_to<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    start_recording_to_file(File::create(path)?)
+}
+
+fn start_recording_to_file(mut file: File) -> io::Result<()> {
+    writeln!(file, "{}{}", SEED_PREFIX, random::seed())?;
+    *PLAYBACK_FILE.lock().unwrap_or_else(|err| err.into_inner()) = Some(file);
+    Ok(())
+}
+
+/// Reads the seed from the first line of a playback file, if it has one.
+pub fn parse_seed(line: &str) -> Option<u64> {
+    line.strip_prefix(SEED_PREFIX)
+        .and_then(|seed| seed.trim().parse().ok())
 }
 
 /// Creates a new file for storing the player's moves. It can be used to reproduce
@@ -14,7 +48,7 @@ pub fn create_playback_file() -> File {
     // Get or create the "playback" directory.
     let playback_dir = Path::new("./playback");
     if !playback_dir.is_dir() {
-        fs::create_dir(&playback_dir).unwrap();
+        fs::create_dir(playback_dir).unwrap();
     }
 
     // Get the next unused playback file.
@@ -33,6 +67,6 @@ pub fn create_playback_file() -> File {
 pub fn record_move(player_move: &str) {
     // Note: This is a single threaded
     if let Some(ref mut file) = *PLAYBACK_FILE.try_lock().unwrap() {
-        write!(file, "{}\n", &player_move).unwrap();
+        writeln!(file, "{}", &player_move).unwrap();
     }
 }
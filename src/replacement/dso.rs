@@ -0,0 +1,33 @@
+
+use libc::c_int;
+use ffi;
+use replacement::supp;
+
+const MINUTES_PER_DAY: c_int = 24 * 60;
+
+// Gets the total time played, in minutes: the time since the game was started
+// or restored, plus the time played before it was saved.
+#[no_mangle]
+pub extern "C-unwind" fn gttime_(total: *mut c_int) {
+    if total.is_null() {
+        error!("null pointer given to gttime_()");
+        supp::exit_();
+    }
+
+    let (mut hours, mut minutes, mut seconds) = (0, 0, 0);
+    supp::itime_(&mut hours, &mut minutes, &mut seconds);
+
+    let mut played = unsafe {
+        hours * 60 + minutes - (ffi::time_.start_hour * 60 + ffi::time_.start_minute)
+    };
+    if played < 0 {
+        // Played past midnight.
+        played += MINUTES_PER_DAY;
+    }
+    played += unsafe { ffi::time_.play_time };
+
+    trace!("gttime_() -> {}", played);
+    unsafe {
+        *total = played;
+    }
+}
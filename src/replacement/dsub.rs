@@ -0,0 +1,60 @@
+
+use std::ptr;
+use libc::c_int;
+use ffi::{self, Logical};
+use output;
+use replacement::supp;
+use textdb;
+use translation;
+
+// Prints a message.
+#[no_mangle]
+pub extern "C-unwind" fn rspeak_(message: c_int) {
+    speak(message, 0, 0);
+}
+
+// Prints a message, with its '#' replaced by another message.
+#[no_mangle]
+pub extern "C-unwind" fn rspsub_(message: c_int, substitution: c_int) {
+    speak(message, substitution, 0);
+}
+
+// Prints a message, with its '#'s replaced by up to two other messages.
+#[no_mangle]
+pub extern "C-unwind" fn rspsb2_(message: c_int, substitution_1: c_int, substitution_2: c_int) {
+    speak(message, substitution_1, substitution_2);
+}
+
+// The body of rspsb2nl_(), for a message on a line of its own. Messages in
+// the translation in use replace the ones in the text database.
+fn speak(message: c_int, substitution_1: c_int, substitution_2: c_int) {
+    let db = textdb::current();
+    let text = match translation::message(message) {
+        Some(text) => text,
+        None if db.is_blank(message) => return,
+        None => match db.get(message) {
+            Some(text) => text.to_string(),
+            None => {
+                error!("Message {} is not in the text database.", message);
+                supp::exit_();
+            }
+        },
+    };
+    trace!("rspsb2_({}, {}, {})", message, substitution_1, substitution_2);
+
+    // Said something.
+    unsafe {
+        ffi::player.tel_flag = Logical::TRUE;
+    }
+
+    // Lines are counted for the "more" prompt, but the lines of the
+    // substituted messages aren't, so each line is substituted on its own.
+    let substitutions = [substitution_1, substitution_2];
+    let mut substitutions = substitutions.iter().cloned().take_while(|&id| id != 0);
+    for line in text.split('\n') {
+        supp::more_output(ptr::null());
+        let mut line = db.substitute_text(line, &mut substitutions, translation::message);
+        line.push('\n');
+        output::write(&line);
+    }
+}
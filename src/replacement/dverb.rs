@@ -0,0 +1,22 @@
+
+use ffi::{self, Logical};
+use saves;
+
+// Saves the game to dsave.dat, or the slot named after SAVE.
+#[no_mangle]
+pub extern "C-unwind" fn savegm_() {
+    // Saving takes no time.
+    unsafe {
+        ffi::parse_vec.parse_won = Logical::FALSE;
+    }
+    saves::save_command(saves::take_slot().as_deref());
+}
+
+// Restores the game from dsave.dat, or the slot named after RESTORE.
+#[no_mangle]
+pub extern "C-unwind" fn rstrgm_() {
+    unsafe {
+        ffi::parse_vec.parse_won = Logical::FALSE;
+    }
+    saves::restore_command(saves::take_slot().as_deref());
+}
@@ -1,7 +1,16 @@
 
+// The functions in these modules replace C functions, and are only called from
+// C, which always passes valid pointers.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
 pub mod supp;
 pub mod np;
-//pub mod vocab;
+pub mod np1;
+pub mod np3;
+pub mod dso;
+pub mod dverb;
+pub mod dsub;
+pub mod vocab;
 
 use libc::c_int;
 
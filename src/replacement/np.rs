@@ -1,8 +1,11 @@
 
+use std::ffi::CStr;
+use std::mem;
 use std::slice;
-use libc::c_int;
-use replacement::supp;
-use ffi;
+use std::sync::Mutex;
+use libc::{c_char, c_int};
+use replacement::{dsub, supp, vocab};
+use ffi::{self, Logical};
 use ffi::objects::ObjectEntry;
 use core;
 
@@ -14,7 +17,7 @@ use core;
 /// the buffer is always at least 78 characters.
 #[no_mangle]
 #[cfg(unix)]
-pub extern "C" fn rdline_(buffer: *mut u8, who: c_int) {
+pub extern "C-unwind" fn rdline_(buffer: *mut u8, who: c_int) {
     trace!("rdline_(*mut u8, {})", who);
 
     if buffer.is_null() {
@@ -29,7 +32,7 @@ pub extern "C" fn rdline_(buffer: *mut u8, who: c_int) {
     // Move up to 77 bytes into the buffer, after trimming whitespace.
     input.truncate(77);
     let temp_vec = vec![0];
-    let iter = input.bytes().chain(temp_vec.into_iter());
+    let iter = input.bytes().chain(temp_vec);
     for (index, c) in iter.enumerate() {
         typed_buffer[index] = c;
     }
@@ -55,7 +58,7 @@ pub extern "C" fn rdline_(buffer: *mut u8, who: c_int) {
 // id!
 #[no_mangle]
 #[allow(non_snake_case)]
-pub extern "C" fn getobj_(noun: c_int, adjective: c_int, special_object: c_int) -> c_int {
+pub extern "C-unwind" fn getobj_(noun: c_int, adjective: c_int, special_object: c_int) -> c_int {
     trace!("getobj_({}, {}, {})", noun, adjective, special_object);
 
     let CHOMP_RETURN = -10000;
@@ -91,9 +94,8 @@ pub extern "C" fn getobj_(noun: c_int, adjective: c_int, special_object: c_int)
             let current_object = objects.get(object_id as usize);
 
             // It's here. Is it reachable?
-            if vehicle == 0 || vehicle == object_id || current_object.get_find_bit() {
-                // We can reach it.
-            } else if current_object.container == vehicle {
+            if vehicle == 0 || vehicle == object_id || current_object.is_findable()
+                || current_object.container == vehicle {
                 // We can reach it.
             } else {
                 // It's here and we can't reach it?
@@ -124,7 +126,7 @@ pub extern "C" fn getobj_(noun: c_int, adjective: c_int, special_object: c_int)
             if let Some(other_id) = ret {
                 if object_id != other_id as i32 {
                     debug!("Found multiple objects, returning object {}.", object_id);
-                    return object_id * -1;
+                    return -object_id;
                 }
             }
 
@@ -151,7 +153,7 @@ pub extern "C" fn getobj_(noun: c_int, adjective: c_int, special_object: c_int)
         if let Some(other_id) = ret {
             if object_id != (other_id as c_int) {
                 debug!("Found multiple objects, returning object {}.", object_id.abs());
-                return object_id * -1;
+                return -object_id;
             }
         }
         
@@ -181,7 +183,7 @@ pub extern "C" fn getobj_(noun: c_int, adjective: c_int, special_object: c_int)
         if global_match && can_be_here {
             if ret.is_some() {
                 debug!("Found multiple objects, returning object {}.", object_id);
-                return (object_id as c_int) * -1;
+                return -(object_id as c_int);
             }
 
             ret = Some(object_id as u32);
@@ -190,10 +192,10 @@ pub extern "C" fn getobj_(noun: c_int, adjective: c_int, special_object: c_int)
 
     if let Some(object_id) = ret {
         debug!("Found object {}", object_id);
-        return object_id as c_int;
+        object_id as c_int
     } else {
         debug!("No object found.");
-        return 0;
+        0
     }
 }
 
@@ -209,7 +211,7 @@ pub extern "C" fn getobj_(noun: c_int, adjective: c_int, special_object: c_int)
 // This is never actually used to search multiple places at the same time, so I've
 // made using multiple places an error.
 #[no_mangle]
-pub extern "C" fn schlst_(noun: c_int, adjective: c_int, room: c_int,
+pub extern "C-unwind" fn schlst_(noun: c_int, adjective: c_int, room: c_int,
                           vehicle: c_int, adventurer: c_int, special_object: c_int) -> c_int
 {
     trace!("schlst_({}, {}, {}, {}, {}, {})", noun, adjective, room, vehicle,
@@ -264,7 +266,7 @@ where F: Fn(&ObjectEntry) -> bool
     let objects = unsafe { &ffi::objects };
 
     let filtered_objects: Vec<_> = objects.iter()
-        .filter(|object| object.is_visible() && f(&object))
+        .filter(|object| object.is_visible() && f(object))
         .collect();
    
     debug!("filtered objects: {:#?}", &filtered_objects);
@@ -275,10 +277,7 @@ where F: Fn(&ObjectEntry) -> bool
             object.get_id() == special_object
                 || object.matches(noun as usize, adjective as usize)
         })
-        .map(|object| {
-            let ret = object.get_id() as c_int;
-            ret
-        })
+        .map(|object| object.get_id() as c_int)
         .collect();
 
     debug!("direct matches: {:?}", &matches);
@@ -295,7 +294,7 @@ where F: Fn(&ObjectEntry) -> bool
     // Collect all the matches together.
     matches.append(&mut indirect_matches);
 
-    if matches.len() == 0 {
+    if matches.is_empty() {
         trace!("search_objects: No objects found.");
         None
     } else if matches.len() == 1 {
@@ -303,146 +302,141 @@ where F: Fn(&ObjectEntry) -> bool
         Some(matches[0])
     } else {
         trace!("search_objects: Multiple matches found: {:?}", matches);
-        Some(matches[0].abs() * -1)
+        Some(-matches[0].abs())
     }
 }
 
+/// The most words a command can have. sparse_() is given 40 tokens, two for
+/// each word.
+pub const MAX_WORDS: usize = 20;
 
+/// A command, as the lexer reads it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Lexed {
+    /// Each word as the pair of Radix-50 tokens sparse_() takes (see
+    /// `vocab::encode()`). Only the first six characters of a word count.
+    pub tokens: Vec<c_int>,
 
-// The lexer.
-// c_output will always have a fixed length of 40.
-// op is a pointer to a single c_int.
-//
-// This lexer will turn words into an array of "opcodes", stored in the
-// c_output array. When it returns, op will contain the last valid index
-// in the opcodes array.
-//
-// Lexing resumes from the character index in ParseVec::parse_continue
-/*pub extern "C" fn lex_(
-    c_input: *const c_char, 
-    c_output: *mut c_int, 
-    op: *mut c_int,
-    verbose_flag: Logical
-) -> Logical {
-    let input = unsafe { CStr::from_ptr(c_input) };
-    let mut output = unsafe { slice::from_raw_parts_mut(c_output, 40) };
-    let verbose = verbose_flag.into();
-
-    // Zero the output buffer.
-    for i in output[..] {
-        i = 0;
-    }
-
-    let checked_input = input.to_str().unwrap();
-
-    trace!("Starting lexer with string: {:?}", &checked_input);
-
-    let mut last_op: c_int = 1;
-    let mut cp = 0;
-    let mut ret = false;
-
-    // TODO: Properly start at the character index given by parse_vec::parse_continue.
-    for (index, c) in checked_input.char_indicies() {
-        // See if we're finished with the current command.
-        if c == '.' || c == ',' {
-            if cp == 0 && last_op == 1 {
-                // We have read no opcodes and no letters.
-                // TODO: I'm pretty sure the only possible value of ret here is
-                // false.
-                return ret.into();
-            } else if cp == 0 {
-                // We have read no letters since the last opcode.
-                // 
-                // TODO: Why -2? Shouldn't this just trim the last one?
-                //
-                // Theory: It's adjusting from "index of the last op" to
-                // "number of ops", in addition to trimming the last one.
-                // If this is correct, then opcode 0 means "no ops were found",
-                // if ret == true in the previous if statement.
-                last_op -= 2;
-            }
-            return true.into();
-        }
-
-        // Check if this is a space.
-        if c == ' ' {
-            if cp == 0 {
-                // Still no word found.
-                continue;
-            }
+    /// The words, in full.
+    pub words: Vec<String>,
+}
 
-            // Word found; advance to next opcode spot.
-            status += 2;
-            op = 0;
-        }
+/// A command the lexer can't read.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LexError {
+    /// The command has no words.
+    Empty,
 
-        // Check if this is a valid character.
-        if c.is_alpha() || (c.is_digit() && c != '0') || c == '-' {
-            if cp >= 6 {
-                // Word is too long; skip until the next space is read
-                // (which will reset cp to 0).
-                continue;
-            }
-            
-            // "Compute word index"?
-            // This procedure seems to be storing between 1 and 3 characters per
-            // "opcode". Words can be 1 to 5 characters long, so they can be either
-            // 1 or 2 opcodes long.
-            //
-            // Note: Intentional integer division.
-            let k = last_op + cp / 3;
-            
-            let converted_char = char_to_number(c);
-            
-            let opcode_adjustment = match cp {
-                0 | 3 => converted_char * 1600,
-                1 | 4 => converted_char * 39,
-                2 => converted_char,
-                _ => unreachabl!()
-            }
+    /// The command has a character other than A to Z, 1 to 9, '-', a space,
+    /// '.' or ','. Holds the character.
+    BadCharacter(u8),
 
-            output[k] += opcode_adjustment;
+    /// The command has more than `MAX_WORDS` words. The C lexer wrote past
+    /// the end of its buffer for these.
+    TooManyWords,
+}
 
-            last_op ++;
+lazy_static! {
+    // The words of the command lex_() read last.
+    static ref WORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
 
+/// Reads the command that starts at `position` (counting from 0) in `input`,
+/// as lex_() does. A command ends at a '.', a ',' or the end of the input.
+///
+/// Returns the command, and where the next one starts. Like prscon, that is
+/// the start of the input again once the last command has been read (or
+/// right after a bad character).
+pub fn lex(input: &[u8], position: usize) -> (Result<Lexed, LexError>, usize) {
+    let mut position = position;
+    let mut lexed = Lexed::default();
+    let mut word = String::new();
+    loop {
+        let c = input.get(position).cloned().unwrap_or(0);
+        if c != 0 {
+            position += 1;
         }
 
-        // Invalid character.
-        debug!("Invalid character: {}", c);
-
-        // Check if we should be verbose.
-        if verbose {
-            trace!("Calling rspeak_(601)");
-            unsafe! {
-                // This prints some error message about an invalid character.
-                ffi::rspeak_(601);
+        match c {
+            0 | b'.' | b',' => {
+                end_word(&mut lexed, &mut word);
+                if input.get(position).cloned().unwrap_or(0) == 0 {
+                    position = 0;
+                }
+                if lexed.words.is_empty() {
+                    return (Err(LexError::Empty), position);
+                }
+                return (Ok(lexed), position);
             }
+            b' ' => end_word(&mut lexed, &mut word),
+            _ if vocab::char_code(c).is_some() => {
+                if word.is_empty() && lexed.words.len() == MAX_WORDS {
+                    return (Err(LexError::TooManyWords), position);
+                }
+                word.push(c as char);
+            }
+            _ => return (Err(LexError::BadCharacter(c)), position),
         }
     }
+}
 
-    // We've reached the end of the input string. Reset the state of the lexer.
-    // TODO: Why 1, instead of 0?
-    unsafe {
-        ffi::parse_vec.parse_continue = 1;
+// Adds the word read so far, if any, to the command.
+fn end_word(lexed: &mut Lexed, word: &mut String) {
+    if let Some(tokens) = vocab::encode(word) {
+        lexed.tokens.extend(&tokens);
+        lexed.words.push(mem::take(word));
     }
-    
-    if cp == 0 && status == 1 {
-        // We have read no opcodes and no letters.
-        // TODO: I'm pretty sure the only way ret is true here, is if
-        // the prompt was blank.
-        return ret.into();
-    } else if cp == 0 {
-        // We have read no letters since the last opcode.
-        // TODO: Why -2? Shouldn't this just trim the last one?
-        // Theory: It's adjusting from "index of the last op" to
-        // "number of ops", in addition to trimming the last one.
-        status -= 2;
-    }
-    return true.into();
 }
-*/
 
+/// The words of the command the game read last, in full.
+pub fn last_words() -> Vec<String> {
+    WORDS.lock().unwrap_or_else(|err| err.into_inner()).clone()
+}
 
+// The lexer. Reads the command that starts at prsvec_.prscon in input into
+// output, as a pair of tokens for each word, and sets op to the index of the
+// last word's first token. output always has room for 40 tokens.
+//
+// Commands with too many words for output are refused, like ones with a bad
+// character.
+#[no_mangle]
+pub extern "C-unwind" fn lex_(input: *const c_char, output: *mut c_int, op: *mut c_int,
+                       vbflag: Logical) -> Logical {
+    if input.is_null() || output.is_null() || op.is_null() {
+        error!("null pointer given to lex_()");
+        supp::exit_();
+    }
 
+    let input = unsafe { CStr::from_ptr(input) }.to_bytes();
+    let output = unsafe { slice::from_raw_parts_mut(output, 2 * MAX_WORDS) };
 
+    // prscon counts from 1.
+    let start = unsafe { ffi::parse_vec.parse_continue.max(1) } as usize - 1;
+    let (lexed, next) = lex(input, start);
+    trace!("lex_({:?}, {}) -> {:?}", String::from_utf8_lossy(input), start, lexed);
+    unsafe {
+        ffi::parse_vec.parse_continue = next as c_int + 1;
+    }
 
+    for token in output.iter_mut() {
+        *token = 0;
+    }
+    match lexed {
+        Ok(lexed) => {
+            output[..lexed.tokens.len()].copy_from_slice(&lexed.tokens);
+            unsafe {
+                *op = lexed.tokens.len() as c_int - 1;
+            }
+            *WORDS.lock().unwrap_or_else(|err| err.into_inner()) = lexed.words;
+            Logical::TRUE
+        }
+        Err(LexError::Empty) => Logical::FALSE,
+        Err(err) => {
+            debug!("Unable to read the command: {:?}", err);
+            if vbflag.is_true() {
+                dsub::rspeak_(601);
+            }
+            Logical::FALSE
+        }
+    }
+}
@@ -0,0 +1,423 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::slice;
+use libc::c_int;
+use replacement::{dsub, np, supp, vocab};
+use ffi::{self, Logical, ParsedWords};
+
+// vindex_.walkw, the action for walking somewhere.
+const WALK: c_int = 142;
+// oindex_.itobj, the object "it" names.
+const IT: c_int = 192;
+// The preposition "of".
+const OF: c_int = 9;
+// What getobj_() returns for an object that can't be reached from the
+// vehicle.
+const UNREACHABLE: c_int = -10000;
+
+/// What the parser makes of a command.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Sparsed {
+    /// A direction to walk in, which prsvec_ is set to.
+    Walk(c_int),
+
+    /// A verb and its objects, in pv_, which synmch_() has to match against
+    /// the verb's syntaxes.
+    Verb,
+}
+
+/// A command the parser can't make sense of. The words are in full, when the
+/// lexer read them, or their first six characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SparseError {
+    /// A word isn't in the vocabulary, or can't be used where it is. Holds
+    /// the word.
+    UnknownWord(String),
+
+    /// A preposition comes right after another one. Holds the second.
+    TooManyPrepositions(String),
+
+    /// A noun names nothing that can be seen here. Holds the noun.
+    NotHere(String),
+
+    /// A noun names something that can't be reached from the vehicle. Holds
+    /// the noun.
+    Unreachable(String),
+
+    /// A noun names more than one thing here. Holds the noun.
+    Ambiguous(String),
+
+    /// A noun comes after two objects. Holds the noun.
+    TooManyObjects(String),
+
+    /// The noun after "of" isn't the object before it. Holds the noun.
+    NotOf(String),
+
+    /// The command ends with an adjective. Holds the adjective.
+    DanglingAdjective(String),
+
+    /// The command has an object but no verb. Holds the object.
+    NoVerb(c_int),
+
+    /// The command has neither a verb nor an object.
+    Nothing,
+
+    /// The command is the object the last one's preposition was missing.
+    /// sparse_() puts it in pv_ and then fails anyway, without a word, so
+    /// this does too.
+    OrphanObject,
+}
+
+// Where the words of the vocabulary are, as sparse_() looks for them. Where
+// a word is in a table twice, the first one counts.
+struct Dictionary {
+    buzzwords: HashSet<[c_int; 2]>,
+
+    // The index (from 1) of the word in vvoc, which pv_ holds as the action.
+    verbs: HashMap<[c_int; 2], c_int>,
+
+    // The verb each of those indexes is a word of.
+    verbs_at: HashMap<c_int, &'static vocab::Verb>,
+
+    // The direction codes.
+    directions: HashMap<[c_int; 2], c_int>,
+
+    prepositions: HashMap<[c_int; 2], c_int>,
+
+    // The index (from 1) of the word in avoc or ovoc, which getobj_() takes.
+    adjectives: HashMap<[c_int; 2], c_int>,
+    objects: HashMap<[c_int; 2], c_int>,
+}
+
+lazy_static! {
+    static ref DICTIONARY: Dictionary = Dictionary::new();
+
+    // The token of "WAL", the start of the verb that can be followed by a
+    // direction.
+    static ref WAL: c_int = vocab::table_tokens("WAL")[0];
+}
+
+impl Dictionary {
+    fn new() -> Dictionary {
+        let mut dictionary = Dictionary {
+            buzzwords: HashSet::new(),
+            verbs: HashMap::new(),
+            verbs_at: HashMap::new(),
+            directions: HashMap::new(),
+            prepositions: HashMap::new(),
+            adjectives: HashMap::new(),
+            objects: HashMap::new(),
+        };
+        let encode = vocab::table_tokens;
+
+        for buzzword in vocab::BUZZWORDS {
+            dictionary.buzzwords.insert(encode(buzzword.name));
+        }
+        let mut array = Vec::new();
+        for verb in vocab::VERBS {
+            for (position, name) in verb.names.iter().enumerate() {
+                let index = (array.len() + 2 * position + 1) as c_int;
+                dictionary.verbs.entry(encode(name)).or_insert(index);
+                dictionary.verbs_at.insert(index, verb);
+            }
+            verb.encode(&mut array);
+        }
+        for direction in vocab::DIRECTIONS {
+            dictionary.directions.entry(encode(direction.name)).or_insert(direction.id << 10);
+        }
+        for preposition in vocab::PREPOSITIONS {
+            for name in preposition.names {
+                dictionary.prepositions.entry(encode(name)).or_insert(preposition.id);
+            }
+        }
+        dictionary.adjectives = indexes(vocab::ADJECTIVES);
+        dictionary.objects = indexes(vocab::OBJECTS);
+        dictionary
+    }
+}
+
+// Where each word of an adjective or object table is in its array.
+fn indexes(table: &[vocab::ObjectDesc]) -> HashMap<[c_int; 2], c_int> {
+    let mut indexes = HashMap::new();
+    let mut array = Vec::new();
+    for word in table {
+        indexes.entry(vocab::table_tokens(word.name)).or_insert(array.len() as c_int + 1);
+        word.encode(&mut array);
+    }
+    indexes
+}
+
+impl SparseError {
+    /// Says why the command can't be parsed, as sparse_() does.
+    pub fn tell(&self) {
+        match *self {
+            SparseError::UnknownWord(_) | SparseError::NotOf(_) => dsub::rspeak_(601),
+            SparseError::TooManyPrepositions(_) => dsub::rspeak_(616),
+            SparseError::NotHere(_) => {
+                let lit = unsafe { ffi::lit_(ffi::player.current_room) }.is_true();
+                dsub::rspeak_(if lit { 618 } else { 579 });
+            }
+            SparseError::Unreachable(_) => {
+                let (vehicle, objects) = unsafe {
+                    (ffi::adventurers.get(ffi::player.winner as usize).vehicle, &ffi::objects)
+                };
+                dsub::rspsub_(620, objects.get(vehicle as usize).description_2);
+            }
+            SparseError::Ambiguous(_) => dsub::rspeak_(619),
+            SparseError::TooManyObjects(_) => dsub::rspeak_(617),
+            SparseError::NoVerb(object) => {
+                let objects = unsafe { &ffi::objects };
+                dsub::rspsub_(621, objects.get(object as usize).description_2);
+            }
+            SparseError::DanglingAdjective(_) | SparseError::Nothing => dsub::rspeak_(622),
+            SparseError::OrphanObject => {}
+        }
+    }
+}
+
+/// Parses a command, as sparse_() does: finds its verb and objects, and puts
+/// them in pv_. A command that is missing something is made an orphan, in
+/// orphs_, for the next one to complete; and the orphan of the last command
+/// can complete this one.
+///
+/// `tokens` has the pair of tokens for each word, as the lexer reads them.
+/// `words` has the words in full, if they are known; they are only used for
+/// errors.
+pub fn sparse(tokens: &[c_int], words: &[String]) -> Result<Sparsed, SparseError> {
+    let mut parsed = ParsedWords {
+        action: 0,
+        object_1: 0,
+        object_2: 0,
+        preposition_1: 0,
+        preposition_2: 0,
+    };
+    let result = parse(tokens, words, &mut parsed);
+    trace!("sparse({:?}) -> {:?}, {:?}", words, result, parsed);
+    unsafe {
+        ffi::parsed_words = parsed;
+    }
+    result
+}
+
+fn parse(tokens: &[c_int], words: &[String], parsed: &mut ParsedWords)
+         -> Result<Sparsed, SparseError> {
+    let dictionary = &*DICTIONARY;
+    let word_count = tokens.len() / 2;
+    let mut adjective = 0;
+    let mut adjective_word = String::new();
+    let mut preposition = 0;
+    let mut object_count = 0;
+    let mut walk = false;
+
+    for (position, pair) in tokens.chunks_exact(2).enumerate() {
+        let tokens = [pair[0], pair[1]];
+        if tokens[0] == 0 {
+            break;
+        }
+        let word = || full_word(tokens, words, position);
+
+        if dictionary.buzzwords.contains(&tokens) {
+            continue;
+        }
+
+        if parsed.action == 0 {
+            if let Some(&action) = dictionary.verbs.get(&tokens) {
+                parsed.action = action;
+                walk = tokens[0] == *WAL;
+                unsafe {
+                    ffi::orphans.action = 0;
+                }
+                continue;
+            }
+        }
+
+        // A direction can only be the first thing after "walk".
+        if parsed.action == 0 || (walk && preposition == 0) {
+            if let Some(&direction) = dictionary.directions.get(&tokens) {
+                unsafe {
+                    ffi::parse_vec.parse_a = WALK;
+                    ffi::parse_vec.parse_o = direction;
+                }
+                return Ok(Sparsed::Walk(direction));
+            }
+        }
+
+        if let Some(&id) = dictionary.prepositions.get(&tokens) {
+            if preposition != 0 {
+                return Err(SparseError::TooManyPrepositions(word()));
+            }
+            preposition = id;
+            adjective = 0;
+            continue;
+        }
+
+        // An adjective at the end of a command can complete an orphan that
+        // is missing one.
+        let noun = if let Some(&index) = dictionary.adjectives.get(&tokens) {
+            adjective = index;
+            adjective_word = word();
+            let orphan = unsafe { ffi::orphans.name & ffi::orphans.flag };
+            if orphan == 0 || position + 1 < word_count {
+                continue;
+            }
+            orphan
+        } else if let Some(&index) = dictionary.objects.get(&tokens) {
+            index
+        } else {
+            return Err(SparseError::UnknownWord(word()));
+        };
+
+        let mut object = np::getobj_(noun, adjective, 0);
+        if object == IT {
+            object = np::getobj_(0, 0, unsafe { ffi::last_it.last_it });
+        }
+        if object == 0 {
+            return Err(SparseError::NotHere(word()));
+        } else if object == UNREACHABLE {
+            return Err(SparseError::Unreachable(word()));
+        } else if object < 0 {
+            if parsed.action == 0 {
+                parsed.action = unsafe { ffi::orphans.flag & ffi::orphans.action };
+            }
+            unsafe {
+                ffi::orphan_(-1, parsed.action, parsed.object_1, preposition, noun);
+            }
+            return Err(SparseError::Ambiguous(word()));
+        }
+
+        if preposition == OF {
+            // "The X of Y" must be about one object. With no object before
+            // it, the C code compared the action instead.
+            let previous = match object_count {
+                0 => parsed.action,
+                1 => parsed.object_1,
+                _ => parsed.object_2,
+            };
+            if previous != object {
+                return Err(SparseError::NotOf(word()));
+            }
+        } else {
+            match object_count {
+                0 => {
+                    parsed.object_1 = object;
+                    parsed.preposition_1 = preposition;
+                }
+                1 => {
+                    parsed.object_2 = object;
+                    parsed.preposition_2 = preposition;
+                }
+                _ => return Err(SparseError::TooManyObjects(word())),
+            }
+            object_count += 1;
+        }
+        preposition = 0;
+        adjective = 0;
+    }
+
+    let orphans = unsafe { ffi::orphans.clone() };
+    if parsed.action == 0 {
+        parsed.action = orphans.flag & orphans.action;
+    }
+    if parsed.action == 0 {
+        if parsed.object_1 == 0 {
+            return Err(SparseError::Nothing);
+        }
+        unsafe {
+            ffi::orphan_(-1, 0, parsed.object_1, 0, 0);
+        }
+        return Err(SparseError::NoVerb(parsed.object_1));
+    }
+    if adjective != 0 {
+        return Err(SparseError::DanglingAdjective(adjective_word));
+    }
+
+    // The orphan was missing an object after a preposition, and this command
+    // is that object. C jumps here before it marks the parse as good.
+    if orphans.flag != 0 && orphans.preposition != 0 && preposition == 0
+        && parsed.object_1 != 0 && parsed.object_2 == 0 && parsed.action == orphans.action {
+        if orphans.slot == 0 {
+            parsed.preposition_1 = orphans.preposition;
+        } else {
+            parsed.object_2 = parsed.object_1;
+            parsed.preposition_2 = orphans.preposition;
+            parsed.object_1 = orphans.slot;
+            parsed.preposition_1 = 0;
+        }
+        return Err(SparseError::OrphanObject);
+    }
+
+    // A preposition at the end goes with the last object, as in "pick the
+    // lamp up", or is missing its object.
+    if preposition != 0 {
+        match object_count {
+            1 if parsed.preposition_1 == 0 => parsed.preposition_1 = preposition,
+            2 if parsed.preposition_2 == 0 => parsed.preposition_2 = preposition,
+            _ => unsafe { ffi::orphan_(-1, parsed.action, 0, preposition, 0) },
+        }
+    }
+    Ok(Sparsed::Verb)
+}
+
+/// The verb whose word is at `action` in vvoc, where the action in pv_ points.
+pub fn verb(action: c_int) -> Option<&'static vocab::Verb> {
+    DICTIONARY.verbs_at.get(&action).cloned()
+}
+
+// The word at `index`, in full if it's in `words`.
+fn full_word(tokens: [c_int; 2], words: &[String], index: usize) -> String {
+    match words.get(index) {
+        Some(word) if vocab::encode(word) == Some(tokens) => word.clone(),
+        _ => vocab::decode(tokens),
+    }
+}
+
+// The parser. Finds the verb and objects of the command that lex_() read into
+// lbuf, where llnt is the index of its last word's first token.
+//
+// Returns 1 for a direction to walk in, 0 for a command that synmch_() has to
+// check, or -1 for one that can't be parsed, after saying why if vbflag is
+// set.
+#[no_mangle]
+pub extern "C-unwind" fn sparse_(lbuf: *const c_int, llnt: c_int, vbflag: Logical) -> c_int {
+    if lbuf.is_null() {
+        error!("null buffer given to sparse_()");
+        supp::exit_();
+    }
+
+    let tokens = unsafe { slice::from_raw_parts(lbuf, (llnt + 1).max(0) as usize) };
+    match sparse(tokens, &np::last_words()) {
+        Ok(Sparsed::Walk(_)) => 1,
+        Ok(Sparsed::Verb) => 0,
+        Err(err) => {
+            debug!("Unable to parse the command: {}", err);
+            if vbflag.is_true() {
+                err.tell();
+            }
+            -1
+        }
+    }
+}
+
+impl fmt::Display for SparseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SparseError::UnknownWord(ref word) => write!(f, "Unknown word: {}", word),
+            SparseError::TooManyPrepositions(ref word) => {
+                write!(f, "Too many prepositions: {}", word)
+            }
+            SparseError::NotHere(ref word) => write!(f, "Nothing here is called {}.", word),
+            SparseError::Unreachable(ref word) => {
+                write!(f, "The {} can't be reached from the vehicle.", word)
+            }
+            SparseError::Ambiguous(ref word) => write!(f, "More than one thing is called {}.", word),
+            SparseError::TooManyObjects(ref word) => write!(f, "Too many objects: {}", word),
+            SparseError::NotOf(ref word) => write!(f, "The {} isn't the object before it.", word),
+            SparseError::DanglingAdjective(ref word) => {
+                write!(f, "The adjective {} has no noun.", word)
+            }
+            SparseError::NoVerb(object) => write!(f, "No verb for object {}.", object),
+            SparseError::Nothing => write!(f, "No verb or object."),
+            SparseError::OrphanObject => write!(f, "The object for the last command's preposition."),
+        }
+    }
+}
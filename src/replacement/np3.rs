@@ -0,0 +1,396 @@
+use std::fmt;
+use std::mem;
+use libc::c_int;
+use replacement::{dsub, np, np1, supp};
+use replacement::vocab::{Syntax, VerbObjectDesc};
+use ffi::{self, Logical, ParsedWords, SyntaxVec};
+use ffi::objects::{ObjectFlags1, ObjectFlags2};
+
+/// Why the verb's syntaxes don't fit the command, or its objects can't be
+/// used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyntaxError {
+    /// No syntax fits, and there is none to fall back on.
+    NoSyntax,
+
+    /// The direct object is missing, and nothing here fits it. Holds the
+    /// preposition it should come after. The command is made an orphan.
+    MissingDirect(c_int),
+
+    /// The indirect object is missing, and nothing here fits it. Holds the
+    /// preposition of the direct object, which the orphan gets. The command is
+    /// made an orphan.
+    MissingIndirect(c_int),
+
+    /// One of the objects can't be taken, or is where it shouldn't be.
+    Take(TakeError),
+}
+
+/// Why an object can't be used the way the syntax wants.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TakeError {
+    /// The object is in a closed container, and has to be reached.
+    Closed(c_int),
+
+    /// The object has to be carried, but can't be taken.
+    NotTakeable(c_int),
+
+    /// The object isn't supposed to be in the room, but is.
+    InRoom(c_int),
+
+    /// The object is the vehicle the adventurer is in.
+    Vehicle(c_int),
+
+    /// The object is too heavy to carry with everything else.
+    TooHeavy(c_int),
+}
+
+impl SyntaxError {
+    /// Says what went wrong, as synmch_() does.
+    pub fn tell(&self) {
+        match *self {
+            SyntaxError::NoSyntax => dsub::rspeak_(601),
+            SyntaxError::MissingDirect(_) => dsub::rspeak_(623),
+            SyntaxError::MissingIndirect(_) => dsub::rspeak_(624),
+            SyntaxError::Take(err) => err.tell(),
+        }
+    }
+}
+
+impl TakeError {
+    /// Says why the object can't be used, as takeit_() does.
+    pub fn tell(&self) {
+        let description = |object: c_int| unsafe {
+            ffi::objects.get(object as usize).description_2
+        };
+        match *self {
+            TakeError::Closed(object) => dsub::rspsub_(566, description(object)),
+            TakeError::NotTakeable(object) => dsub::rspsub_(445, description(object)),
+            TakeError::InRoom(object) => dsub::rspsub_(665, description(object)),
+            TakeError::Vehicle(_) => dsub::rspeak_(672),
+            TakeError::TooHeavy(_) => dsub::rspeak_(558),
+        }
+    }
+}
+
+/// Matches the command sparse_() left in pv_ against the syntaxes of its verb,
+/// as synmch_() does, and sets prsvec_ to the action and objects.
+///
+/// When no syntax fits, the one to fall back on has its missing objects filled
+/// in: from the orphan, or by finding the only thing here that fits
+/// (get-what-I-mean). If there is none, the command is made an orphan.
+/// Objects the syntax wants carried are taken.
+///
+/// Like C, pv_ and syntax_ are left as the search left them, even when it
+/// fails.
+pub fn synmch() -> Result<(), SyntaxError> {
+    let mut parsed = unsafe { ffi::parsed_words.clone() };
+    let result = match_syntax(&mut parsed);
+    trace!("synmch({:?}) -> {:?}", parsed, result);
+    unsafe {
+        ffi::parsed_words = parsed;
+    }
+    result
+}
+
+fn match_syntax(parsed: &mut ParsedWords) -> Result<(), SyntaxError> {
+    let verb = match np1::verb(parsed.action) {
+        Some(verb) => verb,
+        None => {
+            error!("synmch_() called with action {}, which isn't a verb", parsed.action);
+            supp::exit_();
+        }
+    };
+    let orphans = unsafe { ffi::orphans.clone() };
+    let orphan_preposition = orphans.flag & orphans.preposition;
+
+    // The syntax to fall back on, and the last one that was only missing an
+    // object, after the preposition the orphan has (if it has one).
+    let mut driver = None;
+    let mut forced = None;
+    let mut matched = None;
+    for syntax in verb.read_syntaxes() {
+        unpack(syntax);
+        let missing = if !fits(parsed.preposition_1, parsed.object_1, syntax.direct) {
+            if parsed.object_1 != 0 {
+                continue;
+            }
+            preposition(syntax.direct)
+        } else if !fits(parsed.preposition_2, parsed.object_2, syntax.indirect) {
+            if parsed.object_2 != 0 {
+                continue;
+            }
+            preposition(syntax.indirect)
+        } else {
+            matched = Some(syntax);
+            break;
+        };
+        if orphan_preposition == 0 || orphan_preposition == missing {
+            forced = Some(syntax);
+        }
+        if syntax.driver {
+            driver = Some(syntax);
+        }
+    }
+
+    let syntax = match matched {
+        Some(syntax) => syntax,
+        None => {
+            let syntax = driver.or(forced).ok_or(SyntaxError::NoSyntax)?;
+            unpack(syntax);
+            fill_in(parsed, syntax, &orphans)?;
+            syntax
+        }
+    };
+
+    if syntax.flip {
+        mem::swap(&mut parsed.object_1, &mut parsed.object_2);
+    }
+    unsafe {
+        ffi::parse_vec.parse_a = syntax.action;
+        ffi::parse_vec.parse_o = parsed.object_1;
+        ffi::parse_vec.parse_i = parsed.object_2;
+    }
+    take(parsed.object_1, syntax.direct).map_err(SyntaxError::Take)?;
+    take(parsed.object_2, syntax.indirect).map_err(SyntaxError::Take)
+}
+
+// Fills in the objects the syntax wants but the command doesn't have, from
+// the orphan or by get-what-I-mean. If one can't be found, the command is made
+// an orphan.
+fn fill_in(parsed: &mut ParsedWords, syntax: &Syntax, orphans: &ffi::Orphans)
+           -> Result<(), SyntaxError> {
+    if let Some(direct) = syntax.direct {
+        if parsed.object_1 == 0 {
+            parsed.object_1 = orphans.flag & orphans.slot;
+            if parsed.object_1 == 0
+                || !fits(parsed.preposition_1, parsed.object_1, syntax.direct) {
+                parsed.object_1 = gwim(&direct);
+                if parsed.object_1 <= 0 {
+                    unsafe {
+                        ffi::orphan_(-1, parsed.action, 0, direct.preposition, 0);
+                    }
+                    return Err(SyntaxError::MissingDirect(direct.preposition));
+                }
+            }
+        }
+    }
+
+    if let Some(indirect) = syntax.indirect {
+        if parsed.object_2 == 0 {
+            parsed.object_2 = gwim(&indirect);
+            if parsed.object_2 <= 0 {
+                if parsed.object_1 == 0 {
+                    parsed.object_1 = orphans.flag & orphans.slot;
+                }
+                // C gives the orphan the direct object's preposition, not the
+                // indirect one's.
+                let preposition = preposition(syntax.direct);
+                unsafe {
+                    ffi::orphan_(-1, parsed.action, parsed.object_1, preposition, 0);
+                }
+                return Err(SyntaxError::MissingIndirect(preposition));
+            }
+        }
+    }
+    Ok(())
+}
+
+// The preposition an object comes after, or 0 for none.
+fn preposition(object: Option<VerbObjectDesc>) -> c_int {
+    object.map_or(0, |object| object.preposition)
+}
+
+// Puts the syntax in syntax_, the way unpack_() laid it out.
+fn unpack(syntax: &Syntax) {
+    let slots = |object: Option<VerbObjectDesc>| match object {
+        Some(object) => {
+            let [flags_1, flags_2] = object.required_flags();
+            (object.bits(), flags_1, flags_2, object.flags[0], object.flags[1])
+        }
+        None => (0, 0, 0, 0, 0),
+    };
+    let direct = slots(syntax.direct);
+    let indirect = slots(syntax.indirect);
+    unsafe {
+        ffi::syntax_vec = SyntaxVec {
+            verb_flags: syntax.flags(),
+            direct_object: direct.0,
+            direct_flags_1: direct.1,
+            direct_flags_2: direct.2,
+            direct_fwim_1: direct.3,
+            direct_fwim_2: direct.4,
+            indirect_object: indirect.0,
+            indirect_flags_1: indirect.1,
+            indirect_flags_2: indirect.2,
+            indirect_fwim_1: indirect.3,
+            indirect_fwim_2: indirect.4,
+        };
+    }
+}
+
+// Whether an object (or 0 for none), after a preposition (or 0), is what
+// the syntax wants there. As in syneql_(), an object with neither flag word set
+// never fits, and a missing object fits only where none is wanted.
+fn fits(preposition: c_int, object: c_int, wanted: Option<VerbObjectDesc>) -> bool {
+    let (wanted_preposition, [flags_1, flags_2]) = match wanted {
+        Some(wanted) => (wanted.preposition, wanted.required_flags()),
+        None => (0, [0, 0]),
+    };
+    if object == 0 {
+        return preposition == 0 && flags_1 == 0 && flags_2 == 0;
+    }
+    let objects = unsafe { &ffi::objects };
+    let index = object as usize - 1;
+    preposition == wanted_preposition
+        && (flags_1 & objects.flags_1[index] | flags_2 & objects.flags_2[index]) != 0
+}
+
+// Makes sure an object (or 0 for none) can be used the way the syntax wants,
+// as takeit_() does: reached, taken, or left in the room. Global objects
+// always can.
+fn take(object: c_int, wanted: Option<VerbObjectDesc>) -> Result<(), TakeError> {
+    let global_start = unsafe { ffi::global_items.get_start_id() };
+    if object == 0 || object as usize >= global_start {
+        return Ok(());
+    }
+    // Without a description, nothing is wanted of the object.
+    let wanted = wanted.unwrap_or(VerbObjectDesc::new(0));
+    let (here, winner) = unsafe { (ffi::player.current_room, ffi::player.winner) };
+    let entry = unsafe { ffi::objects.get(object as usize) };
+    let container = entry.container;
+
+    if container != 0 && wanted.reachable {
+        let open = unsafe { ffi::objects.get(container as usize) }.flags_2
+            .contains(ObjectFlags2::OPENBT);
+        if !open {
+            return Err(TakeError::Closed(object));
+        }
+    }
+
+    let in_room = || np::schlst_(0, 0, here, 0, 0, object) > 0;
+    match (wanted.search_room, wanted.take) {
+        (true, true) => {
+            if !in_room() {
+                return Ok(());
+            }
+            if !entry.flags_1.contains(ObjectFlags1::TAKEBT)
+                || entry.flags_2.contains(ObjectFlags2::TRYBT) {
+                return if wanted.must_have {
+                    Err(TakeError::NotTakeable(object))
+                } else {
+                    Ok(())
+                };
+            }
+        }
+        (true, false) if !wanted.must_have => return Ok(()),
+        // It shouldn't be in the room.
+        _ => return if in_room() { Err(TakeError::InRoom(object)) } else { Ok(()) },
+    }
+
+    // This is what take_() does, without the side effects.
+    let vehicle = unsafe { ffi::adventurers.get(winner as usize) }.vehicle;
+    if object == vehicle {
+        return Err(TakeError::Vehicle(object));
+    }
+    let carried_container = container != 0
+        && unsafe { ffi::objects.get(container as usize) }.adventurer == winner;
+    if !carried_container
+        && unsafe { ffi::weight_(0, object, winner) + entry.size > ffi::game_state.max_load } {
+        return Err(TakeError::TooHeavy(object));
+    }
+    unsafe {
+        ffi::newsta_(object, 559, 0, 0, winner);
+        let index = object as usize - 1;
+        ffi::objects.flags_2[index] |= ObjectFlags2::TCHBT.bits();
+        ffi::scrupd_(ffi::objects.fval[index]);
+        ffi::objects.fval[index] = 0;
+    }
+    Ok(())
+}
+
+// Get-what-I-mean: finds the one object the adventurer has, or that is in
+// the room, that fits the description, as gwim_() does. Returns 0 if there is
+// none, or -1 if there is more than one or it can't be taken (after saying
+// why).
+fn gwim(wanted: &VerbObjectDesc) -> c_int {
+    let (here, winner) = unsafe { (ffi::player.current_room, ffi::player.winner) };
+    let vehicle = unsafe { ffi::adventurers.get(winner as usize) }.vehicle;
+    let no_care = Logical::from(!wanted.must_have);
+    let [flags_1, flags_2] = wanted.flags;
+
+    let carried = if wanted.search_adventurer {
+        unsafe { ffi::fwim_(flags_1, flags_2, 0, 0, winner, no_care) }
+    } else {
+        0
+    };
+    if !wanted.search_room {
+        return carried;
+    }
+    let found = unsafe { ffi::fwim_(flags_1, flags_2, here, 0, 0, no_care) };
+    if found < 0 {
+        return -1;
+    }
+    if found == 0 {
+        return carried;
+    }
+
+    // Something in the room is no use if the vehicle is in the way.
+    let entry = unsafe { ffi::objects.get(found as usize) };
+    let reachable = vehicle == 0 || found == vehicle
+        || entry.flags_2.contains(ObjectFlags2::FINDBT) || entry.container == vehicle;
+    if !reachable {
+        return carried;
+    }
+    if carried != 0 {
+        return -1;
+    }
+    if let Err(err) = take(found, Some(*wanted)) {
+        err.tell();
+        return -1;
+    }
+    found
+}
+
+// The syntax matcher. Matches the command in pv_ against its verb's syntaxes,
+// and sets prsvec_. Returns false, after saying why, if the command doesn't
+// fit or its objects can't be used.
+#[no_mangle]
+pub extern "C-unwind" fn synmch_() -> Logical {
+    match synmch() {
+        Ok(()) => Logical::TRUE,
+        Err(err) => {
+            debug!("Unable to match the command's syntax: {}", err);
+            err.tell();
+            Logical::FALSE
+        }
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SyntaxError::NoSyntax => write!(f, "No syntax of the verb fits."),
+            SyntaxError::MissingDirect(preposition) => {
+                write!(f, "Missing the direct object, after preposition {}.", preposition)
+            }
+            SyntaxError::MissingIndirect(preposition) => {
+                write!(f, "Missing the indirect object; orphaned with preposition {}.",
+                       preposition)
+            }
+            SyntaxError::Take(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl fmt::Display for TakeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TakeError::Closed(object) => write!(f, "Object {} is in a closed container.", object),
+            TakeError::NotTakeable(object) => write!(f, "Object {} can't be taken.", object),
+            TakeError::InRoom(object) => write!(f, "Object {} shouldn't be in the room.", object),
+            TakeError::Vehicle(object) => write!(f, "Object {} is the vehicle.", object),
+            TakeError::TooHeavy(object) => write!(f, "Object {} is too heavy.", object),
+        }
+    }
+}
@@ -1,34 +1,128 @@
 
 use ffi;
 use core;
-use std::io::{self, Write};
+use output;
+use clock;
+use files;
+use random;
+use translation;
+use undo;
 use std::ffi::CStr;
-use libc::c_char;
+use std::slice;
+use libc::{c_char, c_int};
 
-// Exit the game using exit(0),
+// Ends the game. See core::exit_program().
 #[no_mangle]
-pub extern "C" fn exit_() -> ! {
+pub extern "C-unwind" fn exit_() -> ! {
     trace!("exit_()");
 
     core::exit_program()
 }
 
+// Gets the time of day from the installed clock.
+#[no_mangle]
+pub extern "C-unwind" fn itime_(hours: *mut c_int, minutes: *mut c_int, seconds: *mut c_int) {
+    if hours.is_null() || minutes.is_null() || seconds.is_null() {
+        error!("null pointer given to itime_()");
+        exit_();
+    }
+
+    let (h, m, s) = clock::time_of_day();
+    trace!("itime_() -> {:02}:{:02}:{:02}", h, m, s);
+    unsafe {
+        *hours = h as c_int;
+        *minutes = m as c_int;
+        *seconds = s as c_int;
+    }
+}
+
+// Returns a random number from 0 to maxval - 1.
+#[no_mangle]
+pub extern "C-unwind" fn rnd_(maxval: c_int) -> c_int {
+    let ret = random::below(maxval);
+    trace!("rnd_({}) -> {}", maxval, ret);
+    ret
+}
+
+// The path of the text database, or NULL to use the built-in copy.
+#[no_mangle]
+pub extern "C-unwind" fn supp_textfile() -> *const c_char {
+    files::data_file_ptr()
+}
+
+// The built-in copy of the text database. Its length is stored in size.
+#[no_mangle]
+pub extern "C-unwind" fn supp_textdata(size: *mut c_int) -> *const c_char {
+    if size.is_null() {
+        error!("null pointer given to supp_textdata()");
+        exit_();
+    }
+
+    unsafe {
+        *size = files::BUILT_IN_DATA.len() as c_int;
+    }
+    files::BUILT_IN_DATA.as_ptr() as *const c_char
+}
+
 // Prints a character.
 #[no_mangle]
-pub extern "C" fn supp_putchar(c: c_char) {
+pub extern "C-unwind" fn supp_putchar(c: c_char) {
     let utf: char = (c as u8).into();
-    print!("{}", utf);
-    io::stdout().flush().unwrap();
+    let mut buffer = [0; 4];
+    output::write(utf.encode_utf8(&mut buffer));
+}
+
+// The format supp_printf uses in place of fmt: its translation, if there is
+// one.
+#[no_mangle]
+pub extern "C-unwind" fn supp_translate(fmt: *const c_char) -> *const c_char {
+    translation::format(fmt)
+}
+
+// Prints a string formatted by supp_printf.
+#[no_mangle]
+pub extern "C-unwind" fn supp_write(out: *const c_char) {
+    if !out.is_null() {
+        let string = unsafe { CStr::from_ptr(out) };
+        output::write(&string.to_string_lossy());
+    }
+}
+
+// Reads an answer to an out-of-character question (like yesno_ or a GDT
+// prompt) into the buffer, in place of fgets(buffer, size, stdin).
+#[no_mangle]
+pub extern "C-unwind" fn supp_fgets(buffer: *mut c_char, size: c_int) {
+    trace!("supp_fgets(*mut c_char, {})", size);
+
+    if buffer.is_null() || size <= 0 {
+        error!("invalid buffer given to supp_fgets()");
+        exit_();
+    }
+
+    let typed_buffer = unsafe { slice::from_raw_parts_mut(buffer as *mut u8, size as usize) };
+
+    let mut input = core::read_answer();
+    input.truncate(typed_buffer.len() - 1);
+    for (index, c) in input.bytes().chain(Some(0)).enumerate() {
+        typed_buffer[index] = c;
+    }
+}
+
+// Called by game_() as each command starts, including each of several on one
+// line, so that UNDO can take it back.
+#[no_mangle]
+pub extern "C-unwind" fn supp_command() {
+    undo::snapshot();
 }
 
 // Prints a given string.
 #[no_mangle]
-pub extern "C" fn more_output(out: *const c_char) {
+pub extern "C-unwind" fn more_output(out: *const c_char) {
     // If out is not null, print it and a newline.
     if !out.is_null() {
         let string = unsafe { CStr::from_ptr(out) };
-        println!("{}", &string.to_str().unwrap());
-        io::stdout().flush().unwrap();
+        output::write(&translation::string(&string.to_string_lossy()));
+        output::write("\n");
     }
 
     // No idea what this does.
@@ -40,10 +134,8 @@ pub extern "C" fn more_output(out: *const c_char) {
 
 // No idea what this does.
 #[no_mangle]
-pub extern "C" fn more_input() {
+pub extern "C-unwind" fn more_input() {
     unsafe {
         ffi::coutput = 0;
     }
 }
-
-
@@ -1,5 +1,5 @@
-
-// This is the vocab that used to be within parse.h.
+// This is the vocab that used to be within parse.h. build.rs generates the C
+// arrays from these tables, into the vocab.h that parse.h includes.
 //
 // This is an overview of how Zork used to parse commands.
 //
@@ -8,14 +8,15 @@
 //
 // To parse a token, every character was turned into a number. Characters A..Z
 // mapped to 1..26, the - character was 27, and the numbers 1..9 mapped to 31..39.
-// (Curiously, there was no number 0.) A space ended the token. The special
+// (Curiously, there was no number 0, although 0 would have been 30, and a few
+// words in the tables have one.) A space ended the token. The special
 // characters '.' and ',' would split a command string into multiple commands,
 // each parsed consecutively, until the end of the input was found. Any other
 // character was an error and aborted parsing immediately.
 //
 // The characters were then combined three-at-a-time into an unsigned 16-bit
 // integer, with the following formula:
-// 
+//
 // token = first * 1600 + second * 40 + third
 //
 // This system was called "Radix-50", even though it's actually an encoding in
@@ -25,13 +26,14 @@
 // Therefore, each word was two consecutive tokens. Any missing characters simply
 // had the value 0.
 //
-// The array of tokens was then given to the string parser. Input lines were read
-// 79 characters at a time, and the smallest token was 2 characters long (a letter
-// and a space), which means there were at most 40 words. However, the token
-// array only had space for 40 tokens, while the maximum 40 words would require
-// 80 tokens. The token array was allocated on the stack, and was not bounds checked,
-// so inputting too many tokens would cause memory corruption, and possibly later
-// a segfault.
+// The array of tokens was then given to the string parser, sparse_(). Input
+// lines were read 79 characters at a time, and the smallest token was 2
+// characters long (a letter and a space), which means there were at most 40
+// words. However, the token array only had space for 40 tokens, while the
+// maximum 40 words would require 80 tokens. The token array was allocated on
+// the stack, and was not bounds checked, so inputting too many tokens would
+// cause memory corruption, and possibly later a segfault. (The lexer in np.rs
+// refuses commands with more than 20 words.)
 //
 // The string parser iterated through every pair of tokens, which represented
 // one word, and tried to find it in its vocab arrays. Throughout this description,
@@ -44,1719 +46,1316 @@
 // - Prepositions
 // - Adjectives
 // - Objects
-// 
+//
+// The tables below hold the same words, in the same order, as the arrays did,
+// and the functions at the end lay them out as the arrays again. The words
+// are spelled out in full, where parse.h said what they were; only their
+// first six characters count.
+//
 // The buzzword array was encoded as an array of words. If the token pair matched
-// a word in the array, then the buzzword was identified by the index of that word
-// (divided by two, because words are pairs of numbers). The array was null-
-// terminated (two 0's, making a null word).
+// a word in the array, the word was ignored. The array was null-terminated (two
+// 0's, making a null word).
 //
-
-struct Buzzword {
-    name: &'static str,
-    id: usize
-}
-
-impl Buzzword {
-    pub fn new(name: &'static str, id: usize) {
-        Buzzword { name, id }
-    }
-}
-
-lazy_static! {
-    static BUZZWORDS: Vec<Buzzword> = vec![
-        Buzzword::new("and", 0),
-        Buzzword::new("by", 1),
-        Buzzword::new("is", 2),
-        Buzzword::new("a", 3),
-        Buzzword::new("an", 4),
-        Buzzword::new("the", 5),
-        Buzzword::new("run", 6),
-        Buzzword::new("go", 7),
-        Buzzword::new("proceed", 8)
-    ];
-}
-
-// 
 // The verb array was more complicated. Each verb was stored as a list of words,
 // that were synonyms. Then, after the words, there were a series of numbers to
 // specify the syntax of the verb, called "syntax slots". The first syntax slot
 // was guaranteed to be less than 1600, which is the minimum value for a token
 // (because the first character cannot be 0 and is multiplied by 1600).
 //
-// The first syntax slot was a count of how many sytax slots there are, NOT
+// The first syntax slot was a count of how many syntax slots there are, NOT
 // including this one. This is so that the parser can quickly skip over the
 // syntax slots to the next word.
-// 
-// The second syntax slot was a combination of 5 flag bits and a verb ID number.
-// The flags were:
-// - Bit 14, true if the syntax includes a direct object.
-// - Bit 13, true if the syntax includes an indirect object.
-// - Bit 12, true if the direct object is implicit
-// - Bit 11, true if the indirect object comes before the direct object. If false,
-//   the direct object comes first. // TODO: Confirm this!
-// - Bit 10, true if "this is the default syntax for orphanery" // TODO: ???
-// The verb ID number was stored in the lower 8 bits.
-// 
-// The rest of the syntax slots were taken by "object descriptions". There were
-// 0, 1, or 2 of them, depending on the direct and indirect object flags. // TODO: what order?
-// Object descriptions are three pairs of flags and preposition numbers. // TODO: More info.
-// The flags were:
-// - Bit 14, true if the game should search the adventurer's inventory for the
-//   object.
-// - Bit 13, true if the game should search the room for the object.
-// - Bit 12, true if the game should try to take / consume the object.
-// - Bit 11, true if the object MUST be present.
-// - Bit 10, "Qualifying bits (normally -1, -1) are same as FWIM bits" // TODO: ???
-// - Bit 9, true if the object must be reachable.
-// The preposition ID number was stored in the lower 8 bits.
 //
-// Unused parts of the object descriptions were replaced with the value -1.Also,
-// an object description can be cut short by the length given in the first syntax
-// slot.
+// The rest of the slots held one or more syntaxes: the ways the verb can be
+// used, which synmch_() tries in order. The first slot of a syntax was a
+// combination of 5 flag bits and an action, the number vappli_() acts on:
+// - Bit 14 (SDIR), true if the syntax includes a direct object.
+// - Bit 13 (SIND), true if the syntax includes an indirect object.
+// - Bit 12 (SSTD), true if the direct object is the standard one, which isn't
+//   written out: any object in the room or carried, that can be reached.
+// - Bit 11 (SFLIP), true if the objects are swapped before the action: the
+//   object typed second is the direct one.
+// - Bit 10 (SDRIV), true if this is the syntax to fall back on, filling in the
+//   missing objects, when none of the syntaxes match.
+// The action was stored in the lower 9 bits.
 //
-// // TODO: This isn't the whole story - one of the entries has 22 things!
+// The rest of the syntax's slots were taken by "object descriptions", first
+// the direct object's (unless it was standard) and then the indirect object's.
+// Each object description was 3 slots. The first was a combination of flags
+// and a preposition, the one that must come before the object:
+// - Bit 14 (VABIT), true if the game should search the adventurer's inventory
+//   for a missing object.
+// - Bit 13 (VRBIT), true if the game should search the room for it. Without
+//   it, the object must not be in the room.
+// - Bit 12 (VTBIT), true if the game should try to take the object.
+// - Bit 11 (VCBIT), true if the object MUST end up carried: it's an error if
+//   it can't be taken.
+// - Bit 10 (VEBIT), true if the object must have one of the flags in the next
+//   two slots. Otherwise any object will do.
+// - Bit 9 (VFBIT), true if the object must be reachable.
+// The preposition was stored in the lower 9 bits. The other two slots were
+// object flags (oflag1 and oflag2), that get-what-I-mean looked for when the
+// object was missing: -1 for any object.
 //
 // The verb array was terminated with a -1 entry.
-// 
-
-struct Verb {
-    names: Vec<&'static str>,
-    id: usize,
-    
-    has_direct_object: bool,
-    has_indirect_object: bool,
-    direct_is_implicit: Option<bool>,
-    indirect_first: Option<bool>,
-    orphanery: bool,
-
-    direct_object: Option<VerbObject>,
-    indirect_object: Option<VerbObject>
+//
+// The directions array is composed of a single word, followed by the direction
+// code that the travel table uses (travel::Direction), which is an id number
+// shifted left by 10.
+//
+// The preposition array was stored as a word followed by an id number. Several
+// words could have the same id.
+//
+// The adjectives array was stored as a word followed by any number of object id's
+// that the adjective applies to. Id's could be differentiated from words because
+// the smallest value for a token was 1600, while the largest allowed id was 255.
+//
+// The objects array was stored the same way as the adjectives array. A word can
+// name several objects, and getobj_() decides which one is meant.
+//
+// This module only uses std, so that build.rs can include it too.
+
+use std::os::raw::c_int;
+
+/// The smallest token a word can start with: "A".
+pub const R50MIN: c_int = 1600;
+
+/// Syntax flag: the syntax has a direct object.
+pub const SDIR: c_int = 16384;
+/// Syntax flag: the syntax has an indirect object.
+pub const SIND: c_int = 8192;
+/// Syntax flag: the direct object is the standard one.
+pub const SSTD: c_int = 4096;
+/// Syntax flag: the objects are swapped.
+pub const SFLIP: c_int = 2048;
+/// Syntax flag: the syntax to fall back on.
+pub const SDRIV: c_int = 1024;
+/// The action in the first slot of a syntax.
+pub const SVMASK: c_int = 511;
+
+/// Object flag: search the adventurer.
+pub const VABIT: c_int = 16384;
+/// Object flag: search the room.
+pub const VRBIT: c_int = 8192;
+/// Object flag: try to take the object.
+pub const VTBIT: c_int = 4096;
+/// Object flag: the object must end up carried.
+pub const VCBIT: c_int = 2048;
+/// Object flag: the object must have one of the flags.
+pub const VEBIT: c_int = 1024;
+/// Object flag: the object must be reachable.
+pub const VFBIT: c_int = 512;
+/// The preposition in the first slot of an object description.
+pub const VPMASK: c_int = 511;
+
+/// A word that is ignored.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Buzzword {
+    pub name: &'static str,
 }
 
-struct VerbObjectDesc {
-    prep_id: usize,
+/// A verb, and the ways it can be used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Verb {
+    pub names: &'static [&'static str],
+    pub syntaxes: &'static [Syntax],
 
-    search_player: bool,
-    search_room: bool,
-    must_have_item: bool,
-    must_be_reachable: bool,
+    /// The number of syntax slots the verb's entry says it has, if that's
+    /// fewer than its syntaxes take. The slots after it aren't written out,
+    /// and the parser reads the next entry's words in their place.
+    pub slot_count: Option<usize>,
+}
 
-    qualifying_bits: bool,
+/// One way a verb can be used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Syntax {
+    /// What the verb does, for vappli_().
+    pub action: c_int,
+
+    pub direct: Option<VerbObjectDesc>,
+    pub indirect: Option<VerbObjectDesc>,
+
+    /// The object typed second is the direct one.
+    pub flip: bool,
+
+    /// This is the syntax to fall back on when none match.
+    pub driver: bool,
+}
+
+/// What a syntax wants for one of its objects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VerbObjectDesc {
+    /// The preposition that comes before the object, or 0 for none.
+    pub preposition: c_int,
+
+    pub search_adventurer: bool,
+    pub search_room: bool,
+    pub take: bool,
+    pub must_have: bool,
+
+    /// The object must have one of `flags`.
+    pub qualified: bool,
+    pub reachable: bool,
+
+    /// The object flags (oflag1 and oflag2) to look for when the object is
+    /// missing, or -1 for any.
+    pub flags: [c_int; 2],
+}
+
+/// A word for a direction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Direction {
+    pub name: &'static str,
+
+    /// The direction, from 1 (north) to 15 (cross). The travel table has it
+    /// shifted left by 10.
+    pub id: c_int,
+}
+
+/// A preposition and the words for it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Preposition {
+    pub names: &'static [&'static str],
+    pub id: c_int,
+}
+
+/// An adjective or a noun, and the objects it can describe.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ObjectDesc {
+    pub name: &'static str,
+    pub objects: &'static [c_int],
+}
+
+impl Buzzword {
+    pub const fn new(name: &'static str) -> Buzzword {
+        Buzzword { name }
+    }
+
+    /// Adds the buzzword to the buzzword array.
+    pub fn encode(&self, array: &mut Vec<c_int>) {
+        array.extend(&table_tokens(self.name));
+    }
 }
 
 impl Verb {
-    pub fn new_simple(name: &'static str, id: usize) -> Verb {
-        Verb {
-            names: vec![name],
-            id,
-
-            has_direct_object: false,
-            has_indirect_object: false,
-            direct_is_implicit: None,
-            indirect_first: None,
-            orphanery: false,
-
-            direct_object: None,
-            indirect_object: None
-        }
+    pub const fn new(names: &'static [&'static str], syntaxes: &'static [Syntax]) -> Verb {
+        Verb { names, syntaxes, slot_count: None }
     }
 
-    pub fn with_names(names: Vec<&'static str>, id: usize) -> Verb {
-        Verb {
-            names,
-            id,
+    pub const fn slot_count(self, count: usize) -> Verb {
+        Verb { slot_count: Some(count), ..self }
+    }
 
-            has_direct_object: false,
-            has_indirect_object: false,
-            direct_is_implicit: None,
-            indirect_first: None,
-            orphanery: false,
+    /// Adds the verb to the verb array.
+    pub fn encode(&self, array: &mut Vec<c_int>) {
+        for name in self.names {
+            array.extend(&table_tokens(name));
+        }
+        let mut slots = Vec::new();
+        for syntax in self.syntaxes {
+            syntax.encode(&mut slots);
+        }
+        slots.truncate(self.slot_count.unwrap_or(slots.len()));
+        array.push(slots.len() as c_int);
+        array.extend(slots);
+    }
 
-            direct_object: None,
-            indirect_object: None
+    /// The syntaxes the parser tries: those that start within the verb's
+    /// slots.
+    pub fn read_syntaxes(&self) -> &'static [Syntax] {
+        let count = match self.slot_count {
+            Some(count) => count,
+            None => return self.syntaxes,
+        };
+        let mut start = 0;
+        let mut read = 0;
+        while read < self.syntaxes.len() && start < count {
+            start += self.syntaxes[read].slot_len();
+            read += 1;
         }
+        &self.syntaxes[..read]
     }
 }
-/*
-lazy_static! {
-    static Verbs: Vec<Verb> = vec![
-        Verb::new_simple("brief", 70),
-        Verb::new_simple("verbose", 71),
-        Verb::new_simple("superb", 72), // Super-brief?
-        Verb::new_simple("stay", 73),
-        Verb::new_simple("version", 74),
-        Verb::with_names(vec!["swim", "bathe", "wade"], 75),
-        Verb::new_simple("geronimo", 76),
-        Verb::with_names(vec!["Ulysses", "Odyssey"], 77),
-        Verb::new_simple("well", 78),
-        Verb::new_simple("pray", 79),
-        Verb::new_simple("treasure", 80),
-        Verb::new_simple("temple", 81),
-        Verb::new_simple("blast", 82),
-        Verb::new_simple("score", 83),
-        Verb::with_names(vec!["q", "quit"], 84),
-        Verb::new_simple("help", 40),
-        Verb::new_simple("info", 41),
-        Verb::with_names(vec!["history", "update"], 42),
-        Verb::new_simple("back", 43),
-        Verb::with_names(vec!["sigh", "mumble"], 44),
-        Verb::with_names(vec!["chomp", "lose", "barf"], 45),
-        Verb::new_simple("dungeon", 46),
-        Verb::new_simple("froboz", 47),
-        Verb::with_names(vec!["foo", "bletch", "bar"], 48),
-        Verb::new_simple("repent", 49),
-        Verb::with_names(vec!["hours", "schedule"], 50),
-        Verb::new_simple("win", 51),
-        Verb::with_names(vec!["yell", "scream", "shout"], 52),
-        Verb::with_names(vec!["hop", "skip"], 53),
-        Verb::with_names(vec!["fuck", "shit", "damn", "curse"], 54),
-        Verb::new_simple("zork", 55),
-        Verb {
-            names: vec!["granite"],
-            id: 56,
-
-            has_direct_object: true,
-            has_indirect_object: false,
-            direct_is_implicit: Some(true),
-            indirect_first: None,
-            
-            orphanery: false,
-
-            direct_object: None,
-            indirect_object: None
-        },
-        Verb::new_simple("save", 149),
-        Verb::new_simple("restore", 150),
-        Verb::new_simple("time", 90),
-        Verb::new_simple("diagno", 94),     // TODO: ??
-        Verb::with_names(vec!["exorcism", "exorcise"], 105),
-        Verb::with_names(vec!["i", "inventory"], 133),
-        Verb::new_simple("wait", 128),
-        Verb::with_names(vec!["incant", "incantation"], 95),
-        Verb::new_simple("answer", 96),
-        Verb::new_simple("again", 57),
-        Verb::new_simple("noobj", 58),
-        Verb::with_names(vec!["bug", "gripe", "complain"], 59),
-        Verb::with_names(vec!["feature", "comment", "suggest", "idea"], 60),
-        Verb::new_simple("room", 65),
-        Verb::new_simple("object", 66),
-        Verb::with_names(vec!["rname", "rename"], 67),
-        Verb {
-            names: vec!["deflate"], 
-            id: 103,
-
-            has_direct_object: true,
-            has_indirect_object: false,
-            direct_is_implicit: Some(true),
-            indirect_first: None,
-            orphanery: false,
-
-            direct_object: None,
-            indirect_object: None
-        },
-        Verb {
-            names: vec!["describe", "what", "examine"],
-            id: 120,
-
-            has_direct_object: true,
-            has_indirect_object: false,
-            direct_is_implicit: Some(true),
-            indirect_first: None,
-            orphanery: false,
-
-            direct_object: None,
-            indirect_object: None
-        },
-        Verb {
-            names: vec!["fill"],
-            id: 134,
-
-            has_direct_object: true,
-            has_indirect_object: true,
-            direct_is_implicit: Some(false),
-            indirect_first: Some(false),
-            orphanery: false,
-
-            direct_object: Some(vec![
-                VerbObjectDesc {
-                    prep_id: 0,
-                    
-                    search_player: true,
-                    search_room: true,
-                    must_have_item: false,
-                    must_be_reachable: true,
-
-                    qualifying_bits: false
-                },
-                VerbObjectDesc {
-                    prep_id: 128,
-
-                    search_player: false,
-                    search_room: false,
-                    must_have_item: false,
-                    must_be_reachable: false,
-
-                    qualifying_bits: false
-                },
-                VerbObjectDesc {
-                    prep_id: 0,
-                    
-                    search_player: false,
-
-    ];
-}
 
+impl Syntax {
+    /// A syntax with no objects.
+    pub const fn new(action: c_int) -> Syntax {
+        Syntax { action, direct: None, indirect: None, flip: false, driver: false }
+    }
 
-*/
+    pub const fn direct(self, object: VerbObjectDesc) -> Syntax {
+        Syntax { direct: Some(object), ..self }
+    }
 
-//
-// The directions array is composed of a single token, followed by an id number.
-// The numbers were stored shifted right by 10. // TODO: Why?
+    /// Gives the syntax the standard direct object.
+    pub const fn standard(self) -> Syntax {
+        self.direct(VerbObjectDesc::STANDARD)
+    }
 
+    pub const fn indirect(self, object: VerbObjectDesc) -> Syntax {
+        Syntax { indirect: Some(object), ..self }
+    }
 
-struct Direction {
-    names: Vec<&'static str>,
-    id: usize
-}
+    pub const fn flip(self) -> Syntax {
+        Syntax { flip: true, ..self }
+    }
 
-impl Direction {
-    pub fn new(names: Vec<&'static str>, bits: Vec<u8>) -> Direction {
-        Direction { names, bits }
+    pub const fn driver(self) -> Syntax {
+        Syntax { driver: true, ..self }
+    }
+
+    /// The first slot of the syntax.
+    pub fn flags(&self) -> c_int {
+        let mut flags = self.action;
+        for &(set, flag) in &[(self.direct.is_some(), SDIR),
+                              (self.indirect.is_some(), SIND),
+                              (self.direct == Some(VerbObjectDesc::STANDARD), SSTD),
+                              (self.flip, SFLIP),
+                              (self.driver, SDRIV)] {
+            if set {
+                flags |= flag;
+            }
+        }
+        flags
+    }
+
+    /// The number of slots the syntax takes.
+    pub fn slot_len(&self) -> usize {
+        let mut len = 1;
+        if self.direct.is_some() && self.direct != Some(VerbObjectDesc::STANDARD) {
+            len += 3;
+        }
+        if self.indirect.is_some() {
+            len += 3;
+        }
+        len
+    }
+
+    /// Adds the syntax's slots to a verb's.
+    pub fn encode(&self, slots: &mut Vec<c_int>) {
+        slots.push(self.flags());
+        if let Some(direct) = self.direct {
+            if direct != VerbObjectDesc::STANDARD {
+                direct.encode(slots);
+            }
+        }
+        if let Some(indirect) = self.indirect {
+            indirect.encode(slots);
+        }
     }
 }
 
-lazy_static! {
-    static Directions: Vec<Direction> = vec![
-        Direction::new(
-            vec!["n", "north"],
-            1
-        ),
-        Direction::new(
-            vec!["ne", "northeast", "north-east"],
-            2
-        ),
-        Direction::new(
-            vec!["e", "east"],
-            3
-        ),
-        Direction::new(
-            vec!["se", "southeast", "south-east"],
-            4
-        ),
-        Direction::new(
-            vec!["s", "south"],
-            5
-        ),
-        Direction::new(
-            vec!["sw", "southwest", "south-west"],
-            6
-        ),
-        Direction::new(
-            vec!["w", "west"],
-            7
-        ),
-        Direction::new(
-            vec!["nw", "northwest", "north-west"],
-            8
-        ),
-        Direction::new(
-            vec!["u", "up"],
-            9
-        ),
-        Direction::new(
-            vec!["d", "down"],
-            10
-        ),
-        Direction::new(
-            vec!["launch"],
-            11
-        ),
-        Direction::new(
-            vec!["land"],
-            12
-        ),
-        Direction::new(
-            vec!["in"],
-            13
-        )
-        Direction::new(
-            vec!["enter"],
-            14
-        ),
-        Direction::new(
-            vec!["exit", "out", "leave", "cross"],
-            15
-        ),
-    ];
+impl VerbObjectDesc {
+    /// The direct object of syntaxes with SSTD.
+    pub const STANDARD: VerbObjectDesc =
+        VerbObjectDesc::new(0).adventurer().room().reachable();
+
+    /// An object that comes after `preposition`, with no flags.
+    pub const fn new(preposition: c_int) -> VerbObjectDesc {
+        VerbObjectDesc {
+            preposition,
+            search_adventurer: false,
+            search_room: false,
+            take: false,
+            must_have: false,
+            qualified: false,
+            reachable: false,
+            flags: [-1, -1],
+        }
+    }
+
+    pub const fn adventurer(self) -> VerbObjectDesc {
+        VerbObjectDesc { search_adventurer: true, ..self }
+    }
+
+    pub const fn room(self) -> VerbObjectDesc {
+        VerbObjectDesc { search_room: true, ..self }
+    }
+
+    pub const fn take(self) -> VerbObjectDesc {
+        VerbObjectDesc { take: true, ..self }
+    }
+
+    pub const fn must_have(self) -> VerbObjectDesc {
+        VerbObjectDesc { must_have: true, ..self }
+    }
+
+    pub const fn qualified(self) -> VerbObjectDesc {
+        VerbObjectDesc { qualified: true, ..self }
+    }
+
+    pub const fn reachable(self) -> VerbObjectDesc {
+        VerbObjectDesc { reachable: true, ..self }
+    }
+
+    pub const fn flags(self, flags_1: c_int, flags_2: c_int) -> VerbObjectDesc {
+        VerbObjectDesc { flags: [flags_1, flags_2], ..self }
+    }
+
+    /// The first slot of the object description.
+    pub fn bits(&self) -> c_int {
+        let mut bits = self.preposition;
+        for &(set, bit) in &[(self.search_adventurer, VABIT),
+                             (self.search_room, VRBIT),
+                             (self.take, VTBIT),
+                             (self.must_have, VCBIT),
+                             (self.qualified, VEBIT),
+                             (self.reachable, VFBIT)] {
+            if set {
+                bits |= bit;
+            }
+        }
+        bits
+    }
+
+    /// The flags an object must have one of: `flags` if the description is
+    /// qualified, or else any.
+    pub fn required_flags(&self) -> [c_int; 2] {
+        if self.qualified {
+            self.flags
+        } else {
+            [-1, -1]
+        }
+    }
+
+    /// Adds the object description's slots to a syntax's.
+    pub fn encode(&self, slots: &mut Vec<c_int>) {
+        slots.extend(&[self.bits(), self.flags[0], self.flags[1]]);
+    }
 }
 
-//
-// The preposition array was stored as a list of one or more words, followed by
-// an id number. The id numbers could be differentiated from the words by their
-// size; the smallest token was 1600, while the maximum allowed id was 255.
-//
+impl Direction {
+    pub const fn new(name: &'static str, id: c_int) -> Direction {
+        Direction { name, id }
+    }
 
-struct Preposition {
-    names: Vec<&'static str>,
-    id: usize
+    /// Adds the direction to the direction array.
+    pub fn encode(&self, array: &mut Vec<c_int>) {
+        array.extend(&table_tokens(self.name));
+        array.push(self.id << 10);
+    }
 }
 
 impl Preposition {
-    pub fn new(name: &'static str, id: usize) -> Preposition {
-        Preposition { 
-            names: vec![name], 
-            id 
+    pub const fn new(names: &'static [&'static str], id: c_int) -> Preposition {
+        Preposition { names, id }
+    }
+
+    /// Adds the preposition to the preposition array.
+    pub fn encode(&self, array: &mut Vec<c_int>) {
+        for name in self.names {
+            array.extend(&table_tokens(name));
+            array.push(self.id);
         }
     }
+}
 
-    pub fn with_names(names: &'static str, id: usize) -> Preposition {
-        Preposition { names, id}
+impl ObjectDesc {
+    pub const fn new(name: &'static str, objects: &'static [c_int]) -> ObjectDesc {
+        ObjectDesc { name, objects }
+    }
+
+    /// Adds the word to the adjective or object array.
+    pub fn encode(&self, array: &mut Vec<c_int>) {
+        array.extend(&table_tokens(self.name));
+        array.extend(self.objects);
     }
 }
 
-lazy_static! {
-    static Prepositions: Vec<Preposition> = vec![
-        Preposition::new("over", 1),
-        Preposition::with_names(vec!["with", "using", "through"], 2),
-        Preposition::new("at", 3),
-        Preposition::new("to", 4),
-        Preposition::with_names(vec!["in", "inside", "into"], 5),
-        Preposition::new("down", 6),
-        Preposition::new("up", 7),
-        Preposition::new("under", 8),
-        Preposition::new("of", 9),
-        Preposition::new("on", 10),
-        Preposition::new("off", 11)
-    ];
+// Shorthands for the verb table.
+const fn syntax(action: c_int) -> Syntax {
+    Syntax::new(action)
 }
 
-//
-// The adjectives array was stored as a word followed by any number of object id's
-// that the adjective applies to. Id's could be differentiated from words because
-// the smallest value for a token was 1600, while the largest allowed id was 255.
-//
-// The objects array was stored the same way as the adjectives array.
-//
+const fn object(preposition: c_int) -> VerbObjectDesc {
+    VerbObjectDesc::new(preposition)
+}
 
-struct ObjectDesc {
-    id: usize,
-    
-    names: Vec<&'static str>,
-    adjectives: Vec<&'static str>,
+/// The buzzwords (buzvoc_).
+pub static BUZZWORDS: &[Buzzword] = &[
+    Buzzword::new("and"),
+    Buzzword::new("by"),
+    Buzzword::new("is"),
+    Buzzword::new("a"),
+    Buzzword::new("an"),
+    Buzzword::new("the"),
+    Buzzword::new("run"),
+    Buzzword::new("go"),
+    Buzzword::new("proceed"),
+];
+
+/// The verbs (vvoc).
+pub static VERBS: &[Verb] = &[
+    Verb::new(&["brief"], &[syntax(70)]),
+    Verb::new(&["verbose"], &[syntax(71)]),
+    Verb::new(&["superb"], &[syntax(72)]),
+    Verb::new(&["stay"], &[syntax(73)]),
+    Verb::new(&["version"], &[syntax(74)]),
+    Verb::new(&["swim", "bathe", "wade"], &[syntax(75)]),
+    Verb::new(&["geronimo"], &[syntax(76)]),
+    Verb::new(&["ulysses", "odyssey"], &[syntax(77)]),
+    Verb::new(&["well"], &[syntax(78)]),
+    Verb::new(&["pray"], &[syntax(79)]),
+    Verb::new(&["treasure"], &[syntax(80)]),
+    Verb::new(&["temple"], &[syntax(81)]),
+    Verb::new(&["blast"], &[syntax(82)]),
+    Verb::new(&["score"], &[syntax(83)]),
+    Verb::new(&["q", "quit"], &[syntax(84)]),
+    Verb::new(&["help"], &[syntax(40)]),
+    Verb::new(&["info"], &[syntax(41)]),
+    Verb::new(&["history", "update"], &[syntax(42)]),
+    Verb::new(&["back"], &[syntax(43)]),
+    Verb::new(&["sigh", "mumble"], &[syntax(44)]),
+    Verb::new(&["chomp", "lose", "barf"], &[syntax(45)]),
+    Verb::new(&["dungeon"], &[syntax(46)]),
+    Verb::new(&["froboz"], &[syntax(47)]),
+    Verb::new(&["foo", "bletch", "bar"], &[syntax(48)]),
+    Verb::new(&["repent"], &[syntax(49)]),
+    Verb::new(&["hours", "schedule"], &[syntax(50)]),
+    Verb::new(&["win"], &[syntax(51)]),
+    Verb::new(&["yell", "scream", "shout"], &[syntax(52)]),
+    Verb::new(&["hop", "skip"], &[syntax(53)]),
+    Verb::new(&["fuck", "shit", "damn", "curse"], &[syntax(54)]),
+    Verb::new(&["zork"], &[syntax(55)]),
+    Verb::new(&["granite"], &[syntax(56).standard()]),
+    Verb::new(&["save"], &[syntax(149)]),
+    Verb::new(&["restore"], &[syntax(150)]),
+    Verb::new(&["time"], &[syntax(90)]),
+    Verb::new(&["diagno"], &[syntax(94)]),
+    Verb::new(&["exorcism"], &[syntax(105)]),
+    Verb::new(&["inventory", "i"], &[syntax(133)]),
+    Verb::new(&["wait"], &[syntax(128)]),
+    Verb::new(&["incantation"], &[syntax(95)]),
+    Verb::new(&["answer"], &[syntax(96)]),
+    Verb::new(&["again"], &[syntax(57)]),
+    Verb::new(&["noobj"], &[syntax(58)]),
+    Verb::new(&["bug", "gripe", "complain"], &[syntax(59)]),
+    Verb::new(&["feature", "comment", "suggest", "idea"], &[syntax(60)]),
+    Verb::new(&["room"], &[syntax(65)]),
+    Verb::new(&["object"], &[syntax(66)]),
+    Verb::new(&["rname"], &[syntax(67)]),
+    Verb::new(&["deflate"], &[syntax(103).standard()]),
+    Verb::new(&["describe", "what", "examine"], &[syntax(120).standard()]),
+    Verb::new(&["fill"], &[
+        syntax(134)
+            .direct(object(0).adventurer().room().reachable().flags(128, 0))
+            .indirect(object(2).adventurer().room().reachable().flags(256, 0)),
+        syntax(134).direct(object(0).adventurer().room().reachable().flags(128, 0)),
+    ]),
+    Verb::new(&["find", "seek", "where", "see"], &[
+        syntax(127).direct(object(0).adventurer().room()),
+    ]),
+    Verb::new(&["follow"], &[
+        syntax(85),
+        syntax(85).standard(),
+    ]),
+    Verb::new(&["kick", "bite", "taunt"], &[syntax(107).standard()]),
+    Verb::new(&["lower"], &[syntax(110).standard()]),
+    Verb::new(&["push", "press"], &[syntax(112).standard()]),
+    Verb::new(&["ring", "peal"], &[syntax(87).standard()]),
+    Verb::new(&["rub", "caress", "touch", "fondle"], &[syntax(111).standard()]),
+    Verb::new(&["shake"], &[syntax(121).standard()]),
+    Verb::new(&["spin"], &[syntax(129).standard()]),
+    Verb::new(&["untie", "free"], &[syntax(113).standard()]),
+    Verb::new(&["walk"], &[
+        syntax(142).standard(),
+        syntax(86).direct(object(2).adventurer().room().reachable()),
+        syntax(86).direct(object(5).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["attack", "fight", "injure", "hit", "hurt"], &[
+        syntax(141)
+            .direct(object(0).room().reachable().flags(0, 128))
+            .indirect(object(2).adventurer().must_have().flags(0, 512)),
+    ]),
+    Verb::new(&["board"], &[syntax(130).direct(object(0).room().reachable().flags(0, 2))]),
+    Verb::new(&["brush", "clean"], &[
+        syntax(88).standard().driver(),
+        syntax(88).standard().indirect(object(2).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["burn", "ignite", "incinerate"], &[
+        syntax(137)
+            .direct(object(0).adventurer().room().reachable().flags(16, 0))
+            .indirect(object(2).adventurer().room().must_have().flags(8, 0)),
+    ]),
+    Verb::new(&["climb"], &[
+        syntax(157).direct(object(7).room().flags(0, 2048)),
+        syntax(158).direct(object(6).room().flags(0, 2048)),
+        syntax(156).direct(object(0).room().flags(0, 2048)),
+    ]),
+    Verb::new(&["close"], &[
+        syntax(126).direct(object(0).adventurer().room().reachable().flags(4224, 0)),
+    ]),
+    Verb::new(&["dig"], &[syntax(89).direct(object(2).adventurer().must_have().flags(4, 0))]),
+    Verb::new(&["disembowl"], &[syntax(131).direct(object(0).room().flags(0, 2))]),
+    Verb::new(&["drink", "imbibe", "swallow"], &[
+        syntax(136).direct(object(0).adventurer().room().reachable().flags(256, 0)),
+    ]),
+    Verb::new(&["drop", "release"], &[
+        syntax(145).direct(object(0).adventurer().reachable()).driver(),
+        syntax(145)
+            .direct(object(0).adventurer().reachable())
+            .indirect(object(5).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["eat", "consume", "gobble", "munch", "taste"], &[
+        syntax(135)
+            .direct(object(0).adventurer().room().take().must_have().reachable().flags(1024, 0)),
+    ]),
+    Verb::new(&["extinguish", "douse"], &[
+        syntax(124)
+            .direct(object(0).adventurer().room().take().must_have().reachable().flags(64, 0)),
+    ]),
+    Verb::new(&["give", "hand", "donate"], &[
+        syntax(146).standard().indirect(object(4).room().reachable().flags(32, 0)).driver(),
+        syntax(146)
+            .direct(object(0).room().reachable().flags(32, 0))
+            .indirect(object(0).adventurer().room().reachable()).flip(),
+    ]),
+    Verb::new(&["hello", "hi"], &[
+        syntax(151).driver(),
+        syntax(151).standard(),
+    ]),
+    Verb::new(&["blow"], &[
+        syntax(102)
+            .direct(object(7).adventurer().room().reachable())
+            .indirect(object(2).adventurer().room().reachable().flags(4, 0)).driver(),
+        syntax(112).direct(object(7).adventurer().room().reachable()),
+        syntax(117).direct(object(5).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["inflate"], &[
+        syntax(102).standard().indirect(object(2).adventurer().room().reachable().flags(4, 0)),
+    ]),
+    Verb::new(&["jump", "leap"], &[
+        syntax(91),
+        syntax(91).direct(object(1).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["kill", "murder", "slay", "stab", "dispatch"], &[
+        syntax(139)
+            .direct(object(0).room().reachable().flags(0, 128))
+            .indirect(object(2).adventurer().must_have().flags(0, 512)),
+    ]),
+    Verb::new(&["knock", "rap"], &[
+        syntax(118).direct(object(3).adventurer().room().reachable()).driver(),
+        syntax(118).direct(object(10).adventurer().room().reachable()),
+        syntax(141).direct(object(6).room().qualified().reachable().flags(32, 0)),
+    ]),
+    Verb::new(&["light"], &[
+        syntax(123)
+            .direct(object(0).adventurer().room().take().must_have().reachable().flags(64, 0))
+                .driver(),
+        syntax(137)
+            .direct(object(0).adventurer().room().reachable().flags(64, 0))
+            .indirect(object(2).adventurer().take().must_have().flags(8, 0)),
+    ]),
+    Verb::new(&["lock"], &[syntax(92).direct(object(0).room())]),
+    Verb::new(&["look", "l", "stare", "gaze"], &[
+        syntax(119),
+        syntax(119).direct(object(3).adventurer().room()),
+        syntax(153).direct(object(8).adventurer().room().reachable()),
+        syntax(152).direct(object(5).adventurer().room()),
+    ]),
+    Verb::new(&["melt", "liquify"], &[
+        syntax(101).standard().indirect(object(2).adventurer().room().reachable().flags(8, 0)),
+    ]),
+    Verb::new(&["move"], &[syntax(122).direct(object(0).room())]),
+    Verb::new(&["pull", "tug"], &[
+        syntax(122).direct(object(0).room().reachable()).driver(),
+        syntax(122).direct(object(10).room().reachable()),
+    ]),
+    Verb::new(&["mung", "hack", "frob", "damage"], &[
+        syntax(138).standard().driver(),
+        syntax(138).standard().indirect(object(2).adventurer().must_have()),
+    ]),
+    Verb::new(&["open"], &[
+        syntax(125).direct(object(0).adventurer().room().reachable().flags(4224, 0)).driver(),
+        syntax(125)
+            .direct(object(0).adventurer().room().reachable().flags(4224, 0))
+            .indirect(object(2).adventurer().take().must_have().flags(4, 512)),
+    ]),
+    Verb::new(&["pick"], &[
+        syntax(132).direct(object(7).adventurer().room().reachable().flags(8192, 32)),
+    ]),
+    Verb::new(&["plug", "glue", "patch"], &[
+        syntax(106).standard().indirect(object(2).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["poke", "break", "jab"], &[
+        syntax(138)
+            .direct(object(0).room().reachable().flags(0, 128))
+            .indirect(object(2).adventurer().must_have().flags(0, 512)),
+    ]),
+    Verb::new(&["pour", "spill"], &[
+        syntax(147).direct(object(0).adventurer().qualified().flags(256, 0)).driver(),
+        syntax(147)
+            .direct(object(0).adventurer().qualified().flags(256, 0))
+            .indirect(object(5).adventurer().room()),
+    ]),
+    // The syntax has an indirect object, but the entry ends before it, so
+    // its description is made of the words of "put".
+    Verb::new(&["pump"], &[
+        syntax(154)
+            .direct(object(7).adventurer().room())
+            .indirect(object(348).adventurer().room().qualified().reachable().flags(0, 14979)),
+    ]).slot_count(4),
+    Verb::new(&["put", "insert", "stuff", "place"], &[
+        syntax(144).standard().indirect(object(5).adventurer().room().reachable()).driver(),
+        syntax(145).direct(object(6).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["raise", "lift"], &[
+        syntax(109).standard().driver(),
+        syntax(109).direct(object(7).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["read", "skim"], &[
+        syntax(100)
+            .direct(object(0).adventurer().room().take().reachable().flags(16384, 0)).driver(),
+        syntax(100)
+            .direct(object(0).adventurer().room().take().reachable().flags(16384, 0))
+            .indirect(object(2).adventurer().room().reachable()),
+    ]),
+    Verb::new(&["strike"], &[
+        syntax(141)
+            .direct(object(0).room().qualified().reachable().flags(32, 0))
+            .indirect(object(2).adventurer().must_have().flags(0, 512)),
+        syntax(141).direct(object(0).room().qualified().reachable().flags(32, 0)).driver(),
+        syntax(123).standard(),
+    ]),
+    Verb::new(&["swing", "thrust"], &[
+        syntax(140)
+            .direct(object(0).adventurer().must_have().flags(0, 512))
+            .indirect(object(3).room().reachable().flags(0, 128)),
+    ]),
+    Verb::new(&["take", "get", "hold", "carry", "remove"], &[
+        syntax(132).direct(object(0).adventurer().room().reachable().flags(8192, 32)),
+    ]),
+    Verb::new(&["tell", "command", "request"], &[
+        syntax(143).direct(object(0).room().flags(0, 1024)),
+    ]),
+    Verb::new(&["throw", "hurl", "chuck"], &[
+        syntax(148)
+            .direct(object(0).adventurer().must_have())
+            .indirect(object(3).room().reachable().flags(32, 0)),
+        syntax(148)
+            .direct(object(0).adventurer().must_have())
+            .indirect(object(2).room().reachable().flags(32, 0)),
+    ]),
+    Verb::new(&["tie", "fasten"], &[
+        syntax(114).standard().indirect(object(4).adventurer().room().reachable()),
+        syntax(115)
+            .direct(object(0).room().reachable().flags(32, 0))
+            .indirect(object(2).adventurer().room().must_have().reachable().flags(4, 0)),
+    ]),
+    Verb::new(&["turn", "set"], &[
+        syntax(116)
+            .direct(object(0).adventurer().room().reachable().flags(2, 0))
+            .indirect(object(2).adventurer().room().must_have().flags(4, 0)).driver(),
+        syntax(123)
+            .direct(object(10).adventurer().room().take().must_have().reachable().flags(64, 0)),
+        syntax(124)
+            .direct(object(11).adventurer().room().take().must_have().reachable().flags(64, 0)),
+        syntax(159)
+            .direct(object(0).adventurer().room().reachable().flags(2, 0))
+            .indirect(object(4).room()),
+    ]),
+    Verb::new(&["unlock"], &[
+        syntax(93)
+            .direct(object(0).room().reachable())
+            .indirect(object(2).adventurer().room().take().must_have().flags(4, 0)),
+    ]),
+    Verb::new(&["wake", "surprise", "alarm", "startle"], &[
+        syntax(104).direct(object(0).room().flags(32, 0)).driver(),
+        syntax(104).direct(object(7).room().flags(32, 0)),
+    ]),
+    Verb::new(&["wave", "flaunt", "brandish"], &[syntax(108).direct(object(0).adventurer())]),
+    Verb::new(&["wind"], &[
+        syntax(155).standard(),
+        syntax(155).direct(object(7).adventurer().room().reachable()),
+    ]),
+];
+
+/// The directions (dirvoc_).
+pub static DIRECTIONS: &[Direction] = &[
+    Direction::new("n", 1),
+    Direction::new("north", 1),
+    Direction::new("s", 5),
+    Direction::new("e", 3),
+    Direction::new("east", 3),
+    Direction::new("w", 7),
+    Direction::new("west", 7),
+    Direction::new("se", 4),
+    Direction::new("sw", 6),
+    Direction::new("ne", 2),
+    Direction::new("nw", 8),
+    Direction::new("u", 9),
+    Direction::new("up", 9),
+    Direction::new("d", 10),
+    Direction::new("down", 10),
+    Direction::new("launch", 11),
+    Direction::new("land", 12),
+    Direction::new("enter", 13),
+    Direction::new("exit", 14),
+    Direction::new("out", 14),
+    Direction::new("leave", 14),
+    Direction::new("travel", 15),
+    Direction::new("south", 5),
+    Direction::new("in", 13),
+    Direction::new("cross", 15),
+];
+
+/// The prepositions (prpvoc_).
+pub static PREPOSITIONS: &[Preposition] = &[
+    Preposition::new(&["over"], 1),
+    Preposition::new(&["with", "using", "through"], 2),
+    Preposition::new(&["at"], 3),
+    Preposition::new(&["to"], 4),
+    Preposition::new(&["in", "inside", "int"], 5), // Meant to be "into".
+    Preposition::new(&["down"], 6),
+    Preposition::new(&["up"], 7),
+    Preposition::new(&["under"], 8),
+    Preposition::new(&["of"], 9),
+    Preposition::new(&["on"], 10),
+    Preposition::new(&["off"], 11),
+];
+
+/// The adjectives (avoc).
+pub static ADJECTIVES: &[ObjectDesc] = &[
+    ObjectDesc::new("brown", &[1, 81]),
+    ObjectDesc::new("elongated", &[1]),
+    ObjectDesc::new("hot", &[3]),
+    ObjectDesc::new("pepper", &[3]),
+    ObjectDesc::new("vitreous", &[4]),
+    ObjectDesc::new("jade", &[6]),
+    ObjectDesc::new("huge", &[8]),
+    ObjectDesc::new("enormous", &[8, 122]),
+    ObjectDesc::new("trophy", &[9]),
+    ObjectDesc::new("clear", &[10]),
+    ObjectDesc::new("large", &[12, 26, 47, 95, 96, 123, 133, 135, 146, 147, 150, 176]),
+    ObjectDesc::new("nasty", &[13]),
+    ObjectDesc::new("elvish", &[14]),
+    ObjectDesc::new("brass", &[15, 16, 46, 156]),
+    ObjectDesc::new("broken", &[16, 22, 92, 113, 155, 158]),
+    ObjectDesc::new("oriental", &[17]),
+    ObjectDesc::new("bloody", &[20]),
+    ObjectDesc::new("rusty", &[21]),
+    ObjectDesc::new("burned", &[22]),
+    ObjectDesc::new("dead", &[22]),
+    ObjectDesc::new("old", &[25, 41, 44, 45]),
+    ObjectDesc::new("leather", &[25]),
+    ObjectDesc::new("platinum", &[26]),
+    ObjectDesc::new("pearl", &[27]),
+    ObjectDesc::new("moby", &[31]),
+    ObjectDesc::new("crystal", &[32, 126]),
+    ObjectDesc::new("gold", &[33, 85, 104, 157, 158, 188]),
+    ObjectDesc::new("ivory", &[34]),
+    ObjectDesc::new("sapphire", &[37]),
+    ObjectDesc::new("wooden", &[38, 67, 136, 137, 165, 173, 174, 175]),
+    ObjectDesc::new("wood", &[38, 67, 136, 137, 165, 173, 174, 175]),
+    ObjectDesc::new("steel", &[39, 125, 189]),
+    ObjectDesc::new("dented", &[39]),
+    ObjectDesc::new("fancy", &[40]),
+    ObjectDesc::new("ancient", &[41, 44]),
+    ObjectDesc::new("small", &[5, 46, 52, 53, 89, 102, 103, 153, 187]),
+    ObjectDesc::new("black", &[47, 162]),
+    ObjectDesc::new("tour", &[49]),
+    ObjectDesc::new("viscous", &[55]),
+    ObjectDesc::new("vicious", &[62]),
+    ObjectDesc::new("glass", &[10, 126, 132]),
+    ObjectDesc::new("trap", &[66]),
+    ObjectDesc::new("front", &[68]),
+    ObjectDesc::new("stone", &[69, 150, 214]),
+    ObjectDesc::new("mangled", &[72]),
+    ObjectDesc::new("red", &[79, 94, 140, 161, 170, 171]),
+    ObjectDesc::new("yellow", &[80, 159]),
+    ObjectDesc::new("blue", &[82, 112, 114, 141]),
+    ObjectDesc::new("vampiric", &[83]),
+    ObjectDesc::new("magic", &[90]),
+    ObjectDesc::new("seaworthy", &[90]),
+    ObjectDesc::new("tan", &[91]),
+    ObjectDesc::new("sharp", &[92]),
+    ObjectDesc::new("wicker", &[98]),
+    ObjectDesc::new("cloth", &[100]),
+    ObjectDesc::new("braided", &[101]),
+    ObjectDesc::new("gaudy", &[108]),
+    ObjectDesc::new("square", &[109, 127]),
+    ObjectDesc::new("clay", &[109]),
+    ObjectDesc::new("shiny", &[110]),
+    ObjectDesc::new("thin", &[110]),
+    ObjectDesc::new("green", &[115, 143]),
+    ObjectDesc::new("purple", &[116]),
+    ObjectDesc::new("white", &[117, 147, 160]),
+    ObjectDesc::new("marble", &[119]),
+    ObjectDesc::new("coke", &[121]),
+    ObjectDesc::new("empty", &[121]),
+    ObjectDesc::new("round", &[128]),
+    ObjectDesc::new("triangular", &[129]),
+    ObjectDesc::new("rare", &[134]),
+    ObjectDesc::new("oblong", &[135]),
+    ObjectDesc::new("eat-me", &[138]),
+    ObjectDesc::new("eatme", &[138]),
+    ObjectDesc::new("orange", &[139]),
+    ObjectDesc::new("ecch", &[141]),
+    ObjectDesc::new("rocky", &[147]),
+    ObjectDesc::new("sheer", &[147]),
+    ObjectDesc::new("200", &[148]),
+    ObjectDesc::new("neat", &[148]),
+    ObjectDesc::new("shimmering", &[151]),
+    ObjectDesc::new("zurich", &[152]),
+    ObjectDesc::new("birds", &[153, 154, 155]),
+    ObjectDesc::new("encrusted", &[154, 155]),
+    ObjectDesc::new("beautiful", &[156]),
+    ObjectDesc::new("clockwork", &[157, 158]),
+    ObjectDesc::new("mechanical", &[157, 158]),
+    ObjectDesc::new("mahogany", &[163]),
+    ObjectDesc::new("pine", &[164]),
+    ObjectDesc::new("long", &[166]),
+    ObjectDesc::new("center", &[166]),
+    ObjectDesc::new("short", &[167]),
+    ObjectDesc::new("t", &[168]),
+    ObjectDesc::new("compas", &[169, 211]),
+    ObjectDesc::new("bronze", &[172]),
+    ObjectDesc::new("cell", &[174, 175]),
+    ObjectDesc::new("locked", &[174]),
+    ObjectDesc::new("sun", &[177]),
+    ObjectDesc::new("bare", &[200]),
+    ObjectDesc::new("song", &[203]),
+    ObjectDesc::new("north", &[205]),
+    ObjectDesc::new("northern", &[205]),
+    ObjectDesc::new("south", &[206]),
+    ObjectDesc::new("southe", &[206]),
+    ObjectDesc::new("east", &[207]),
+    ObjectDesc::new("eastern", &[207]),
+    ObjectDesc::new("west", &[208]),
+    ObjectDesc::new("western", &[208]),
+    ObjectDesc::new("dungeon", &[215]),
+];
+
+/// The nouns for objects (ovoc).
+pub static OBJECTS: &[ObjectDesc] = &[
+    ObjectDesc::new("bag", &[1, 25, 100]),
+    ObjectDesc::new("sack", &[1]),
+    ObjectDesc::new("garlic", &[2]),
+    ObjectDesc::new("clove", &[2]),
+    ObjectDesc::new("food", &[3]),
+    ObjectDesc::new("sandwitch", &[3]),
+    ObjectDesc::new("lunch", &[3]),
+    ObjectDesc::new("dinner", &[3]),
+    ObjectDesc::new("gunk", &[4, 55]),
+    ObjectDesc::new("piece", &[4, 143, 186]),
+    ObjectDesc::new("slag", &[4]),
+    ObjectDesc::new("coal", &[5]),
+    ObjectDesc::new("pile", &[5, 18, 38, 78, 87, 88, 122]),
+    ObjectDesc::new("heap", &[5]),
+    ObjectDesc::new("figurine", &[6]),
+    ObjectDesc::new("machine", &[7]),
+    ObjectDesc::new("pdp10", &[7]),
+    ObjectDesc::new("pdp11", &[7]),
+    ObjectDesc::new("dryer", &[7]),
+    ObjectDesc::new("lid", &[7]),
+    ObjectDesc::new("diamond", &[8]),
+    ObjectDesc::new("case", &[9, 123]),
+    ObjectDesc::new("bottle", &[10, 121]),
+    ObjectDesc::new("container", &[10]),
+    ObjectDesc::new("water", &[11, 209]),
+    ObjectDesc::new("quantity", &[11, 209]),
+    ObjectDesc::new("liquid", &[11, 209]),
+    ObjectDesc::new("h2o", &[11, 209]),
+    ObjectDesc::new("rope", &[12, 101]),
+    ObjectDesc::new("hemp", &[12]),
+    ObjectDesc::new("coil", &[12, 110]),
+    ObjectDesc::new("knife", &[13, 21]),
+    ObjectDesc::new("blade", &[13, 14]),
+    ObjectDesc::new("sword", &[14]),
+    ObjectDesc::new("orchri", &[14]),
+    ObjectDesc::new("glamdr", &[14]),
+    ObjectDesc::new("lamp", &[15, 16, 22]),
+    ObjectDesc::new("lantern", &[15, 16, 22]),
+    ObjectDesc::new("rug", &[17]),
+    ObjectDesc::new("carpet", &[17]),
+    ObjectDesc::new("leaves", &[18]),
+    ObjectDesc::new("leaf", &[18]),
+    ObjectDesc::new("troll", &[19]),
+    ObjectDesc::new("axe", &[20]),
+    ObjectDesc::new("keys", &[23]),
+    ObjectDesc::new("key", &[23]),
+    ObjectDesc::new("set", &[23]),
+    ObjectDesc::new("bones", &[24]),
+    ObjectDesc::new("skeleton", &[24]),
+    ObjectDesc::new("body", &[24, 73]),
+    ObjectDesc::new("coins", &[25]),
+    ObjectDesc::new("bar", &[26, 165, 168]),
+    ObjectDesc::new("necklace", &[27]),
+    ObjectDesc::new("pearls", &[27]),
+    ObjectDesc::new("mirror", &[28, 29, 212]),
+    ObjectDesc::new("ice", &[30]),
+    ObjectDesc::new("mass", &[30]),
+    ObjectDesc::new("glacier", &[30]),
+    ObjectDesc::new("ruby", &[31]),
+    ObjectDesc::new("trident", &[32]),
+    ObjectDesc::new("fork", &[32]),
+    ObjectDesc::new("coffin", &[33]),
+    ObjectDesc::new("casket", &[33]),
+    ObjectDesc::new("torch", &[34]),
+    ObjectDesc::new("cage", &[35, 36, 124, 125]),
+    ObjectDesc::new("dumbwa", &[35, 36]),
+    ObjectDesc::new("basket", &[35, 36, 98, 113]),
+    ObjectDesc::new("bracelet", &[37]),
+    ObjectDesc::new("jewel", &[37]),
+    ObjectDesc::new("timber", &[38]),
+    ObjectDesc::new("box", &[39, 53, 105]),
+    ObjectDesc::new("stradi", &[40]),
+    ObjectDesc::new("violin", &[40]),
+    ObjectDesc::new("engraving", &[41]),
+    ObjectDesc::new("inscription", &[41, 44]),
+    ObjectDesc::new("ghost", &[42]),
+    ObjectDesc::new("spirit", &[42]),
+    ObjectDesc::new("fiend", &[42]),
+    ObjectDesc::new("grail", &[43]),
+    ObjectDesc::new("prayer", &[44, 47]),
+    ObjectDesc::new("trunk", &[45]),
+    ObjectDesc::new("chest", &[45]),
+    ObjectDesc::new("bell", &[46]),
+    ObjectDesc::new("book", &[47, 49, 114, 115, 116, 117]),
+    ObjectDesc::new("bible", &[47]),
+    ObjectDesc::new("goodbook", &[47]),
+    ObjectDesc::new("candle", &[48]),
+    ObjectDesc::new("pair", &[48]),
+    ObjectDesc::new("guidebook", &[49]),
+    ObjectDesc::new("guide", &[49]),
+    ObjectDesc::new("paper", &[50, 122, 143, 186]),
+    ObjectDesc::new("newspaper", &[50]),
+    ObjectDesc::new("issue", &[50]),
+    ObjectDesc::new("report", &[50]),
+    ObjectDesc::new("magazine", &[50]),
+    ObjectDesc::new("news", &[50]),
+    ObjectDesc::new("matchbox", &[51]),
+    ObjectDesc::new("match", &[51]),
+    ObjectDesc::new("matches", &[51]),
+    ObjectDesc::new("advertizement", &[52]),
+    ObjectDesc::new("pamphlet", &[52]),
+    ObjectDesc::new("leaflet", &[52]),
+    ObjectDesc::new("booklet", &[52]),
+    ObjectDesc::new("mailbox", &[53]),
+    ObjectDesc::new("tube", &[54]),
+    ObjectDesc::new("toothpaste", &[54]),
+    ObjectDesc::new("putty", &[55]),
+    ObjectDesc::new("material", &[55]),
+    ObjectDesc::new("glue", &[55]),
+    ObjectDesc::new("wrench", &[56]),
+    ObjectDesc::new("screwdriver", &[57]),
+    ObjectDesc::new("cyclopse", &[58]),
+    ObjectDesc::new("monster", &[58]),
+    ObjectDesc::new("chalice", &[59]),
+    ObjectDesc::new("cup", &[59]),
+    ObjectDesc::new("goblet", &[59]),
+    ObjectDesc::new("painting", &[60, 149]),
+    ObjectDesc::new("art", &[60, 149]),
+    ObjectDesc::new("canvas", &[60]),
+    ObjectDesc::new("picture", &[60]),
+    ObjectDesc::new("work", &[60]),
+    ObjectDesc::new("masterpiece", &[60, 215]),
+    ObjectDesc::new("thief", &[61]),
+    ObjectDesc::new("robber", &[61]),
+    ObjectDesc::new("crimio", &[61]),
+    ObjectDesc::new("bandit", &[61]),
+    ObjectDesc::new("crook", &[61]),
+    ObjectDesc::new("gent", &[61]),
+    ObjectDesc::new("gentleman", &[61]),
+    ObjectDesc::new("man", &[61]),
+    ObjectDesc::new("thug", &[61]),
+    ObjectDesc::new("bagman", &[61]),
+    ObjectDesc::new("stille", &[62]),
+    ObjectDesc::new("window", &[63]),
+    ObjectDesc::new("bolt", &[64]),
+    ObjectDesc::new("nut", &[64]),
+    ObjectDesc::new("grate", &[65]),
+    ObjectDesc::new("grating", &[65]),
+    ObjectDesc::new("door", &[66, 67, 68, 69, 164, 172, 173, 174, 175, 189]),
+    ObjectDesc::new("trapdoor", &[66]),
+    ObjectDesc::new("trap-door", &[66]),
+    ObjectDesc::new("letter", &[67, 150]),
+    ObjectDesc::new("switch", &[70, 76, 79, 80, 81, 82, 170]),
+    ObjectDesc::new("head", &[71, 120]),
+    ObjectDesc::new("corpse", &[72, 73]),
+    ObjectDesc::new("bodies", &[73]),
+    ObjectDesc::new("dam", &[74]),
+    ObjectDesc::new("gates", &[74, 76]),
+    ObjectDesc::new("gate", &[74, 76]),
+    ObjectDesc::new("fcd", &[74]),
+    ObjectDesc::new("rail", &[75]),
+    ObjectDesc::new("railing", &[75]),
+    ObjectDesc::new("button", &[76, 79, 80, 81, 82, 127, 128, 129, 170, 176]),
+    ObjectDesc::new("bubble", &[77]),
+    ObjectDesc::new("leak", &[78]),
+    ObjectDesc::new("drip", &[78]),
+    ObjectDesc::new("hole", &[78, 107]),
+    ObjectDesc::new("bat", &[83]),
+    ObjectDesc::new("rainbow", &[84]),
+    ObjectDesc::new("pot", &[85]),
+    ObjectDesc::new("statue", &[86]),
+    ObjectDesc::new("sculpture", &[86]),
+    ObjectDesc::new("rock", &[86]),
+    ObjectDesc::new("boat", &[87, 88, 90]),
+    ObjectDesc::new("plastic", &[87, 88]),
+    ObjectDesc::new("pump", &[89]),
+    ObjectDesc::new("airpump", &[89]),
+    ObjectDesc::new("air-pump", &[89]),
+    ObjectDesc::new("label", &[91, 112]),
+    ObjectDesc::new("fineprint", &[91]),
+    ObjectDesc::new("stick", &[92]),
+    ObjectDesc::new("barrel", &[93]),
+    ObjectDesc::new("buoy", &[94]),
+    ObjectDesc::new("emerald", &[95]),
+    ObjectDesc::new("shovel", &[96]),
+    ObjectDesc::new("guano", &[97]),
+    ObjectDesc::new("crap", &[97]),
+    ObjectDesc::new("shit", &[97]),
+    ObjectDesc::new("hunk", &[97]),
+    ObjectDesc::new("balloon", &[98, 113]),
+    ObjectDesc::new("reception", &[99]),
+    ObjectDesc::new("wire", &[101, 110]),
+    ObjectDesc::new("hook", &[102, 103]),
+    ObjectDesc::new("zorkmi", &[104, 148]),
+    ObjectDesc::new("coin", &[104]),
+    ObjectDesc::new("safe", &[105]),
+    ObjectDesc::new("card", &[106, 188]),
+    ObjectDesc::new("note", &[106, 186]),
+    ObjectDesc::new("slot", &[107, 187]),
+    ObjectDesc::new("crown", &[108]),
+    ObjectDesc::new("brick", &[109]),
+    ObjectDesc::new("fuse", &[110]),
+    ObjectDesc::new("gnome", &[111, 152]),
+    ObjectDesc::new("stamp", &[118]),
+    ObjectDesc::new("tomb", &[119]),
+    ObjectDesc::new("crypt", &[119]),
+    ObjectDesc::new("grave", &[119]),
+    ObjectDesc::new("heads", &[120]),
+    ObjectDesc::new("poles", &[120]),
+    ObjectDesc::new("implements", &[120]),
+    ObjectDesc::new("losers", &[120]),
+    ObjectDesc::new("cokes", &[121]),
+    ObjectDesc::new("listing", &[122]),
+    ObjectDesc::new("stack", &[122]),
+    ObjectDesc::new("printout", &[122]),
+    ObjectDesc::new("sphere", &[126]),
+    ObjectDesc::new("ball", &[126]),
+    ObjectDesc::new("etching", &[130, 131]),
+    ObjectDesc::new("walls", &[130, 131, 198, 205, 206, 207, 208]),
+    ObjectDesc::new("wall", &[130, 131, 159, 160, 161, 162, 163, 164, 198, 205, 206, 207, 208]),
+    ObjectDesc::new("flask", &[132]),
+    ObjectDesc::new("pool", &[133]),
+    ObjectDesc::new("sewage", &[133]),
+    ObjectDesc::new("tin", &[134]),
+    ObjectDesc::new("saffron", &[134]),
+    ObjectDesc::new("spices", &[134]),
+    ObjectDesc::new("table", &[135]),
+    ObjectDesc::new("post", &[136, 166, 167]),
+    ObjectDesc::new("posts", &[136]),
+    ObjectDesc::new("bucket", &[137]),
+    ObjectDesc::new("cake", &[138, 139, 140, 141]),
+    ObjectDesc::new("icing", &[139, 140, 141]),
+    ObjectDesc::new("robot", &[142]),
+    ObjectDesc::new("robby", &[142]),
+    ObjectDesc::new("c3po", &[142]),
+    ObjectDesc::new("r2d2", &[142]),
+    ObjectDesc::new("tree", &[144, 145, 204]),
+    ObjectDesc::new("cliff", &[146, 147]),
+    ObjectDesc::new("ledge", &[146, 147]),
+    ObjectDesc::new("stack", &[148]),
+    ObjectDesc::new("bills", &[148]),
+    ObjectDesc::new("portrait", &[149]),
+    ObjectDesc::new("vault", &[150]),
+    ObjectDesc::new("cube", &[150]),
+    ObjectDesc::new("curtain", &[151]),
+    ObjectDesc::new("light", &[151]),
+    ObjectDesc::new("nest", &[153]),
+    ObjectDesc::new("egg", &[154, 155]),
+    ObjectDesc::new("bauble", &[156]),
+    ObjectDesc::new("canary", &[157, 158]),
+    ObjectDesc::new("panel", &[159, 160, 161, 162, 163, 164, 213]),
+    ObjectDesc::new("pole", &[166, 167]),
+    ObjectDesc::new("tbar", &[168]),
+    ObjectDesc::new("t-bar", &[168]),
+    ObjectDesc::new("arrow", &[169]),
+    ObjectDesc::new("point", &[169]),
+    ObjectDesc::new("beam", &[171]),
+    ObjectDesc::new("dial", &[177]),
+    ObjectDesc::new("sundial", &[177]),
+    ObjectDesc::new("1", &[178]),
+    ObjectDesc::new("one", &[178]),
+    ObjectDesc::new("2", &[179]),
+    ObjectDesc::new("two", &[179]),
+    ObjectDesc::new("3", &[180]),
+    ObjectDesc::new("three", &[180]),
+    ObjectDesc::new("4", &[181]),
+    ObjectDesc::new("four", &[181]),
+    ObjectDesc::new("5", &[182]),
+    ObjectDesc::new("five", &[182]),
+    ObjectDesc::new("6", &[183]),
+    ObjectDesc::new("six", &[183]),
+    ObjectDesc::new("7", &[184]),
+    ObjectDesc::new("seven", &[184]),
+    ObjectDesc::new("8", &[185]),
+    ObjectDesc::new("eight", &[185]),
+    ObjectDesc::new("warning", &[186]),
+    ObjectDesc::new("slit", &[187]),
+    ObjectDesc::new("it", &[192]),
+    ObjectDesc::new("that", &[192]),
+    ObjectDesc::new("this", &[192]),
+    ObjectDesc::new("me", &[193]),
+    ObjectDesc::new("myself", &[193]),
+    ObjectDesc::new("cretin", &[193]),
+    ObjectDesc::new("all", &[194]),
+    ObjectDesc::new("everything", &[194]),
+    ObjectDesc::new("treasure", &[195]),
+    ObjectDesc::new("valuable", &[195]),
+    ObjectDesc::new("sailor", &[196]),
+    ObjectDesc::new("teeth", &[197]),
+    ObjectDesc::new("grue", &[199]),
+    ObjectDesc::new("hand", &[200]),
+    ObjectDesc::new("hands", &[200]),
+    ObjectDesc::new("lungs", &[201]),
+    ObjectDesc::new("air", &[201]),
+    ObjectDesc::new("aviator", &[202]),
+    ObjectDesc::new("flyer", &[202]),
+    ObjectDesc::new("bird", &[203]),
+    ObjectDesc::new("songbird", &[203]),
+    ObjectDesc::new("guard", &[210]),
+    ObjectDesc::new("guardian", &[210]),
+    ObjectDesc::new("rose", &[211]),
+    ObjectDesc::new("structure", &[212]),
+    ObjectDesc::new("channel", &[214]),
+    ObjectDesc::new("keeper", &[215]),
+    ObjectDesc::new("ladder", &[216]),
+];
+
+/// The Radix-50 code of a character the lexer takes: A to Z are 1 to 26,
+/// '-' is 27, and 1 to 9 are 31 to 39. '0' would be 30, but the lexer
+/// doesn't take it (or lowercase letters).
+pub fn char_code(c: u8) -> Option<c_int> {
+    match c {
+        b'A'..=b'Z' => Some((c - b'A') as c_int + 1),
+        b'-' => Some(27),
+        b'1'..=b'9' => Some((c - b'1') as c_int + 31),
+        _ => None,
+    }
 }
 
-lazy_static! {
-    static OBJECT_DESCRIPTIONS: Vec<ObjectDesc> = vec![
-        ObjectDesc {
-            id: 1,
-
-            names: vec!["bag", "sack"],
-            adjectives: vec!["brown", "elongated"]
-        },
-        ObjectDesc {
-            id: 2,
-
-            names: vec!["garlic", "clove"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 3,
-
-            names: vec!["food", "sandwitch", "lunch", "dinner"],
-            adjectives: vec!["hot", "pepper"]
-        },
-        ObjectDesc {
-            id: 4,
-
-            names: vec!["gunk", "piece", "slag"],
-            adjectives: vec!["vitreous"]
-        },
-        ObjectDesc {
-            id: 5,
-
-            names: vec!["coal", "pile", "heap"],
-            adjectives: vec!["small"]
-        },
-        ObjectDesc {
-            id: 6,
-
-            names: vec!["figurine"],
-            adjectives: vec!["jade"]
-        },
-        ObjectDesc {
-            id: 7,
-
-            names: vec!["machine", "pdpnm", "pdpnn", "dryer", "lid"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 8,
-
-            names: vec!["diamond"],
-            adjectives: vec!["huge", "enormous"]
-        },
-        ObjectDesc {
-            id: 9,
-
-            names: vec!["case"],
-            adjectives: vec!["trophy"]
-        },
-        ObjectDesc {
-            id: 10,
-
-            names: vec!["bottle", "container"],
-            adjectives: vec!["clear", "glass"]
-        },
-        ObjectDesc {
-            id: 11,
-
-            names: vec!["water", "quantity", "liquid", "hoo"],
-            adjectives:
-        },
-        ObjectDesc {
-            id: 12,
-
-            names: vec!["rope", "hemp", "coil"],
-            adjectives: vec!["large"]
-        },
-        ObjectDesc {
-            id: 13,
-
-            names: vec!["knife", "blade"],
-            adjectives: vec!["nasty"]
-        },
-        ObjectDesc {
-            id: 14,
-
-            names: vec!["sword", "orchrist", "glamring", "blade"],
-            adjectives: vec!["elvish"]
-        },
-        ObjectDesc {
-            id: 15,
-
-            names: vec!["lamp", "lantern"],
-            adjectives: vec!["brass"]
-        },
-        ObjectDesc {
-            id: 16,
-
-            names: vec!["lamp", "lantern"],
-            adjectives: vec!["brass", "broken"]
-        },
-        ObjectDesc {
-            id: 17,
-
-            names: vec!["rug", "carpet"],
-            adjectives: vec!["oriental"]
-        },
-        ObjectDesc {
-            id: 18,
-
-            names: vec!["leaves", "leaf"],
-            adjectives:
-        },
-        ObjectDesc {
-            id: 19,
-
-            names: vec!["troll"],
-            adjectives:
-        },
-        ObjectDesc {
-            id: 20,
-
-            names: vec!["axe"],
-            adjectives: vec!["bloody"]
-        },
-        ObjectDesc {
-            id: 21,
-            
-            names: vec!["knife"],
-            adjectives: vec!["rusty"]
-        },
-        ObjectDesc {
-            id: 22,
-
-            names: vec!["lamp", "lantern"],
-            adjectives: vec!["broken", "burned", "dead"]
-        },
-        ObjectDesc {
-            id: 23,
-
-            names: vec!["keys", "key", "set"],
-            adjectives:
-        },
-        ObjectDesc {
-            id: 24,
-
-            names: vec!["bones", "skeleton", "body"],
-            adjectives:
-        },
-        ObjectDesc {
-            id: 25,
-
-            names: vec!["coins", "bag"],
-            adjectives: vec!["old", "leather"]
-        },
-        ObjectDesc {
-            id: 26,
-
-            names: vec!["bar"],
-            adjectives: vec!["large", "platinum"]
-        },
-        ObjectDesc {
-            id: 27,
-
-            names: vec!["necklace", "pearls"],
-            adjectives: vec!["pearl"]
-        },
-        ObjectDesc {
-            id: 28,
-
-            names: vec!["mirror"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 29,
-
-            names: vec!["mirror"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 30,
-
-            names: vec!["ice", "mass", "glacier"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 31,
-
-            names: vec!["ruby"],
-            adjectives: vec!["moby"]
-        },
-        ObjectDesc {
-            id: 32,
-
-            names: vec!["trident", "fork"],
-            adjectives: vec!["crystal"]
-        },
-        ObjectDesc {
-            id: 33,
-
-            names: vec!["coffin", "casket"],
-            adjectives: vec!["gold"]
-        },
-        ObjectDesc {
-            id: 34,
-
-            names: vec!["torch"],
-            adjectives: vec!["ivory"]
-        },
-        ObjectDesc {
-            id: 35,
-
-            names: vec!["cage", "dumbwaiter", "basket"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 36,
-
-            names: vec!["cage", "dumbwaiter", "basket"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 37,
-
-            names: vec!["bracelet", "jewel"],
-            adjectives: vec!["sapphire"]
-        },
-        ObjectDesc {
-            id: 38,
-
-            names: vec!["timber", "pile"],
-            adjectives: vec!["wooden", "wood"]
-        },
-        ObjectDesc {
-            id: 39,
-
-            names: vec!["box"],
-            adjectives: vec!["steel", "dented"]
-        },
-        ObjectDesc {
-            id: 40,
-
-            names: vec!["stradivarius", "violin"],
-            adjectives: vec!["fancy"]
-        },
-        ObjectDesc {
-            id: 41,
-
-            names: vec!["engraving", "inscription"],
-            adjectives: vec!["old", "ancient"]
-        },
-        ObjectDesc {
-            id: 42,
-
-            names: vec!["ghost", "spirit", "fiend"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 43,
-            
-            names: vec!["grail"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 44,
-
-            names: vec!["prayer", "inscription"],
-            adjectives: vec!["old", "ancient"]
-        },
-        ObjectDesc {
-            id: 45,
-
-            names: vec!["trunk", "chest"],
-            adjectives: vec!["old"]
-        },
-        ObjectDesc {
-            id: 46,
-
-            names: vec!["bell"],
-            adjectives: vec!["brass", "small"]
-        },
-        ObjectDesc {
-            id: 47,
-
-            names: vec!["book", "bible", "goodbook", "prayerbook"],
-            adjectives: vec!["large", "black"]
-        },
-        ObjectDesc {
-            id: 48,
-
-            names: vec!["candle", "pair"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 49,
-
-            names: vec!["guidebook", "guide", "book"],
-            adjectives: vec!["tour"]
-        },
-        ObjectDesc {
-            id: 50,
-
-            names: vec!["paper", "newspaper", "issue", "report", "magazine", "news"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 51,
-
-            names: vec!["matchbox", "match", "matches"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 52,
-
-            names: vec!["advertizement", "pamphlet", "leaflet", "booklet"],
-            adjectives: vec!["small"]
-        },
-        ObjectDesc {
-            id: 53,
-
-            names: vec!["mailbox", "box"],
-            adjectives: vec!["small"]
-        },
-        ObjectDesc {
-            id: 54,
-
-            names: vec!["tube", "toothpaste"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 55,
-
-            names: vec!["putty", "material", "glue", "gunk"],
-            adjectives: vec!["viscous"]
-        },
-        ObjectDesc {
-            id: 56,
-
-            names: vec!["wrench"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 57,
-
-            names: vec!["screwdriver"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 58,
-
-            names: vec!["cyclopse", "monster"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 59,
-
-            names: vec!["chalice", "cup", "goblet"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 60,
-
-            names: vec!["painting", "art", "cantur", "picture", "work", "masterpiece"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 61,
-
-            names: vec!["thief", "robber", "criminal", "bandit", "crook", "gent",
-                "gentleman", "man", "thug", "bagman"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 62,
-
-            names: vec!["stille"],
-            adjectives: vec!["vicious"]
-        },
-        ObjectDesc {
-            id: 63,
-
-            names: vec!["window"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 64,
-
-            names: vec!["bolt", "nut"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 65,
-
-            names: vec!["grate", "grating"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 66,
-
-            names: vec!["door", "trapdoor", "trap-door"],
-            adjectives: vec!["trap"]
-        },
-        ObjectDesc {
-            id: 67,
-
-            names: vec!["letter", "door"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 68,
-
-            names: vec!["door"],
-            adjectives: vec!["front"]
-        },
-        ObjectDesc {
-            id: 69,
-
-            names: vec!["door"],
-            adjectives: vec!["stone"]
-        },
-        ObjectDesc {
-            id: 70,
-
-            names: vec!["switch"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 71,
-
-            names: vec!["head"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 72,
-            
-            names: vec!["corpse"],
-            adjectives: vec!["mangled"]
-        },
-        ObjectDesc {
-            id: 73,
-
-            names: vec!["bodies", "body", "corpse", "corpses"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 74,
-
-            names: vec!["dam", "gates", "gate", "fcd"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 75,
-
-            names: vec!["rail", "railing"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 76,
-
-            names: vec!["button", "switch", "gates", "gate"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 77,
-
-            names: vec!["bubble"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 78,
-
-            names: vec!["leak", "drip", "hole", "pile"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 79,
-
-            names: vec!["switch", "button"],
-            adjectives: vec!["red"]
-        },
-        ObjectDesc {
-            id: 80,
-
-            names: vec!["switch", "button"],
-            adjectives: vec!["yellow"]
-        },
-        ObjectDesc {
-            id: 81,
-
-            names: vec!["switch", "button"],
-            adjectives: vec!["brown"]
-        },
-        ObjectDesc {
-            id: 82,
-
-            names: vec!["switch", "button"],
-            adjectives: vec!["blue"] 
-        },
-        ObjectDesc {
-            id: 83,
-
-            names: vec!["bat"],
-            adjectives: vec!["vampire", "vampiric"]
-        },
-        ObjectDesc {
-            id: 84,
-
-            names: vec!["rainbow"],
-            adjectives:
-        },
-        ObjectDesc {
-            id: 85,
-
-            names: vec!["pot"],
-            adjectives: vec!["gold"]
-        },
-        ObjectDesc {
-            id: 86,
-
-            names: vec!["statue", "sculpture", "rock"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 87,
-            
-            names: vec!["boat", "plastic", "pile"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 88,
-
-            names: vec!["pile", "boat", "plastic"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 89,
-
-            names: vec!["pump", "airpump", "air-pump"],
-            adjectives: vec!["small"]
-        },
-        ObjectDesc {
-            id: 90,
-            
-            names: vec!["boat"],
-            adjectives: vec!["magic", "seaworthy"]
-        },
-        ObjectDesc {
-            id: 91,
-
-            names: vec!["label", "fineprint"],
-            adjectives: vec!["tan"]
-        },
-        ObjectDesc {
-            id: 92,
-
-            names: vec!["stick"],
-            adjectives: vec!["broken", "sharp"]
-        },
-        ObjectDesc {
-            id: 93,
-
-            names: vec!["barrel"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 94,
-
-            names: vec!["buoy"],
-            adjectives: vec!["red"]
-        },
-        ObjectDesc {
-            id: 95,
-
-            names: vec!["emerald"],
-            adjectives: vec!["large"]
-        },
-        ObjectDesc {
-            id: 96,
-
-            names: vec!["shovel"],
-            adjectives: vec!["large"]
-        },
-        ObjectDesc {
-            id: 97,
-
-            names: vec!["guano", "crap", "shit", "hunk"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 98,
-
-            names: vec!["balloon", "basket"],
-            adjectives: vec!["wicker"]
-        },
-        ObjectDesc {
-            id: 99,
-
-            names: vec!["reception"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 100,
-
-            names: vec!["bag"],
-            adjectives: vec!["cloth"]
-        },
-        ObjectDesc {
-            id: 101,
-
-            names: vec!["wire", "rope"],
-            adjectives: vec!["braided"]
-        },
-        ObjectDesc {
-            id: 102,
-
-            names: vec!["hook"],
-            adjectives: vec!["small"]
-        },
-        ObjectDesc {
-            id: 103,
-
-            names: vec!["hook"],
-            adjectives: vec!["small"]
-        },
-        ObjectDesc {
-            id: 104,
-
-            names: vec!["zorkmi", "coin"],
-            adjectives: vec!["gold"]
-        },
-        ObjectDesc {
-            id: 105,
-
-            names: vec!["safe", "box"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 106,
-
-            names: vec!["card", "note"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 107,
-
-            names: vec!["slot", "hole"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 108,
-
-            names: vec!["crown"],
-            adjectives: vec!["gaudy"]
-        },
-        ObjectDesc {
-            id: 109,
-            
-            names: vec!["brick"],
-            adjectives: vec!["square", "clay"]
-        },
-        ObjectDesc {
-            id: 110,
-
-            names: vec!["fuse", "coil", "wire"],
-            adjectives: vec!["shiny", "thin"]
-        },
-        ObjectDesc {
-            id: 111,
-
-            names: vec!["gnome"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 112,
-
-            names: vec!["label", "pile"],
-            adjectives: vec!["blue"]
-        },
-        ObjectDesc {
-            id: 113,
-
-            names: vec!["balloon", "basket"],
-            adjectives: vec!["broken"]
-        },
-        ObjectDesc {
-            id: 114,
-
-            names: vec!["book"],
-            adjectives: vec!["blue"]
-        },
-        ObjectDesc {
-            id: 115,
-
-            names: vec!["book"],
-            adjectives: vec!["green"]
-        },
-        ObjectDesc {
-            id: 116,
-
-            names: vec!["book"],
-            adjectives: vec!["purple"]
-        },
-        ObjectDesc {
-            id: 117,
-
-            names: vec!["book"],
-            adjectives: vec!["white"]
-        },
-        ObjectDesc {
-            id: 118,
-
-            names: vec!["stamp"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 119,
-
-            names: vec!["tomb", "crypt", "grave"],
-            adjectives: vec!["marble"]
-        },
-        ObjectDesc {
-            id: 120,
-
-            names: vec!["heads", "poles", "implements", "losers", "head"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 121,
-
-            names: vec!["cokes", "bottle", "bottles"],
-            adjectives: vec!["coke", "empty"] 
-        },
-        ObjectDesc {
-            id: 122,
-
-            names: vec!["listing", "stack", "printout", "paper"],
-            adjectives: vec!["enormous"]
-        },
-        ObjectDesc {
-            id: 123,
-
-            names: vec!["case"],
-            adjectives: vec!["large"]
-        },
-        ObjectDesc {
-            id: 124,
-
-            names: vec!["cage"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 125,
-
-            names: vec!["cage"],
-            adjectives: vec!["steel"]
-        },
-        ObjectDesc {
-            id: 126,
-
-            names: vec!["sphere", "ball"],
-            adjectives: vec!["glass"]
-        },
-        ObjectDesc {
-            id: 127,
-
-            names: vec!["button"],
-            adjectives: vec!["square"]
-        },
-        ObjectDesc {
-            id: 128,
-
-            names: vec!["button"],
-            adjectives: vec!["round"]
-        },
-        ObjectDesc {
-            id: 129,
-
-            names: vec!["button"],
-            adjectives: vec!["triangle", "triangular"]
-        },
-        ObjectDesc {
-            id: 130,
-
-            names: vec!["etching", "walls", "wall"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 131,
-
-            names: vec!["etching", "walls", "wall"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 132,
-
-            names: vec!["flask"],
-            adjectives: vec!["glass"]
-        },
-        ObjectDesc {
-            id: 133,
-
-            names: vec!["pool", "sewage"],
-            adjectives: vec!["large"]
-        },
-        ObjectDesc {
-            id: 134,
-
-            names: vec!["tin", "saffron", "spices"],
-            adjectives: vec!["rare"]
-        },
-        ObjectDesc {
-            id: 135,
-
-            names: vec!["table"],
-            adjectives: vec!["large", "oblong"]
-        },
-        ObjectDesc {
-            id: 136,
-
-            names: vec!["post", "posts"],
-            adjectives: vec!["wooden", "wood"]
-        },
-        ObjectDesc {
-            id: 137,
-
-            names: vec!["bucket"],
-            adjectives: vec!["wooden", "wood"]
-        },
-        ObjectDesc {
-            id: 138,
-            
-            names: vec!["cake"],
-            adjectives: vec!["eat-me", "eatme"]
-        },
-        ObjectDesc {
-            id: 139,
-            
-            names: vec!["icing", "cake"],
-            adjectives: vec!["orange"]
-        },
-        ObjectDesc {
-            id: 140,
-
-            names: vec!["cake", "icing"],
-            adjectives: vec!["red"]
-        },
-        ObjectDesc {
-            id: 141,
-
-            names: vec!["cake", "icing"],
-            adjectives: vec!["blue", "ecch"]
-        },
-        ObjectDesc {
-            id: 142,
-
-            names: vec!["robot", "robby", "cppo", "rudo"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 143,
-
-            names: vec!["paper", "piece"],
-            adjectives: vec!["green"]
-        },
-        ObjectDesc {
-            id: 144,
-
-            names: vec!["tree"],
-            adjectives: vec![]  // TODO: Shouldn't one or both of these be "large"?
-        },
-        ObjectDesc {
-            id: 145,
-
-            names: vec!["tree"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 146,
-
-            names: vec!["cliff", "ledge"],
-            adjectives: vec!["large"] 
-        },
-        ObjectDesc {
-            id: 147,
-
-            names: vec!["cliff", "ledge"],
-            adjectives: vec!["large", "white", "rocky", "sheer"]
-        },
-        ObjectDesc {
-            id: 148,
-
-            names: vec!["stack", "bills", "zorkmi"],
-            adjectives: vec!["omm", "neat"]
-        },
-        ObjectDesc {
-            id: 149,
-
-            names: vec!["portrait", "painting", "art"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 150,
-
-            names: vec!["vault", "cube", "lettering"],
-            adjectives: vec!["large", "stone"]
-        },
-        ObjectDesc {
-            id: 151,
-
-            names: vec!["curtain", "light"],
-            adjectives: vec!["shimmering"]
-        },
-        ObjectDesc {
-            id: 152,
-            
-            names: vec!["gnome"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 153,
-
-            names: vec!["nest"],
-            adjectives: vec!["small", "birds"]
-        },
-        ObjectDesc {
-            id: 154,
-            
-            names: vec!["egg"],
-            adjectives: vec!["birds", "encrusted"]
-        },
-        ObjectDesc {
-            id: 155,
-
-            names: vec!["egg"],
-            adjectives: vec!["broken", "birds", "encrusted"]
-        },
-        ObjectDesc {
-            id: 156,
-
-            names: vec!["bauble"],
-            adjectives: vec!["brass", "beautiful"]
-        },
-        ObjectDesc {
-            id: 157,
-
-            names: vec!["canary"],
-            adjectives: vec!["gold", "clockwork", "mechanical"]
-        },
-        ObjectDesc {
-            id: 158,
-
-            names: vec!["canary"],
-            adjectives: vec!["broken", "gold", "clockwork", "mechanical"]
-        },
-        ObjectDesc {
-            id: 159,
-
-            names: vec!["panel", "wall"],
-            adjectives: vec!["yellow"]
-        },
-        ObjectDesc {
-            id: 160,
-
-            names: vec!["panel", "wall"],
-            adjectives: vec!["white"]
-        },
-        ObjectDesc {
-            id: 161,
-
-            names: vec!["panel", "wall"],
-            adjectives: vec!["red"]
-        },
-        ObjectDesc {
-            id: 162,
-
-            names: vec!["panel", "wall"],
-            adjectives: vec!["black"]
-        },
-        ObjectDesc {
-            id: 163,
-
-            names: vec!["panel", "wall"],
-            adjectives: vec!["mahogany"]
-        },
-        ObjectDesc {
-            id: 164,
-
-            names: vec!["panel", "wall"],
-            adjectives: vec!["pine"]
-        },
-        ObjectDesc {
-            id: 165,
-
-            names: vec!["bar"],
-            adjectives: vec!["wooden", "wood"] 
-        },
-        ObjectDesc {
-            id: 166,
-
-            names: vec!["pole", "post"],
-            adjectives: vec!["long", "center"]
-        },
-        ObjectDesc {
-            id: 167,
-
-            names: vec!["pole", "post"],
-            adjectives: vec!["short"]
-        },
-        ObjectDesc {
-            id: 168,
-
-            names: vec!["tbar", "t-bar", "bar"],
-            adjectives: vec!["t"]
-        },
-        ObjectDesc {
-            id: 169,
-
-            names: vec!["arrow", "point"],
-            adjectives: vec!["compass"]
-        },
-        ObjectDesc {
-            id: 170,
-
-            names: vec!["switch", "button"],
-            adjectives: vec!["red"]
-        },
-        ObjectDesc {
-            id: 171,
-
-            names: vec!["beam"],
-            adjectives: vec!["red"]
-        },
-        ObjectDesc {
-            id: 172,
-
-            names: vec!["door"],
-            adjectives: vec!["bronze"]
-        },
-        ObjectDesc {
-            id: 173,
-
-            names: vec!["door"],
-            adjectives: vec!["wooden", "wood"]
-        },
-        ObjectDesc {
-            id: 174,
-
-            names: vec!["door"],
-            adjectives: vec!["wooden", "wood", "cell", "locked"]
-        },
-        ObjectDesc {
-            id: 175,
-
-            names: vec!["door"],
-            adjectives: vec!["wooden", "wood", "cell"]
-        },
-        ObjectDesc {
-            id: 176,
-
-            names: vec!["button"],
-            adjectives: vec!["large"]
-        },
-        ObjectDesc {
-            id: 177,
-
-            names: vec!["dial", "sundial"],
-            adjectives: vec!["sun"]
-        },
-        ObjectDesc {
-            id: 178,
-
-            names: vec!["1", "one"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 179,
-
-            names: vec!["2", "two"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 180,
-            
-            names: vec!["3", "three"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 181,
-
-            names: vec!["4", "four"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 182,
-
-            names: vec!["5", "five"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 183,
-
-            names: vec!["6", "six"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 184,
-
-            names: vec!["7", "seven"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 185,
-
-            names: vec!["8", "eight"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 186,
-
-            names: vec!["warning", "paper", "piece", "note"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 187,
-
-            names: vec!["slit", "slot"],
-            adjectives: vec!["small"]
-        },
-        ObjectDesc {
-            id: 188,
-
-            names: vec!["card"],
-            adjectives: vec!["gold"]
-        },
-        ObjectDesc {
-            id: 189,
-
-            names: vec!["door"],
-            adjectives: vec!["steel"]
-        },
-        
-        
-        // No entries for objects 190 and 191.
-        ObjectDesc {
-            id: 190,
-            names: vec![],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 191,
-            names: vec![],
-            adjectives: vec![]
-        },
-
-
-        ObjectDesc {
-            id: 192,
-
-            names: vec!["it", "that", "this"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 193,
-
-            names: vec!["me", "myself", "cretin"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 194,
-
-            names: vec!["all", "everything"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 195,
-
-            names: vec!["treasure", "valuable"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 196,
-
-            names: vec!["sailor"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 197,
-
-            names: vec!["teeth"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 198,
-
-            names: vec!["walls", "wall"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 199,
-
-            names: vec!["grue"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 200,
-
-            names: vec!["hand", "hands"],
-            adjectives: vec!["bare"]
-        },
-        ObjectDesc {
-            id: 201,
-            
-            names: vec!["lungs", "air"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 202,
-
-            names: vec!["aviator", "flyer"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 203,
-
-            names: vec!["bird", "songbird"],
-            adjectives: vec!["song"]
-        },
-        ObjectDesc {
-            id: 204,
-
-            names: vec!["tree"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 205,
-
-            names: vec!["walls", "wall"],
-            adjectives: vec!["north", "northern"]
-        },
-        ObjectDesc {
-            id: 206,
-
-            names: vec!["walls", "wall"],
-            adjectives: vec!["south", "southern"]
-        },
-        ObjectDesc {
-            id: 207,
-
-            names: vec!["walls", "wall"],
-            adjectives: vec!["east", "eastern"]
-        },
-        ObjectDesc {
-            id: 208,
-
-
-            names: vec!["walls", "wall"],
-            adjectives: vec!["west", "western"]
-        },
-        ObjectDesc {
-            id: 209,
-
-            names: vec!["water", "quantity", "liquid", "hoo", "h2o"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 210,
-
-            names: vec!["guard", "guardian"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 211,
-
-            names: vec!["rose"],
-            adjectives: vec!["compass"]
-        },
-        ObjectDesc {
-            id: 212,
-
-            names: vec!["structure", "mirror"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 213,
-
-            names: vec!["panel"],
-            adjectives: vec![]
-        },
-        ObjectDesc {
-            id: 214,
-
-            names: vec!["channel"],
-            adjectives: vec!["stone"]
-        },
-        ObjectDesc {
-            id: 215,
-
-            names: vec!["keeper", "masterpiece"],
-            adjectives: vec!["dungeon"]
-        },
-        ObjectDesc {
-            id: 216,
-
-            names: vec!["ladder"],
-            adjectives: vec![]
-        }
-    ];
+/// Encodes a word as the lexer does, as a pair of Radix-50 tokens: each
+/// token holds three characters' codes (see `char_code()`) as
+/// `first * 1600 + second * 40 + third`. Only the first six characters
+/// count, and missing ones are 0. Letters can be either case.
+///
+/// Returns `None` for an empty word, or one with a character the lexer
+/// doesn't take.
+pub fn encode(word: &str) -> Option<[c_int; 2]> {
+    encode_codes(word, |c| char_code(c.to_ascii_uppercase()))
 }
 
+/// Encodes a word the way the tables' words are: as `encode()` does, but
+/// with '0' as 30. A few of them (like PDP10) have one; the lexer never
+/// produces those tokens, so the game can't read the word (see
+/// `is_readable()`).
+pub fn encode_name(name: &str) -> Option<[c_int; 2]> {
+    encode_codes(name, |c| if c == b'0' { Some(30) } else { char_code(c.to_ascii_uppercase()) })
+}
 
+/// The tokens of a word in the tables, from `encode_name()`.
+///
+/// Panics if `name` has a character the tables can't hold.
+pub fn table_tokens(name: &str) -> [c_int; 2] {
+    encode_name(name).unwrap_or_else(|| panic!("{:?} isn't a word", name))
+}
 
+/// Whether the lexer can produce `tokens` for some word. It can't if they
+/// hold a '0'.
+pub fn is_readable(tokens: [c_int; 2]) -> bool {
+    tokens.iter().all(|&token| [1600, 40, 1].iter().all(|&place| token / place % 40 != 30))
+}
 
+// Encodes a word with the codes of its characters from `code`.
+fn encode_codes<F: Fn(u8) -> Option<c_int>>(word: &str, code: F) -> Option<[c_int; 2]> {
+    if word.is_empty() {
+        return None;
+    }
+    let mut tokens = [0; 2];
+    for (index, c) in word.bytes().enumerate() {
+        let code = code(c)?;
+        if index < 6 {
+            tokens[index / 3] += code * [1600, 40, 1][index % 3];
+        }
+    }
+    Some(tokens)
+}
 
+/// Decodes a pair of tokens into the (up to six) characters of the word they
+/// hold, in uppercase. Codes no character has are shown as '?'.
+pub fn decode(tokens: [c_int; 2]) -> String {
+    let mut word = String::new();
+    for &token in &tokens {
+        for &place in &[1600, 40, 1] {
+            word.push(match token / place % 40 {
+                0 => ' ',
+                code @ 1..=26 => (b'A' + code as u8 - 1) as char,
+                27 => '-',
+                code @ 30..=39 => (b'0' + code as u8 - 30) as char,
+                _ => '?',
+            });
+        }
+    }
+    word.trim_end().to_string()
+}
+
+/// The buzzword array, buzvoc_, which ends with a null word.
+pub fn buzzword_array() -> Vec<c_int> {
+    let mut array = Vec::new();
+    for buzzword in BUZZWORDS {
+        buzzword.encode(&mut array);
+    }
+    array.extend(&[0, 0]);
+    array
+}
 
+/// The verb array, vvoc, which ends with -1.
+pub fn verb_array() -> Vec<c_int> {
+    let mut array = Vec::new();
+    for verb in VERBS {
+        verb.encode(&mut array);
+    }
+    array.push(-1);
+    array
+}
 
+/// The direction array, dirvoc_.
+pub fn direction_array() -> Vec<c_int> {
+    let mut array = Vec::new();
+    for direction in DIRECTIONS {
+        direction.encode(&mut array);
+    }
+    array
+}
 
+/// The preposition array, prpvoc_.
+pub fn preposition_array() -> Vec<c_int> {
+    let mut array = Vec::new();
+    for preposition in PREPOSITIONS {
+        preposition.encode(&mut array);
+    }
+    array
+}
 
+/// The adjective array, avoc, which ends with -1.
+pub fn adjective_array() -> Vec<c_int> {
+    let mut array = Vec::new();
+    for adjective in ADJECTIVES {
+        adjective.encode(&mut array);
+    }
+    array.push(-1);
+    array
+}
 
+/// The object array, ovoc, which ends with -1.
+pub fn object_array() -> Vec<c_int> {
+    let mut array = Vec::new();
+    for object in OBJECTS {
+        object.encode(&mut array);
+    }
+    array.push(-1);
+    array
+}
@@ -0,0 +1,200 @@
+//! The format of versions 1 and 2 of saved games, which is also what the C
+//! version's dsave.dat holds: the parts of the game's state that savegm_()
+//! wrote, field by field. Games in it are read, and put onto a `GameState`;
+//! nothing writes it any more.
+
+use serde::{Deserialize, Serialize};
+use state::GameState;
+use super::SaveError;
+
+// Rooms, objects, etc. in a saved game. These match the sizes of the arrays
+// in vars.h.
+pub const OBJECTS: usize = 220;
+pub const ROOMS: usize = 200;
+pub const ADVENTURERS: usize = 4;
+pub const FLAGS: usize = 46;
+pub const SWITCHES: usize = 22;
+pub const VILLAINS: usize = 4;
+pub const CLOCK_EVENTS: usize = 25;
+pub const PUZZLE_SQUARES: usize = 64;
+
+/// A game in versions 1 and 2 of the format, or in the C version's
+/// dsave.dat: the parts of the state that savegm_() wrote.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fields {
+    pub version: (i32, i32, i32),
+
+    pub winner: i32,
+    pub here: i32,
+    pub thief_position: i32,
+    pub tel_flag: bool,
+    pub thief_flag: bool,
+    pub thief_active: bool,
+    pub sword_active: bool,
+    pub sword_state: i32,
+    pub puzzle: Vec<i32>,
+
+    // Minutes played.
+    pub play_time: i32,
+
+    /// When the session that saved the game started. Only version 1 of the
+    /// format has it, and it isn't used; it's kept so that those games'
+    /// checksums still match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<(i32, i32, i32)>,
+    pub moves: i32,
+    pub deaths: i32,
+    pub raw_score: i32,
+    pub endgame_score: i32,
+    pub max_load: i32,
+    pub light_shift: i32,
+    pub balloon_location: i32,
+    pub munged_room: i32,
+    pub hello_sailor: i32,
+    pub from_direction: i32,
+    pub scol_room: i32,
+    pub scol_active: i32,
+
+    pub object_description_1: Vec<i32>,
+    pub object_description_2: Vec<i32>,
+    pub object_flags_1: Vec<i32>,
+    pub object_flags_2: Vec<i32>,
+    pub object_fval: Vec<i32>,
+    pub object_tval: Vec<i32>,
+    pub object_size: Vec<i32>,
+    pub object_capacity: Vec<i32>,
+    pub object_room: Vec<i32>,
+    pub object_adventurer: Vec<i32>,
+    pub object_container: Vec<i32>,
+
+    pub room_value: Vec<i32>,
+    pub room_flags: Vec<i32>,
+
+    pub adventurer_room: Vec<i32>,
+    pub adventurer_score: Vec<i32>,
+    pub adventurer_vehicle: Vec<i32>,
+    pub adventurer_strength: Vec<i32>,
+    pub adventurer_flags: Vec<i32>,
+
+    pub flags: Vec<bool>,
+    pub switches: Vec<i32>,
+    pub villain_probability: Vec<i32>,
+    pub clock_event_flags: Vec<bool>,
+    pub clock_event_ticks: Vec<i32>,
+
+    // The object "it" refers to, and the parser's half-finished command.
+    pub last_it: i32,
+    pub orphans: [i32; 5],
+}
+
+impl Fields {
+    /// Puts the game onto `state`. What the fields don't cover is left as
+    /// it is.
+    pub fn apply_to(&self, state: &mut GameState) {
+        let objects = &mut state.objects;
+        let rooms = &mut state.rooms;
+        let adventurers = &mut state.adventurers;
+
+        state.player.winner = self.winner;
+        state.player.current_room = self.here;
+        state.hack.thief_position = self.thief_position;
+        state.player.tel_flag = self.tel_flag.into();
+        state.hack.thief_flag = self.thief_flag.into();
+        state.hack.thief_active = self.thief_active.into();
+        state.hack.sword_active = self.sword_active.into();
+        state.hack.sword_state = self.sword_state;
+        state.puzzle.squares.copy_from_slice(&self.puzzle);
+
+        state.play_time.play_time = self.play_time;
+        state.game_state.moves = self.moves;
+        state.game_state.deaths = self.deaths;
+        state.game_state.raw_score = self.raw_score;
+        state.game_state.endgame_score = self.endgame_score;
+        state.game_state.max_load = self.max_load;
+        state.game_state.light_shift = self.light_shift;
+        state.game_state.balloon_location = self.balloon_location;
+        state.game_state.munged_room = self.munged_room;
+        state.game_state.hello_sailor = self.hello_sailor;
+        state.screen.from_direction = self.from_direction;
+        state.screen.scol_room = self.scol_room;
+        state.screen.scol_active = self.scol_active;
+
+        objects.description_1.copy_from_slice(&self.object_description_1);
+        objects.description_2.copy_from_slice(&self.object_description_2);
+        objects.flags_1.copy_from_slice(&self.object_flags_1);
+        objects.flags_2.copy_from_slice(&self.object_flags_2);
+        objects.fval.copy_from_slice(&self.object_fval);
+        objects.tval.copy_from_slice(&self.object_tval);
+        objects.size.copy_from_slice(&self.object_size);
+        objects.capacity.copy_from_slice(&self.object_capacity);
+        objects.room.copy_from_slice(&self.object_room);
+        objects.adventurer.copy_from_slice(&self.object_adventurer);
+        objects.container.copy_from_slice(&self.object_container);
+
+        rooms.value.copy_from_slice(&self.room_value);
+        rooms.flags.copy_from_slice(&self.room_flags);
+
+        adventurers.rooms.copy_from_slice(&self.adventurer_room);
+        adventurers.scores.copy_from_slice(&self.adventurer_score);
+        adventurers.vehicles.copy_from_slice(&self.adventurer_vehicle);
+        adventurers.strengths.copy_from_slice(&self.adventurer_strength);
+        adventurers.flags.copy_from_slice(&self.adventurer_flags);
+
+        for (flag, &value) in state.game_flags.flags.iter_mut().zip(&self.flags) {
+            *flag = value.into();
+        }
+        state.game_flags.switches.copy_from_slice(&self.switches);
+        state.villains.probability.copy_from_slice(&self.villain_probability);
+        for (flag, &value) in state.clock_events.flags.iter_mut().zip(&self.clock_event_flags) {
+            *flag = value.into();
+        }
+        state.clock_events.ticks.copy_from_slice(&self.clock_event_ticks);
+
+        state.last_it.last_it = self.last_it;
+        state.orphans.flag = self.orphans[0];
+        state.orphans.action = self.orphans[1];
+        state.orphans.slot = self.orphans[2];
+        state.orphans.preposition = self.orphans[3];
+        state.orphans.name = self.orphans[4];
+    }
+
+    /// Checks that the arrays are the size of the game's, so that
+    /// `apply_to()` can copy them.
+    pub fn check(&self) -> Result<(), SaveError> {
+        let lengths = [
+            ("puzzle", self.puzzle.len(), PUZZLE_SQUARES),
+            ("object_description_1", self.object_description_1.len(), OBJECTS),
+            ("object_description_2", self.object_description_2.len(), OBJECTS),
+            ("object_flags_1", self.object_flags_1.len(), OBJECTS),
+            ("object_flags_2", self.object_flags_2.len(), OBJECTS),
+            ("object_fval", self.object_fval.len(), OBJECTS),
+            ("object_tval", self.object_tval.len(), OBJECTS),
+            ("object_size", self.object_size.len(), OBJECTS),
+            ("object_capacity", self.object_capacity.len(), OBJECTS),
+            ("object_room", self.object_room.len(), OBJECTS),
+            ("object_adventurer", self.object_adventurer.len(), OBJECTS),
+            ("object_container", self.object_container.len(), OBJECTS),
+            ("room_value", self.room_value.len(), ROOMS),
+            ("room_flags", self.room_flags.len(), ROOMS),
+            ("adventurer_room", self.adventurer_room.len(), ADVENTURERS),
+            ("adventurer_score", self.adventurer_score.len(), ADVENTURERS),
+            ("adventurer_vehicle", self.adventurer_vehicle.len(), ADVENTURERS),
+            ("adventurer_strength", self.adventurer_strength.len(), ADVENTURERS),
+            ("adventurer_flags", self.adventurer_flags.len(), ADVENTURERS),
+            ("flags", self.flags.len(), FLAGS),
+            ("switches", self.switches.len(), SWITCHES),
+            ("villain_probability", self.villain_probability.len(), VILLAINS),
+            ("clock_event_flags", self.clock_event_flags.len(), CLOCK_EVENTS),
+            ("clock_event_ticks", self.clock_event_ticks.len(), CLOCK_EVENTS),
+        ];
+
+        for &(field, length, expected) in lengths.iter() {
+            if length != expected {
+                return Err(SaveError::Corrupt(format!("{} has {} entries instead of {}",
+                                                      field, length, expected)));
+            }
+        }
+        Ok(())
+    }
+}
+
@@ -0,0 +1,125 @@
+
+//! Reads the saved games written by the C version of the game.
+//!
+//! The old dsave.dat is a raw dump of native-endian integers, in the order
+//! savegm_() wrote them. Nothing writes this format any more; old saves are
+//! read so that they can be restored (and saved again in the new format).
+
+use std::io::{self, Read};
+use ffi;
+use super::SaveError;
+use super::fields::{Fields, ADVENTURERS, CLOCK_EVENTS, FLAGS, OBJECTS, PUZZLE_SQUARES, ROOMS,
+                    SWITCHES, VILLAINS};
+
+/// Reads a game in the format of the old dsave.dat. Like rstrgm_(), only the
+/// major and minor versions have to match this game's.
+pub fn read<R: Read>(input: &mut R) -> Result<Fields, SaveError> {
+    let mut reader = Reader { input };
+
+    // Older versions wrote other fields, so a file from one of them is
+    // reported as such rather than as damaged.
+    let version = (reader.int()?, reader.int()?, reader.int()?);
+    let current = unsafe { &ffi::version };
+    if version.0 != current.major || version.1 != current.minor {
+        return Err(SaveError::WrongVersion {
+            major: version.0,
+            minor: version.1,
+        });
+    }
+
+    let data = Fields {
+        version,
+
+        winner: reader.int()?,
+        here: reader.int()?,
+        thief_position: reader.int()?,
+        tel_flag: reader.logical()?,
+        thief_flag: reader.logical()?,
+        thief_active: reader.logical()?,
+        sword_active: reader.logical()?,
+        sword_state: reader.int()?,
+        puzzle: reader.ints(PUZZLE_SQUARES)?,
+
+        play_time: reader.int()?,
+        start_time: None,
+        moves: reader.int()?,
+        deaths: reader.int()?,
+        raw_score: reader.int()?,
+        endgame_score: reader.int()?,
+        max_load: reader.int()?,
+        light_shift: reader.int()?,
+        balloon_location: reader.int()?,
+        munged_room: reader.int()?,
+        hello_sailor: reader.int()?,
+        from_direction: reader.int()?,
+        scol_room: reader.int()?,
+        scol_active: reader.int()?,
+
+        object_description_1: reader.ints(OBJECTS)?,
+        object_description_2: reader.ints(OBJECTS)?,
+        object_flags_1: reader.ints(OBJECTS)?,
+        object_flags_2: reader.ints(OBJECTS)?,
+        object_fval: reader.ints(OBJECTS)?,
+        object_tval: reader.ints(OBJECTS)?,
+        object_size: reader.ints(OBJECTS)?,
+        object_capacity: reader.ints(OBJECTS)?,
+        object_room: reader.ints(OBJECTS)?,
+        object_adventurer: reader.ints(OBJECTS)?,
+        object_container: reader.ints(OBJECTS)?,
+
+        room_value: reader.ints(ROOMS)?,
+        room_flags: reader.ints(ROOMS)?,
+
+        adventurer_room: reader.ints(ADVENTURERS)?,
+        adventurer_score: reader.ints(ADVENTURERS)?,
+        adventurer_vehicle: reader.ints(ADVENTURERS)?,
+        adventurer_strength: reader.ints(ADVENTURERS)?,
+        adventurer_flags: reader.ints(ADVENTURERS)?,
+
+        flags: reader.logicals(FLAGS)?,
+        switches: reader.ints(SWITCHES)?,
+        villain_probability: reader.ints(VILLAINS)?,
+        clock_event_flags: reader.logicals(CLOCK_EVENTS)?,
+        clock_event_ticks: reader.ints(CLOCK_EVENTS)?,
+
+        // Not saved. The parser starts over, as it did after the old RESTORE.
+        last_it: 0,
+        orphans: Default::default(),
+    };
+
+    // Anything after the last field is junk.
+    if reader.input.read(&mut [0])? != 0 {
+        return Err(SaveError::Corrupt("the file is too long".to_string()));
+    }
+
+    Ok(data)
+}
+
+struct Reader<'a, R: 'a> {
+    input: &'a mut R,
+}
+
+impl<'a, R: Read> Reader<'a, R> {
+    fn int(&mut self) -> Result<i32, SaveError> {
+        let mut bytes = [0; 4];
+        match self.input.read_exact(&mut bytes) {
+            Ok(()) => Ok(i32::from_ne_bytes(bytes)),
+            Err(ref err) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                Err(SaveError::Corrupt("the file is too short".to_string()))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn ints(&mut self, count: usize) -> Result<Vec<i32>, SaveError> {
+        (0..count).map(|_| self.int()).collect()
+    }
+
+    fn logical(&mut self) -> Result<bool, SaveError> {
+        Ok(self.int()? != 0)
+    }
+
+    fn logicals(&mut self, count: usize) -> Result<Vec<bool>, SaveError> {
+        (0..count).map(|_| self.logical()).collect()
+    }
+}
@@ -0,0 +1,552 @@
+
+//! Saving and restoring games.
+//!
+//! A game can be saved to the default slot (SAVE, kept in dsave.dat as it
+//! always was) or to a named slot (SAVE name, kept in name.sav). Every slot is
+//! a file in the save directory; SAVES lists them.
+//!
+//! Saved games are JSON: a `SaveFile` holding the game, the hash of the text
+//! database it was played with (object descriptions are offsets into it), and
+//! a checksum of the game. Saves in older versions of the format, and from
+//! the C version, are still read; see `fields` and `legacy`.
+
+pub mod fields;
+pub mod legacy;
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use chrono::{DateTime, Local};
+use crc32fast;
+use libc::c_int;
+use serde::{Deserialize, Serialize};
+use serde_json::{self, Value};
+use ffi::{self, Logical};
+use files;
+use output;
+use replacement::{dso, dsub, supp};
+use state::GameState;
+use textdb;
+use self::fields::Fields;
+
+/// Identifies a saved game file.
+pub const FORMAT_NAME: &str = "zork-save";
+
+/// The version of the save format. Bump it when `SaveData` changes in a way
+/// that older versions of the game can't read. Version 2 stopped writing
+/// `start_time`, and version 3 holds the whole `GameState` instead of the
+/// fields in `fields::Fields`.
+pub const FORMAT_VERSION: u32 = 3;
+
+/// The file extension of named slots.
+pub const SLOT_EXTENSION: &str = "sav";
+
+// Slot names are kept short, and safe to use as file names.
+const MAX_NAME_LENGTH: usize = 32;
+
+// Messages from the text database.
+const SAVED: c_int = 597;
+const CANT_DO_IT: c_int = 598;
+const RESTORED: c_int = 599;
+const OBSOLETE_VERSION: c_int = 600;
+const NO_SAVES_IN_ENDGAME: c_int = 828;
+const NO_RESTORES_IN_ENDGAME: c_int = 829;
+
+// The room whose RSEEN flag marks the start of the endgame (rindex_.tstrs).
+const ENDGAME_ROOM: usize = 158;
+
+// The slot named by the SAVE or RESTORE on the line being run, for savegm_()
+// and rstrgm_().
+static SLOT: Mutex<Option<String>> = Mutex::new(None);
+
+/// Why a game couldn't be saved or restored.
+#[derive(Debug)]
+pub enum SaveError {
+    /// The slot name can't be used as a file name.
+    BadName(String),
+
+    /// There is no saved game in the slot.
+    NotFound(String),
+
+    /// The file isn't a saved game, or was cut short.
+    Corrupt(String),
+
+    /// The file was written by a newer version of the game, in a format this
+    /// one doesn't know.
+    NewerFormat(u32),
+
+    /// The game was played with a different text database.
+    WrongData,
+
+    /// The game was saved by another version of the game.
+    WrongVersion { major: i32, minor: i32 },
+
+    /// Games can't be saved or restored in the endgame.
+    Endgame,
+
+    /// Reading or writing the file failed.
+    Io(io::Error),
+}
+
+/// A summary of a saved game, for SAVES.
+#[derive(Clone, Debug)]
+pub struct SaveInfo {
+    /// The slot name, or `None` for the default slot.
+    pub name: Option<String>,
+
+    /// When the game was saved.
+    pub saved_at: DateTime<Local>,
+
+    /// The player's score, or `None` if the file is damaged.
+    pub score: Option<i32>,
+
+    /// The room the player was in. 0 if the file is damaged.
+    pub room: usize,
+}
+
+/// A saved game file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SaveFile {
+    /// Always `FORMAT_NAME`.
+    pub format: String,
+
+    /// The format version, `FORMAT_VERSION` when written by this version.
+    pub format_version: u32,
+
+    /// The CRC-32 of the text database the game was played with.
+    pub data_hash: u32,
+
+    /// The CRC-32 of `game`, serialized as JSON.
+    pub checksum: u32,
+
+    pub game: SaveData,
+}
+
+// A saved game file in versions 1 and 2 of the format.
+#[derive(Deserialize)]
+struct OldSaveFile {
+    format: String,
+    data_hash: u32,
+    checksum: u32,
+    game: Fields,
+}
+
+/// Everything a saved game contains: the version of the game that saved it,
+/// and the game's state.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveData {
+    pub version: (i32, i32, i32),
+
+    /// The game's state. Its play_time is the total time played, as gttime_()
+    /// gives it.
+    pub state: GameState,
+}
+
+/// Handles SAVE with a slot name. Returns the path saved to.
+pub fn save(name: Option<&str>) -> Result<PathBuf, SaveError> {
+    if in_endgame() {
+        return Err(SaveError::Endgame);
+    }
+
+    let path = slot_path(name)?;
+    debug!("Saving to {:?}", path);
+    let save_file = SaveFile::new(SaveData::capture())?;
+    let mut file = BufWriter::new(File::create(&path)?);
+    save_file.write(&mut file)?;
+    file.flush()?;
+    Ok(path)
+}
+
+/// Handles RESTORE with a slot name.
+pub fn restore(name: Option<&str>) -> Result<(), SaveError> {
+    if in_endgame() {
+        return Err(SaveError::Endgame);
+    }
+
+    let path = slot_path(name)?;
+    debug!("Restoring from {:?}", path);
+    read_slot(&path, name)?.apply();
+    Ok(())
+}
+
+/// Converts a dsave.dat written by the C version of the game into a slot.
+/// Returns the path saved to.
+pub fn import_legacy<P: AsRef<Path>>(legacy: P, name: Option<&str>) -> Result<PathBuf, SaveError> {
+    let legacy = legacy.as_ref();
+    let fields = legacy::read(&mut &open(legacy, &legacy.display().to_string())?[..])?;
+    let data = SaveData::from_fields(&fields)?;
+
+    let path = slot_path(name)?;
+    info!("Importing {:?} to {:?}", legacy, path);
+    let save_file = SaveFile::new(data)?;
+    let mut file = BufWriter::new(File::create(&path)?);
+    save_file.write(&mut file)?;
+    file.flush()?;
+    Ok(path)
+}
+
+/// Lists the saved games in the save directory, oldest first.
+pub fn list() -> io::Result<Vec<SaveInfo>> {
+    let mut saves = Vec::new();
+    for entry in fs::read_dir(files::save_dir())? {
+        let path = entry?.path();
+        let name = match slot_name(&path) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        let saved_at = fs::metadata(&path)?.modified()?.into();
+        let (score, room) = match read_slot(&path, name.as_deref()) {
+            Ok(data) => {
+                let state = &data.state;
+                (Some(state.adventurers.scores[0]), state.player.current_room as usize)
+            }
+            Err(err) => {
+                warn!("Unable to read {:?}: {}", path, err);
+                (None, 0)
+            }
+        };
+
+        saves.push(SaveInfo {
+            name,
+            saved_at,
+            score,
+            room,
+        });
+    }
+    saves.sort_by_key(|save| save.saved_at);
+    Ok(saves)
+}
+
+/// Runs SAVE, printing the result.
+pub fn save_command(name: Option<&str>) {
+    match save(name) {
+        Ok(_) => match name {
+            Some(name) => {
+                output::write(&format!("Saved as \"{}\".\n", name.to_ascii_lowercase()));
+                // As rspeak_() does, so that the move doesn't end with "Nothing
+                // happens."
+                unsafe {
+                    ffi::player.tel_flag = Logical::TRUE;
+                }
+            }
+            None => rspeak(SAVED),
+        },
+        Err(SaveError::Endgame) => rspeak(NO_SAVES_IN_ENDGAME),
+        Err(err) => {
+            rspeak(CANT_DO_IT);
+            output::write(&format!("{}\n", err));
+        }
+    }
+}
+
+/// Runs RESTORE, printing the result.
+pub fn restore_command(name: Option<&str>) {
+    match restore(name) {
+        Ok(()) => rspeak(RESTORED),
+        Err(SaveError::Endgame) => rspeak(NO_RESTORES_IN_ENDGAME),
+        Err(SaveError::WrongVersion { .. }) => rspeak(OBSOLETE_VERSION),
+        Err(err) => {
+            rspeak(CANT_DO_IT);
+            output::write(&format!("{}\n", err));
+        }
+    }
+}
+
+/// Runs SAVES, printing the list of saved games.
+pub fn list_command() {
+    let saves = match list() {
+        Ok(saves) => saves,
+        Err(err) => {
+            output::write(&format!("Unable to read the save directory: {}\n", err));
+            return;
+        }
+    };
+
+    if saves.is_empty() {
+        output::write("There are no saved games.\n");
+        return;
+    }
+
+    for save in saves {
+        let name = save.name.unwrap_or_else(|| "(default)".to_string());
+        let saved_at = save.saved_at.format("%Y-%m-%d %H:%M");
+        match save.score {
+            Some(score) => {
+                output::write(&format!("{:<20} {}  Score: {:<4} ", name, saved_at, score));
+                let rooms = unsafe { &ffi::rooms };
+                if save.room >= 1 && save.room <= rooms.len() {
+                    rspeak(rooms.description_2[save.room - 1]);
+                } else {
+                    output::write("\n");
+                }
+            }
+            None => output::write(&format!("{:<20} {}  (damaged)\n", name, saved_at)),
+        }
+    }
+}
+
+/// Handles the save commands that the parser doesn't know about, before it
+/// sees the line. `command` is an uppercase line of input.
+///
+/// SAVES is run here, and takes no time. SAVE and RESTORE with a slot name
+/// are turned into plain SAVE and RESTORE, so that they go through the parser
+/// to savegm_() and rstrgm_() (and take a move) like the C commands do; the
+/// slot is kept for them, see `take_slot()`.
+///
+/// Returns the line to hand to the parser, or `None` if it has been handled.
+pub fn handle_command(command: &str) -> Option<String> {
+    *lock_slot() = None;
+    let words: Vec<&str> = command.split_whitespace().collect();
+    match words.as_slice() {
+        ["SAVES"] => {
+            list_command();
+            None
+        }
+        [verb @ "SAVE", name] | [verb @ "RESTORE", name] => {
+            *lock_slot() = Some(name.to_string());
+            Some(verb.to_string())
+        }
+        _ => Some(command.to_string()),
+    }
+}
+
+/// The slot named by the SAVE or RESTORE being run, or `None` for the
+/// default slot. The slot is only given out once.
+pub fn take_slot() -> Option<String> {
+    lock_slot().take()
+}
+
+fn lock_slot() -> MutexGuard<'static, Option<String>> {
+    SLOT.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+// The file for a slot. `None` is the default slot, dsave.dat.
+fn slot_path(name: Option<&str>) -> Result<PathBuf, SaveError> {
+    let name = match name {
+        Some(name) => name,
+        None => return Ok(files::save_file()),
+    };
+
+    let valid = !name.is_empty() && name.len() <= MAX_NAME_LENGTH &&
+        name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(SaveError::BadName(name.to_ascii_lowercase()));
+    }
+
+    let file_name = format!("{}.{}", name.to_ascii_lowercase(), SLOT_EXTENSION);
+    Ok(files::save_dir().join(file_name))
+}
+
+// The slot a file in the save directory belongs to. Returns `Some(None)` for
+// the default slot, and `None` for files that aren't saved games.
+fn slot_name(path: &Path) -> Option<Option<String>> {
+    let file_name = path.file_name()?.to_str()?;
+    if file_name == files::SAVE_FILE {
+        return Some(None);
+    }
+
+    let name = file_name.strip_suffix(SLOT_EXTENSION)?.strip_suffix('.')?;
+    if name.is_empty() {
+        None
+    } else {
+        Some(Some(name.to_string()))
+    }
+}
+
+// Reads a saved game in either format.
+fn read_slot(path: &Path, name: Option<&str>) -> Result<SaveData, SaveError> {
+    let name = name.unwrap_or("(default)").to_ascii_lowercase();
+    let contents = open(path, &name)?;
+    if contents.first() == Some(&b'{') {
+        SaveFile::read(&mut &contents[..])?.into_game()
+    } else {
+        SaveData::from_fields(&legacy::read(&mut &contents[..])?)
+    }
+}
+
+// Reads a whole file. `name` is what to call it if it doesn't exist.
+fn open(path: &Path, name: &str) -> Result<Vec<u8>, SaveError> {
+    match fs::read(path) {
+        Ok(contents) => Ok(contents),
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => {
+            Err(SaveError::NotFound(name.to_string()))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn in_endgame() -> bool {
+    unsafe { ffi::rooms.get(ENDGAME_ROOM).is_seen() }
+}
+
+fn rspeak(message: c_int) {
+    dsub::rspeak_(message)
+}
+
+impl SaveData {
+    /// Copies the state of the current game.
+    pub fn capture() -> SaveData {
+        let mut state = GameState::capture();
+        dso::gttime_(&mut state.play_time.play_time);
+        let version = unsafe { (ffi::version.major, ffi::version.minor, ffi::version.edit) };
+        SaveData { version, state }
+    }
+
+    /// Replaces the state of the current game with this one. The time played
+    /// is counted from now, as if the game had been started now.
+    ///
+    /// The command being run and the line it came from (prsvec_, pv_,
+    /// syntax_, curxt_ and input_) aren't the saved game's, and are left as
+    /// they are, as are the tables read from the text database (rmsg_ and
+    /// exits_) and GDT's debug_.
+    ///
+    /// rstrgm_() in C left the start time alone, so the time played in this
+    /// session before the restore was counted again on top of the saved
+    /// game's.
+    pub fn apply(&self) {
+        let current = GameState::capture();
+        let state = GameState {
+            parse_vec: current.parse_vec,
+            parsed_words: current.parsed_words,
+            syntax_vec: current.syntax_vec,
+            current_exit: current.current_exit,
+            input_buffer: current.input_buffer,
+            messages: current.messages,
+            exits: current.exits,
+            debug_flags: current.debug_flags,
+            ..self.state.clone()
+        };
+        state.restore();
+        unsafe {
+            supp::itime_(
+                &mut ffi::play_time.start_hour,
+                &mut ffi::play_time.start_minute,
+                &mut ffi::play_time.start_second,
+            );
+        }
+    }
+
+    // A game in the old format, put onto the state of the current game.
+    fn from_fields(fields: &Fields) -> Result<SaveData, SaveError> {
+        fields.check()?;
+        let mut state = GameState::capture();
+        fields.apply_to(&mut state);
+        Ok(SaveData { version: fields.version, state })
+    }
+}
+
+impl SaveFile {
+    /// Wraps a game for saving with the text database in use.
+    pub fn new(game: SaveData) -> Result<SaveFile, SaveError> {
+        Ok(SaveFile {
+            format: FORMAT_NAME.to_string(),
+            format_version: FORMAT_VERSION,
+            data_hash: textdb::data_hash()?,
+            checksum: checksum(&game),
+            game,
+        })
+    }
+
+    pub fn write<W: Write>(&self, out: &mut W) -> Result<(), SaveError> {
+        serde_json::to_writer(&mut *out, self).map_err(io::Error::from)?;
+        out.write_all(b"\n")?;
+        Ok(())
+    }
+
+    /// Reads a saved game file. The game in it isn't checked; see
+    /// `into_game()`.
+    ///
+    /// Files in versions 1 and 2 of the format are checked, and their games
+    /// put onto the state of the current game, as version 3 files.
+    pub fn read<R: Read>(input: &mut R) -> Result<SaveFile, SaveError> {
+        let value: Value = serde_json::from_reader(input).map_err(json_error)?;
+        let version = value.get("format_version").and_then(Value::as_u64).unwrap_or(0);
+        if version >= 3 {
+            return serde_json::from_value(value).map_err(json_error);
+        }
+
+        let old: OldSaveFile = serde_json::from_value(value).map_err(json_error)?;
+        if old.checksum != checksum(&old.game) {
+            return Err(SaveError::Corrupt("the checksum doesn't match".to_string()));
+        }
+        let game = SaveData::from_fields(&old.game)?;
+        Ok(SaveFile {
+            format: old.format,
+            format_version: FORMAT_VERSION,
+            data_hash: old.data_hash,
+            checksum: checksum(&game),
+            game,
+        })
+    }
+
+    /// Checks that the game can be restored by this version of the game, with
+    /// the text database in use, and returns it.
+    pub fn into_game(self) -> Result<SaveData, SaveError> {
+        if self.format != FORMAT_NAME {
+            return Err(SaveError::Corrupt("this isn't a saved game".to_string()));
+        }
+        if self.format_version > FORMAT_VERSION {
+            return Err(SaveError::NewerFormat(self.format_version));
+        }
+        if self.checksum != checksum(&self.game) {
+            return Err(SaveError::Corrupt("the checksum doesn't match".to_string()));
+        }
+
+        let current = unsafe { &ffi::version };
+        if self.game.version.0 != current.major || self.game.version.1 != current.minor {
+            return Err(SaveError::WrongVersion {
+                major: self.game.version.0,
+                minor: self.game.version.1,
+            });
+        }
+        if self.data_hash != textdb::data_hash()? {
+            return Err(SaveError::WrongData);
+        }
+
+        Ok(self.game)
+    }
+}
+
+// The CRC-32 of a game, serialized as JSON.
+fn checksum<T: Serialize>(game: &T) -> u32 {
+    // Serializing plain integers and arrays can't fail.
+    let json = serde_json::to_vec(game).expect("Unable to serialize the game");
+    crc32fast::hash(&json)
+}
+
+// A JSON error as a SaveError.
+fn json_error(err: serde_json::Error) -> SaveError {
+    if err.is_io() {
+        SaveError::Io(err.into())
+    } else {
+        SaveError::Corrupt(err.to_string())
+    }
+}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> SaveError {
+        SaveError::Io(err)
+    }
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SaveError::BadName(ref name) => write!(f, "\"{}\" can't be used as the name of a saved \
+                game. Use up to {} letters, digits, '-' and '_'.", name, MAX_NAME_LENGTH),
+            SaveError::NotFound(ref name) => write!(f, "There is no saved game called \"{}\".", name),
+            SaveError::Corrupt(ref reason) => write!(f, "The saved game is damaged: {}.", reason),
+            SaveError::WrongVersion { major, minor } => {
+                write!(f, "The game was saved by version {}.{} of Dungeon.", major, minor)
+            }
+            SaveError::NewerFormat(version) => write!(f, "The game was saved in version {} of \
+                the save format, which this version of Dungeon can't read.", version),
+            SaveError::WrongData => write!(f, "The game was played with a different text database."),
+            SaveError::Endgame => write!(f, "Games can't be saved or restored in the endgame."),
+            SaveError::Io(ref err) => write!(f, "{}.", err),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+
+//! The whole state of a game.
+//!
+//! `GameState` holds a copy of every mutable common block in vars.h and
+//! parse.h. It can be compared, serialized, and put back, which is what
+//! saved games, UNDO, test fixtures and differential tests are built on.
+
+use ffi::{self, Adventurers, ClockEvents, Counters, CurrentExit, DebugFlags, Exits, Flags, Hack,
+          InputBuffer, LastIt, Messages, MultiRoomObjects, Orphans, ParseVec, ParsedWords, PlayTime,
+          Player, Puzzle, Screen, SyntaxVec, Villains};
+use ffi::objects::{GlobalObjects, Objects};
+use ffi::rooms::Rooms;
+use serde::{Deserialize, Serialize};
+
+/// A copy of every mutable block of game globals. Fields are named after the
+/// `ffi` aliases of the blocks; the C names are in the comments.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameState {
+    // prsvec_
+    pub parse_vec: ParseVec,
+    // orphs_
+    pub orphans: Orphans,
+    // last_
+    pub last_it: LastIt,
+    // play_
+    pub player: Player,
+    // rooms_
+    pub rooms: Rooms,
+    // objcts_
+    pub objects: Objects,
+    // oroom2_
+    pub multi_room_objects: MultiRoomObjects,
+    // cevent_
+    pub clock_events: ClockEvents,
+    // advs_
+    pub adventurers: Adventurers,
+    // findex_
+    pub game_flags: Flags,
+    // debug_
+    pub debug_flags: DebugFlags,
+    // hack_
+    pub hack: Hack,
+    // vill_
+    pub villains: Villains,
+    // state_
+    pub game_state: Counters,
+    // curxt_
+    pub current_exit: CurrentExit,
+    // star_
+    pub global_items: GlobalObjects,
+    // input_
+    pub input_buffer: InputBuffer,
+    // screen_
+    pub screen: Screen,
+    // rmsg_
+    pub messages: Messages,
+    // time_
+    pub play_time: PlayTime,
+    // exits_
+    pub exits: Exits,
+    // puzzle_
+    pub puzzle: Puzzle,
+    // pv_
+    pub parsed_words: ParsedWords,
+    // syntax_
+    pub syntax_vec: SyntaxVec,
+}
+
+impl GameState {
+    /// Copies the state of the current game.
+    pub fn capture() -> GameState {
+        unsafe {
+            GameState {
+                parse_vec: ffi::parse_vec.clone(),
+                orphans: ffi::orphans.clone(),
+                last_it: ffi::last_it.clone(),
+                player: ffi::player.clone(),
+                rooms: ffi::rooms.clone(),
+                objects: ffi::objects.clone(),
+                multi_room_objects: ffi::multi_room_objects.clone(),
+                clock_events: ffi::clock_events.clone(),
+                adventurers: ffi::adventurers.clone(),
+                game_flags: ffi::game_flags.clone(),
+                debug_flags: ffi::debug_flags.clone(),
+                hack: ffi::hack.clone(),
+                villains: ffi::villains.clone(),
+                game_state: ffi::game_state.clone(),
+                current_exit: ffi::current_exit.clone(),
+                global_items: ffi::global_items.clone(),
+                input_buffer: ffi::input_buffer.clone(),
+                screen: ffi::screen.clone(),
+                messages: ffi::messages.clone(),
+                play_time: ffi::play_time.clone(),
+                exits: ffi::exits.clone(),
+                puzzle: ffi::puzzle.clone(),
+                parsed_words: ffi::parsed_words.clone(),
+                syntax_vec: ffi::syntax_vec.clone(),
+            }
+        }
+    }
+
+    /// Replaces the state of the current game with this one.
+    ///
+    /// The constant tables inside screen_ and puzzle_ (scoldr, scolwl, cpdr
+    /// and cpwl) are left alone.
+    pub fn restore(&self) {
+        unsafe {
+            ffi::parse_vec = self.parse_vec.clone();
+            ffi::orphans = self.orphans.clone();
+            ffi::last_it = self.last_it.clone();
+            ffi::player = self.player.clone();
+            ffi::rooms = self.rooms.clone();
+            ffi::objects = self.objects.clone();
+            ffi::multi_room_objects = self.multi_room_objects.clone();
+            ffi::clock_events = self.clock_events.clone();
+            ffi::adventurers = self.adventurers.clone();
+            ffi::game_flags = self.game_flags.clone();
+            ffi::debug_flags = self.debug_flags.clone();
+            ffi::hack = self.hack.clone();
+            ffi::villains = self.villains.clone();
+            ffi::game_state = self.game_state.clone();
+            ffi::current_exit = self.current_exit.clone();
+            ffi::global_items = self.global_items.clone();
+            ffi::input_buffer = self.input_buffer.clone();
+            ffi::messages = self.messages.clone();
+            ffi::play_time = self.play_time.clone();
+            ffi::exits = self.exits.clone();
+            ffi::parsed_words = self.parsed_words.clone();
+            ffi::syntax_vec = self.syntax_vec.clone();
+
+            ffi::screen.from_direction = self.screen.from_direction;
+            ffi::screen.scol_room = self.screen.scol_room;
+            ffi::screen.scol_active = self.screen.scol_active;
+            ffi::puzzle.squares = self.puzzle.squares;
+        }
+    }
+}
@@ -0,0 +1,322 @@
+
+//! Logs how the game's state changes from turn to turn.
+//!
+//! At the start of every turn, the state is compared with the state at the
+//! start of the last one, and the changes are logged at trace level to the
+//! `state_diff` target, using the C names of fields:
+//!
+//! ```text
+//! Turn 5:
+//!   object 15 (LAMP) oroom 3 -> 0, oadv 0 -> 1
+//!   object 53 oflag2 (empty) -> OPENBT
+//!   findex.trollf false -> true
+//! ```
+//!
+//! Comparing two of these logs shows the first turn where two versions of the
+//! game (say, before and after porting a function to Rust) differ.
+
+use std::fmt::Display;
+use std::sync::{Mutex, MutexGuard};
+use libc::{c_char, c_int};
+use log::Level;
+use ffi::Logical;
+use ffi::objects::{ObjectFlags1, ObjectFlags2};
+use ffi::rooms::RoomFlags;
+use names;
+use state::GameState;
+
+/// The log target the changes are logged to.
+pub const LOG_TARGET: &str = "state_diff";
+
+lazy_static! {
+    // The state at the start of the last turn.
+    static ref LAST_STATE: Mutex<Option<GameState>> = Mutex::new(None);
+}
+
+/// Logs the changes since the last turn. Does nothing unless the log target is
+/// enabled.
+pub fn log_turn() {
+    if !log_enabled!(target: LOG_TARGET, Level::Trace) {
+        return;
+    }
+
+    let state = GameState::capture();
+    let mut last = lock();
+    if let Some(ref last) = *last {
+        let changes = diff(last, &state);
+        if !changes.is_empty() {
+            trace!(target: LOG_TARGET, "Turn {}:\n  {}", state.game_state.moves, changes.join("\n  "));
+        }
+    }
+    *last = Some(state);
+}
+
+/// Forgets the last turn. Done when a new game starts.
+pub fn reset() {
+    *lock() = None;
+}
+
+/// Describes every difference between two states, one line per object, room,
+/// field, etc.
+pub fn diff(old: &GameState, new: &GameState) -> Vec<String> {
+    let mut changes = Changes::default();
+
+    let (o, n) = (&old.objects, &new.objects);
+    changes.table("object", names::object, &[
+        ("odesc1", &o.description_1, &n.description_1),
+        ("odesc2", &o.description_2, &n.description_2),
+        ("odesco", &o.desco, &n.desco),
+        ("oactio", &o.action, &n.action),
+        ("oflag1", &o.flags_1, &n.flags_1),
+        ("oflag2", &o.flags_2, &n.flags_2),
+        ("ofval", &o.fval, &n.fval),
+        ("otval", &o.tval, &n.tval),
+        ("osize", &o.size, &n.size),
+        ("ocapac", &o.capacity, &n.capacity),
+        ("oroom", &o.room, &n.room),
+        ("oadv", &o.adventurer, &n.adventurer),
+        ("ocan", &o.container, &n.container),
+        ("oread", &o.read, &n.read),
+    ]);
+
+    let (o, n) = (&old.rooms, &new.rooms);
+    changes.table("room", names::room, &[
+        ("rdesc1", &o.description_1, &n.description_1),
+        ("rdesc2", &o.description_2, &n.description_2),
+        ("rexit", &o.exits, &n.exits),
+        ("ractio", &o.action, &n.action),
+        ("rval", &o.value, &n.value),
+        ("rflag", &o.flags, &n.flags),
+    ]);
+
+    let (o, n) = (&old.adventurers, &new.adventurers);
+    changes.table("adventurer", names::adventurer, &[
+        ("aroom", &o.rooms, &n.rooms),
+        ("ascore", &o.scores, &n.scores),
+        ("avehic", &o.vehicles, &n.vehicles),
+        ("aobj", &o.current_its, &n.current_its),
+        ("aactio", &o.actions, &n.actions),
+        ("astren", &o.strengths, &n.strengths),
+        ("aflag", &o.flags, &n.flags),
+    ]);
+
+    let (o, n) = (&old.clock_events, &new.clock_events);
+    changes.table("clock event", names::clock_event, &[
+        ("ctick", &o.ticks, &n.ticks),
+        ("cactio", &o.actions, &n.actions),
+    ]);
+    changes.logical_table("clock event", names::clock_event, "cflag", &o.flags, &n.flags);
+
+    let (o, n) = (&old.villains, &new.villains);
+    changes.table("villain", |_| None, &[
+        ("villns", &o.villains, &n.villains),
+        ("vprob", &o.probability, &n.probability),
+        ("vopps", &o.opponents, &n.opponents),
+        ("vbest", &o.best_weapon, &n.best_weapon),
+        ("vmelee", &o.melee, &n.melee),
+    ]);
+
+    let (o, n) = (&old.multi_room_objects, &new.multi_room_objects);
+    changes.table("oroom2 entry", |_| None, &[
+        ("oroom2", &o.objects, &n.objects),
+        ("rroom2", &o.rooms, &n.rooms),
+    ]);
+
+    let (o, n) = (&old.game_flags, &new.game_flags);
+    for (i, name) in names::FLAGS.iter().enumerate() {
+        changes.logical(&format!("findex.{}", name), o.flags[i], n.flags[i]);
+    }
+    for (i, name) in names::SWITCHES.iter().enumerate() {
+        changes.field(&format!("findex.{}", name), o.switches[i], n.switches[i]);
+    }
+
+    let (o, n) = (&old.game_state, &new.game_state);
+    changes.field("state.moves", o.moves, n.moves);
+    changes.field("state.deaths", o.deaths, n.deaths);
+    changes.field("state.rwscor", o.raw_score, n.raw_score);
+    changes.field("state.mxscor", o.max_score, n.max_score);
+    changes.field("state.mxload", o.max_load, n.max_load);
+    changes.field("state.ltshft", o.light_shift, n.light_shift);
+    changes.field("state.bloc", o.balloon_location, n.balloon_location);
+    changes.field("state.mungrm", o.munged_room, n.munged_room);
+    changes.field("state.hs", o.hello_sailor, n.hello_sailor);
+    changes.field("state.egscor", o.endgame_score, n.endgame_score);
+    changes.field("state.egmxsc", o.endgame_max_score, n.endgame_max_score);
+
+    let (o, n) = (&old.player, &new.player);
+    changes.field("play.winner", o.winner, n.winner);
+    changes.field("play.here", o.current_room, n.current_room);
+    changes.logical("play.telflg", o.tel_flag, n.tel_flag);
+
+    let (o, n) = (&old.hack, &new.hack);
+    changes.field("hack.thfpos", o.thief_position, n.thief_position);
+    changes.logical("hack.thfflg", o.thief_flag, n.thief_flag);
+    changes.logical("hack.thfact", o.thief_active, n.thief_active);
+    changes.logical("hack.swdact", o.sword_active, n.sword_active);
+    changes.field("hack.swdsta", o.sword_state, n.sword_state);
+
+    changes.field("last.lastit", old.last_it.last_it, new.last_it.last_it);
+
+    let (o, n) = (&old.orphans, &new.orphans);
+    changes.field("orphs.oflag", o.flag, n.flag);
+    changes.field("orphs.oact", o.action, n.action);
+    changes.field("orphs.oslot", o.slot, n.slot);
+    changes.field("orphs.oprep", o.preposition, n.preposition);
+    changes.field("orphs.oname", o.name, n.name);
+
+    let (o, n) = (&old.screen, &new.screen);
+    changes.field("screen.fromdr", o.from_direction, n.from_direction);
+    changes.field("screen.scolrm", o.scol_room, n.scol_room);
+    changes.field("screen.scolac", o.scol_active, n.scol_active);
+    changes.array("puzzle.cpvec", &old.puzzle.squares, &new.puzzle.squares);
+
+    let (o, n) = (&old.play_time, &new.play_time);
+    changes.field("time.pltime", o.play_time, n.play_time);
+    changes.field("time.shour", o.start_hour, n.start_hour);
+    changes.field("time.smin", o.start_minute, n.start_minute);
+    changes.field("time.ssec", o.start_second, n.start_second);
+
+    let (o, n) = (&old.debug_flags, &new.debug_flags);
+    changes.field("debug.dbgflg", o.debug_flag, n.debug_flag);
+    changes.field("debug.prsflg", o.parse_flag, n.parse_flag);
+    changes.field("debug.gdtflg", o.gdt_flag, n.gdt_flag);
+
+    changes.field("star.mbase", old.global_items.mbase, new.global_items.mbase);
+    changes.field("rmsg.mrloc", old.messages.location, new.messages.location);
+    changes.array("rmsg.rtext", &old.messages.text, &new.messages.text);
+    changes.array("exits.travel", &old.exits.travel, &new.exits.travel);
+
+    // The parser's working state.
+    let (o, n) = (&old.parse_vec, &new.parse_vec);
+    changes.field("prsvec.prsa", o.parse_a, n.parse_a);
+    changes.field("prsvec.prsi", o.parse_i, n.parse_i);
+    changes.field("prsvec.prso", o.parse_o, n.parse_o);
+    changes.logical("prsvec.prswon", o.parse_won, n.parse_won);
+    changes.field("prsvec.prscon", o.parse_continue, n.parse_continue);
+
+    let (o, n) = (&old.parsed_words, &new.parsed_words);
+    changes.field("pv.act", o.action, n.action);
+    changes.field("pv.o1", o.object_1, n.object_1);
+    changes.field("pv.o2", o.object_2, n.object_2);
+    changes.field("pv.p1", o.preposition_1, n.preposition_1);
+    changes.field("pv.p2", o.preposition_2, n.preposition_2);
+
+    let (o, n) = (&old.syntax_vec, &new.syntax_vec);
+    changes.field("syntax.vflag", o.verb_flags, n.verb_flags);
+    changes.field("syntax.dobj", o.direct_object, n.direct_object);
+    changes.field("syntax.dfl1", show("oflag1", o.direct_flags_1),
+                  show("oflag1", n.direct_flags_1));
+    changes.field("syntax.dfl2", show("oflag2", o.direct_flags_2),
+                  show("oflag2", n.direct_flags_2));
+    changes.field("syntax.dfw1", o.direct_fwim_1, n.direct_fwim_1);
+    changes.field("syntax.dfw2", o.direct_fwim_2, n.direct_fwim_2);
+    changes.field("syntax.iobj", o.indirect_object, n.indirect_object);
+    changes.field("syntax.ifl1", show("oflag1", o.indirect_flags_1),
+                  show("oflag1", n.indirect_flags_1));
+    changes.field("syntax.ifl2", show("oflag2", o.indirect_flags_2),
+                  show("oflag2", n.indirect_flags_2));
+    changes.field("syntax.ifw1", o.indirect_fwim_1, n.indirect_fwim_1);
+    changes.field("syntax.ifw2", o.indirect_fwim_2, n.indirect_fwim_2);
+
+    let (o, n) = (&old.current_exit, &new.current_exit);
+    changes.field("curxt.xtype", o.kind, n.kind);
+    changes.field("curxt.xroom1", o.room, n.room);
+    changes.field("curxt.xstrng", o.message, n.message);
+    changes.field("curxt.xactio", o.action, n.action);
+    changes.field("curxt.xobj", o.object, n.object);
+
+    changes.field("input.inbuf", format!("{:?}", input_text(&old.input_buffer.buffer)),
+                  format!("{:?}", input_text(&new.input_buffer.buffer)));
+
+    changes.lines
+}
+
+fn lock() -> MutexGuard<'static, Option<GameState>> {
+    LAST_STATE.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+// Shows the value of a field of objcts_ or rooms_. Flag words are shown as the
+// names of their flags.
+fn show(field: &str, value: c_int) -> String {
+    match field {
+        "oflag1" => format!("{:?}", ObjectFlags1::from_bits_retain(value)),
+        "oflag2" => format!("{:?}", ObjectFlags2::from_bits_retain(value)),
+        "rflag" => format!("{:?}", RoomFlags::from_bits_retain(value)),
+        _ => value.to_string(),
+    }
+}
+
+// The text in input_.inbuf, up to the NUL.
+fn input_text(buffer: &[c_char]) -> String {
+    buffer.iter().take_while(|&&c| c != 0).map(|&c| c as u8 as char).collect()
+}
+
+#[derive(Default)]
+struct Changes {
+    lines: Vec<String>,
+}
+
+impl Changes {
+    fn field<T: PartialEq + Display>(&mut self, name: &str, old: T, new: T) {
+        if old != new {
+            self.lines.push(format!("{} {} -> {}", name, old, new));
+        }
+    }
+
+    fn logical(&mut self, name: &str, old: Logical, new: Logical) {
+        self.field(name, old.is_true(), new.is_true());
+    }
+
+    // An array where each entry is something different; the changed entries
+    // are listed on one line.
+    fn array(&mut self, name: &str, old: &[c_int], new: &[c_int]) {
+        let entries: Vec<String> = old.iter().zip(new).enumerate()
+            .filter(|&(_, (old, new))| old != new)
+            .map(|(i, (old, new))| format!("[{}] {} -> {}", i + 1, old, new))
+            .collect();
+        if !entries.is_empty() {
+            self.lines.push(format!("{} {}", name, entries.join(", ")));
+        }
+    }
+
+    // Parallel arrays, like objcts_. Changes are listed per entry: "object 15
+    // (LAMP) oroom 3 -> 0, oadv 0 -> 1".
+    fn table<F>(&mut self, kind: &str, name: F, fields: &[(&str, &[c_int], &[c_int])])
+        where F: Fn(c_int) -> Option<&'static str>
+    {
+        let length = fields.iter().map(|&(_, old, _)| old.len()).max().unwrap_or(0);
+        for i in 0..length {
+            let changes: Vec<String> = fields.iter()
+                .filter(|&&(_, old, new)| old[i] != new[i])
+                .map(|&(field, old, new)| {
+                    format!("{} {} -> {}", field, show(field, old[i]), show(field, new[i]))
+                })
+                .collect();
+            if !changes.is_empty() {
+                self.entry(kind, &name, i, &changes);
+            }
+        }
+    }
+
+    fn logical_table<F>(&mut self, kind: &str, name: F, field: &str, old: &[Logical], new: &[Logical])
+        where F: Fn(c_int) -> Option<&'static str>
+    {
+        for (i, (old, new)) in old.iter().zip(new).enumerate() {
+            if old != new {
+                let change = format!("{} {} -> {}", field, old.is_true(), new.is_true());
+                self.entry(kind, &name, i, &[change]);
+            }
+        }
+    }
+
+    fn entry<F>(&mut self, kind: &str, name: &F, i: usize, changes: &[String])
+        where F: Fn(c_int) -> Option<&'static str>
+    {
+        let index = i as c_int + 1;
+        let line = match name(index) {
+            Some(name) => format!("{} {} ({}) {}", kind, index, name, changes.join(", ")),
+            None => format!("{} {} {}", kind, index, changes.join(", ")),
+        };
+        self.lines.push(line);
+    }
+}
@@ -0,0 +1,183 @@
+//! The game's messages, from the text database (dtextc.dat).
+//!
+//! The messages are stored after the index at the start of the file, each one
+//! starting at a multiple of 8 bytes and ending with a NUL. Every byte is XORed
+//! with a key that depends on its offset. The whole area is read and decoded
+//! once, when the game first prints something.
+//!
+//! A message is named by a number: a positive one is an index into rtext
+//! (rmsg_), which holds the message's offset; a negative one is an offset
+//! itself, as -(offset / 8) - 1. 0 is no message at all.
+
+use std::fs;
+use std::io;
+use std::sync::{Arc, Mutex, MutexGuard};
+use libc::c_int;
+use core;
+use ffi;
+use files;
+
+const KEY: &[u8; 16] = b"IanLanceTaylorJr";
+
+/// The decoded messages of a text database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextDb {
+    // The message area, decoded.
+    text: String,
+
+    // rtext: the offsets of the numbered messages.
+    index: Vec<c_int>,
+}
+
+lazy_static! {
+    // The database of the running game, loaded on first use.
+    static ref CURRENT: Mutex<Option<Arc<TextDb>>> = Mutex::new(None);
+
+    // The CRC-32 of the running game's data file, worked out on first use.
+    static ref DATA_HASH: Mutex<Option<u32>> = Mutex::new(None);
+}
+
+impl TextDb {
+    /// Decodes the message area of a text database. `start` is where it
+    /// begins in `data` (mrloc), and `index` is the table of message offsets
+    /// (rtext).
+    ///
+    /// The messages are ASCII; any other byte is read as '?'.
+    pub fn new(data: &[u8], start: usize, index: Vec<c_int>) -> TextDb {
+        let area = data.get(start..).unwrap_or(&[]);
+        let text = area.iter().enumerate()
+            .map(|(x, &byte)| crypt(x, byte))
+            .map(|byte| if byte.is_ascii() { byte as char } else { '?' })
+            .collect();
+        TextDb { text, index }
+    }
+
+    /// Loads the text database the running game uses. The C code must have
+    /// read its index (in init_()) already.
+    pub fn load() -> io::Result<TextDb> {
+        let (start, index) = unsafe {
+            let messages = &ffi::messages;
+            (messages.location as usize, messages.text[..messages.len()].to_vec())
+        };
+        let db = match files::data_file() {
+            Some(path) => TextDb::new(&fs::read(path)?, start, index),
+            None => TextDb::new(files::BUILT_IN_DATA, start, index),
+        };
+        debug!("Loaded {} bytes of messages", db.text.len());
+        Ok(db)
+    }
+
+    /// The text of a message, with any `#`s left in. Messages that aren't in
+    /// the database (and message 0) are empty.
+    pub fn message(&self, id: c_int) -> &str {
+        self.get(id).unwrap_or("")
+    }
+
+    /// The text of a message, or `None` if it isn't in the database.
+    pub fn get(&self, id: c_int) -> Option<&str> {
+        let pointer = self.pointer(id)?;
+        if pointer >= 0 {
+            return if pointer == 0 { Some("") } else { None };
+        }
+
+        let start = (-(pointer as i64) - 1) as usize * 8;
+        let rest = self.text.get(start..)?;
+        rest.find('\0').map(|end| &rest[..end])
+    }
+
+    /// Whether a message is no message at all: 0, or a numbered message that
+    /// is 0 in rtext. rspeak_() prints nothing for these, not even a newline.
+    pub fn is_blank(&self, id: c_int) -> bool {
+        self.pointer(id) == Some(0)
+    }
+
+    /// The text of a message, with each `#` replaced by the next of
+    /// `substitutions` (which are messages themselves), as rspsb2_() does.
+    /// Once they run out, `#`s are left as they are. A substitution of 0 ends
+    /// the list.
+    pub fn substitute(&self, id: c_int, substitutions: &[c_int]) -> String {
+        let mut substitutions = substitutions.iter().cloned().take_while(|&id| id != 0);
+        self.substitute_text(self.message(id), &mut substitutions, |_| None)
+    }
+
+    /// `text` with each `#` replaced by the message from `substitutions`
+    /// that's next; the ones used up are taken from it, so a message can be
+    /// substituted a piece at a time. `lookup` gives the text of a message
+    /// in place of the database's, for translations.
+    pub fn substitute_text<I, F>(&self, text: &str, substitutions: &mut I, lookup: F) -> String
+        where I: Iterator<Item = c_int>, F: Fn(c_int) -> Option<String>
+    {
+        let mut substituted = String::new();
+        for c in text.chars() {
+            if c == '#' {
+                if let Some(substitution) = substitutions.next() {
+                    match lookup(substitution) {
+                        Some(text) => substituted.push_str(&text),
+                        None => substituted.push_str(self.message(substitution)),
+                    }
+                    continue;
+                }
+            }
+            substituted.push(c);
+        }
+        substituted
+    }
+
+    // The entry for a message in rtext, or the offset a negative one names.
+    fn pointer(&self, id: c_int) -> Option<c_int> {
+        if id > 0 {
+            self.index.get(id as usize - 1).cloned()
+        } else {
+            Some(id)
+        }
+    }
+}
+
+// Encodes or decodes the byte `offset` bytes into the message area. The
+// cipher is an XOR, so doing it twice gives the byte back.
+pub(crate) fn crypt(offset: usize, byte: u8) -> u8 {
+    byte ^ KEY[offset & 0xf] ^ (offset & 0xff) as u8
+}
+
+/// The text database of the running game. It is loaded the first time this is
+/// called in a game.
+pub fn current() -> Arc<TextDb> {
+    let mut current = lock();
+    if let Some(ref db) = *current {
+        return db.clone();
+    }
+
+    let db = match TextDb::load() {
+        Ok(db) => Arc::new(db),
+        Err(err) => {
+            error!("Unable to read the text database: {}", err);
+            core::exit_program();
+        }
+    };
+    *current = Some(db.clone());
+    db
+}
+
+/// The CRC-32 of the running game's text database, from
+/// `files::data_hash()`. It is worked out the first time this is called in a
+/// game, rather than on every save and restore.
+pub fn data_hash() -> io::Result<u32> {
+    let mut hash = DATA_HASH.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some(hash) = *hash {
+        return Ok(hash);
+    }
+    let computed = files::data_hash()?;
+    *hash = Some(computed);
+    Ok(computed)
+}
+
+/// Forgets the loaded database and its hash. Done when a new game starts,
+/// since it may use a different data file.
+pub fn reset() {
+    *lock() = None;
+    *DATA_HASH.lock().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+fn lock() -> MutexGuard<'static, Option<Arc<TextDb>>> {
+    CURRENT.lock().unwrap_or_else(|err| err.into_inner())
+}
@@ -0,0 +1,238 @@
+//! Translations of the game's text.
+//!
+//! A translation is a TOML file with two tables. `[messages]` replaces the
+//! messages of the text database, keyed by the number rspeak_() is given: the
+//! index into rtext for most messages, or the pointer (as `zork-data
+//! decompile` lists it) for the ones that aren't in the index, like room and
+//! object descriptions. Their `#`s are filled in as usual, with the
+//! translations of the substituted messages.
+//! `[strings]` replaces the text the C code prints itself, keyed by the
+//! English original: the formats given to supp_printf(), like
+//! `" %d [total of %d points], in %d move"`, and the lines given to
+//! more_output(). Anything that isn't in the translation is printed in
+//! English.
+//!
+//! ```toml
+//! [messages]
+//! 2 = "Fertig."
+//!
+//! [strings]
+//! "Your score " = "Ihr Punktestand "
+//! "would be" = "wäre"
+//! ```
+//!
+//! A replacement format must take the same arguments as the original, in the
+//! same order, since the C code passes them.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use libc::{c_char, c_int};
+use serde::Deserialize;
+use toml;
+
+/// The replacements for some of the game's text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Translation {
+    messages: HashMap<c_int, String>,
+    strings: HashMap<String, CString>,
+}
+
+/// A translation that can't be loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TranslationError {
+    /// The file can't be read.
+    Io(String),
+
+    /// The file isn't valid TOML, or doesn't have the right tables.
+    Toml(String),
+
+    /// A key in `[messages]` isn't a message number (or is 0).
+    BadMessage(String),
+
+    /// A string can't replace its original: its format takes different
+    /// arguments, or it has a NUL. Holds the original.
+    BadString(String),
+}
+
+// The layout of a translation file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Source {
+    #[serde(default)]
+    messages: BTreeMap<String, String>,
+    #[serde(default)]
+    strings: BTreeMap<String, String>,
+}
+
+lazy_static! {
+    // The translation in use.
+    static ref CURRENT: Mutex<Option<Arc<Translation>>> = Mutex::new(None);
+}
+
+thread_local! {
+    // The translation the last string from supp_translate() came from, which
+    // has to outlive the C code's use of it.
+    static IN_USE: RefCell<Option<Arc<Translation>>> = const { RefCell::new(None) };
+}
+
+impl Translation {
+    /// Reads a translation file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Translation, TranslationError> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(source) => Translation::from_toml(&source),
+            Err(err) => Err(TranslationError::Io(format!("{}: {}", path.display(), err))),
+        }
+    }
+
+    /// Reads a translation from TOML.
+    pub fn from_toml(source: &str) -> Result<Translation, TranslationError> {
+        let source: Source = toml::from_str(source)
+            .map_err(|err| TranslationError::Toml(err.to_string()))?;
+        let mut translation = Translation::default();
+        for (id, text) in source.messages {
+            match id.parse() {
+                Ok(number) if number != 0 => translation.add_message(number, text),
+                _ => return Err(TranslationError::BadMessage(id)),
+            }
+        }
+        for (original, text) in source.strings {
+            translation.add_string(original, text)?;
+        }
+        Ok(translation)
+    }
+
+    /// Replaces a message.
+    pub fn add_message(&mut self, id: c_int, text: String) {
+        self.messages.insert(id, text);
+    }
+
+    /// Replaces a string the C code prints. If it's a format, `text` must
+    /// take the same arguments.
+    pub fn add_string(&mut self, original: String, text: String) -> Result<(), TranslationError> {
+        let expected = arguments(&original);
+        if expected.is_none() || expected != arguments(&text) {
+            return Err(TranslationError::BadString(original));
+        }
+        match CString::new(text) {
+            Ok(text) => {
+                self.strings.insert(original, text);
+                Ok(())
+            }
+            Err(_) => Err(TranslationError::BadString(original)),
+        }
+    }
+
+    /// The replacement for a message, if there is one.
+    pub fn message(&self, id: c_int) -> Option<&str> {
+        self.messages.get(&id).map(String::as_str)
+    }
+
+    /// The replacement for a string the C code prints, if there is one.
+    pub fn string(&self, original: &str) -> Option<&str> {
+        self.strings.get(original).map(|text| text.to_str().expect("replacements are UTF-8"))
+    }
+}
+
+// The arguments a printf format takes, one word per conversion (with its
+// precision for strings, which may not be NUL-terminated), or None if it has
+// a conversion that can't be checked.
+fn arguments(format: &str) -> Option<Vec<String>> {
+    let mut arguments = Vec::new();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            continue;
+        }
+
+        let mut precision = String::new();
+        let mut length = String::new();
+        while let Some(&c) = chars.peek() {
+            match c {
+                '-' | '+' | ' ' | '#' | '0'..='9' if precision.is_empty() => {}
+                '*' => arguments.push("int".to_string()),
+                '.' | '0'..='9' => precision.push(c),
+                'h' | 'l' | 'L' | 'j' | 'z' | 't' => length.push(c),
+                _ => break,
+            }
+            chars.next();
+        }
+        let argument = match chars.next()? {
+            'd' | 'i' | 'c' => format!("{}int", length),
+            'u' | 'o' | 'x' | 'X' => format!("{}unsigned", length),
+            'e' | 'E' | 'f' | 'F' | 'g' | 'G' | 'a' | 'A' => format!("{}double", length),
+            's' if length.is_empty() => format!("string{}", precision),
+            _ => return None,
+        };
+        arguments.push(argument);
+    }
+    Some(arguments)
+}
+
+/// Uses `translation` for the game's text, or English with `None`.
+pub fn set(translation: Option<Translation>) {
+    *lock() = translation.map(Arc::new);
+}
+
+/// The translation in use, if any.
+pub fn current() -> Option<Arc<Translation>> {
+    lock().clone()
+}
+
+/// The replacement for a message in the translation in use.
+pub fn message(id: c_int) -> Option<String> {
+    current().and_then(|translation| translation.message(id).map(str::to_string))
+}
+
+/// `original`, or its replacement in the translation in use.
+pub fn string(original: &str) -> String {
+    match current() {
+        Some(ref translation) => translation.string(original).unwrap_or(original).to_string(),
+        None => original.to_string(),
+    }
+}
+
+// The format supp_printf() should use in place of `format`. The result is
+// valid until the next call on the same thread.
+pub(crate) fn format(format: *const c_char) -> *const c_char {
+    let translation = match current() {
+        Some(translation) => translation,
+        None => return format,
+    };
+    if format.is_null() {
+        return format;
+    }
+    let original = unsafe { CStr::from_ptr(format) };
+    let text = match original.to_str().ok().and_then(|original| translation.strings.get(original)) {
+        Some(text) => text.as_ptr(),
+        None => return format,
+    };
+    IN_USE.with(|in_use| *in_use.borrow_mut() = Some(translation.clone()));
+    text
+}
+
+fn lock() -> MutexGuard<'static, Option<Arc<Translation>>> {
+    CURRENT.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+impl fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TranslationError::Io(ref err) => write!(f, "Unable to read the translation: {}", err),
+            TranslationError::Toml(ref err) => write!(f, "{}", err),
+            TranslationError::BadMessage(ref id) => write!(f, "Not a message number: {}", id),
+            TranslationError::BadString(ref original) => {
+                write!(f, "The replacement for {:?} doesn't take the same arguments.", original)
+            }
+        }
+    }
+}
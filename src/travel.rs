@@ -0,0 +1,215 @@
+//! Decodes the travel table: the exits of every room.
+//!
+//! Each room's `rexit` points into `exits_.travel`, where its exits are stored
+//! one after another, the last one marked by `XLFLAG`. The first word of an
+//! exit holds its direction, its kind and the room it leads to; conditional
+//! exits and doors have a message in the next word, and a flag or object
+//! (plus the action that decides where they go) in the word after that. This
+//! is the layout `findxt_()` in dso3.c reads, with the masks from `xpars_`.
+
+use std::fmt;
+use libc::c_int;
+use ffi;
+
+// The fields of xpars_.
+const XRMASK: c_int = 255;
+const XDMASK: c_int = 31744;
+const XFMASK: c_int = 3;
+const XFSHFT: c_int = 256;
+const XASHFT: c_int = 256;
+// How many words each kind of exit takes.
+const XELNT: [usize; 4] = [1, 2, 3, 3];
+const XLFLAG: c_int = 32768;
+
+/// A direction an exit can go in. The values are the direction codes of
+/// `xsrch_` and `dirvoc_`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North = 1024,
+    NorthEast = 2048,
+    East = 3072,
+    SouthEast = 4096,
+    South = 5120,
+    SouthWest = 6144,
+    West = 7168,
+    NorthWest = 8192,
+    Up = 9216,
+    Down = 10240,
+    Launch = 11264,
+    Land = 12288,
+    Enter = 13312,
+    Exit = 14336,
+    Cross = 15360,
+}
+
+/// What it takes to go through an exit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExitKind {
+    /// The exit can always be taken.
+    Normal,
+
+    /// The exit can never be taken. Holds the message that says why.
+    No(c_int),
+
+    /// The exit can be taken when a flag (an index into findex_'s flags,
+    /// starting at 1) is set. Holds the flag, and the message shown when it
+    /// isn't set, or 0 for the default one.
+    Conditional(c_int, c_int),
+
+    /// The exit can be taken when a door (an object) is open. Holds the
+    /// object, and the message shown when it's closed, or 0 for the default
+    /// one.
+    Door(c_int, c_int),
+}
+
+/// One exit out of a room.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exit {
+    pub direction: Direction,
+    pub kind: ExitKind,
+
+    /// The room the exit leads to, or 0 for none.
+    pub target: c_int,
+
+    /// The routine in cxappl_() that is asked where a conditional exit or a
+    /// door goes before its flag or door is checked, or 0 for none.
+    pub action: c_int,
+}
+
+/// A travel table that can't be decoded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TravelError {
+    /// The room id is out of range.
+    NoSuchRoom(usize),
+
+    /// An exit starts or ends outside the table. Holds where it starts (from 1,
+    /// like rexit).
+    OutOfRange(usize),
+
+    /// An exit has a direction that isn't one of the direction codes. Holds
+    /// where the exit starts, and the code.
+    BadDirection(usize, c_int),
+
+    /// A door exit's door isn't an object. Holds the room the exit is in,
+    /// and the door.
+    NoSuchDoor(usize, c_int),
+}
+
+impl Direction {
+    /// Every direction, in the order of their codes.
+    pub const ALL: [Direction; 15] = [
+        Direction::North, Direction::NorthEast, Direction::East, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::West, Direction::NorthWest,
+        Direction::Up, Direction::Down, Direction::Launch, Direction::Land, Direction::Enter,
+        Direction::Exit, Direction::Cross,
+    ];
+
+    /// The direction with this code, if there is one.
+    pub fn from_code(code: c_int) -> Option<Direction> {
+        Direction::ALL.iter().cloned().find(|&direction| direction.code() == code)
+    }
+
+    /// The code the C code uses for the direction.
+    pub fn code(self) -> c_int {
+        self as c_int
+    }
+
+    /// The direction's name, as the player would type it.
+    pub fn name(self) -> &'static str {
+        match self {
+            Direction::North => "north",
+            Direction::NorthEast => "northeast",
+            Direction::East => "east",
+            Direction::SouthEast => "southeast",
+            Direction::South => "south",
+            Direction::SouthWest => "southwest",
+            Direction::West => "west",
+            Direction::NorthWest => "northwest",
+            Direction::Up => "up",
+            Direction::Down => "down",
+            Direction::Launch => "launch",
+            Direction::Land => "land",
+            Direction::Enter => "enter",
+            Direction::Exit => "exit",
+            Direction::Cross => "cross",
+        }
+    }
+}
+
+/// Decodes the exits of a room in the current game.
+pub fn room_exits(room: usize) -> Result<Vec<Exit>, TravelError> {
+    let (rooms, exits) = unsafe { (&ffi::rooms, &ffi::exits) };
+    if room == 0 || room > rooms.len() {
+        return Err(TravelError::NoSuchRoom(room));
+    }
+    decode(&exits.travel, rooms.exits[room - 1] as usize)
+}
+
+/// Decodes the exits that start at `start` in a travel table. Like rexit,
+/// `start` counts from 1, and 0 means there are no exits.
+pub fn decode(travel: &[c_int], start: usize) -> Result<Vec<Exit>, TravelError> {
+    let mut exits = Vec::new();
+    if start == 0 {
+        return Ok(exits);
+    }
+
+    let mut index = start;
+    loop {
+        let word = match travel.get(index - 1) {
+            Some(&word) => word,
+            None => return Err(TravelError::OutOfRange(index)),
+        };
+        let kind = (((word & !XLFLAG & 65535) / XFSHFT) & XFMASK) as usize + 1;
+        let length = XELNT[kind - 1];
+        if index - 1 + length > travel.len() {
+            return Err(TravelError::OutOfRange(index));
+        }
+
+        let code = word & XDMASK;
+        let direction = match Direction::from_code(code) {
+            Some(direction) => direction,
+            None => return Err(TravelError::BadDirection(index, code)),
+        };
+        let message = if kind >= 2 { travel[index] } else { 0 };
+        let (object, action) = if kind >= 3 {
+            (travel[index + 1] & XRMASK, travel[index + 1] / XASHFT)
+        } else {
+            (0, 0)
+        };
+        let kind = match kind {
+            1 => ExitKind::Normal,
+            2 => ExitKind::No(message),
+            3 => ExitKind::Conditional(object, message),
+            _ => ExitKind::Door(object, message),
+        };
+
+        exits.push(Exit {
+            direction,
+            kind,
+            target: word & XRMASK,
+            action,
+        });
+
+        if word & XLFLAG != 0 {
+            return Ok(exits);
+        }
+        index += length;
+    }
+}
+
+impl fmt::Display for TravelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TravelError::NoSuchRoom(room) => write!(f, "There is no room {}.", room),
+            TravelError::OutOfRange(index) => {
+                write!(f, "The exit at {} runs past the end of the travel table.", index)
+            }
+            TravelError::BadDirection(index, code) => {
+                write!(f, "The exit at {} has an unknown direction ({}).", index, code)
+            }
+            TravelError::NoSuchDoor(room, door) => {
+                write!(f, "A door out of room {} is object {}, which doesn't exist.", room, door)
+            }
+        }
+    }
+}
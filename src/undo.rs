@@ -0,0 +1,107 @@
+
+//! UNDO, and the snapshots it restores.
+//!
+//! A snapshot of the game (a `GameState`) is taken as game_() starts each
+//! command, so `UNDO n` can put the game back to where it was n commands ago.
+//! A line like "TAKE LAMP, N" is two commands, and two snapshots.
+//!
+//! UNDO itself is only read as a line of its own.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+use output;
+use state::GameState;
+
+/// How many commands can be undone.
+pub const HISTORY_LENGTH: usize = 100;
+
+/// Why UNDO couldn't be done.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UndoError {
+    /// No commands have been entered yet.
+    NothingToUndo,
+
+    /// Fewer commands than asked for can be undone. Holds how many can.
+    TooMany(usize),
+}
+
+lazy_static! {
+    // The oldest snapshot is at the front.
+    static ref HISTORY: Mutex<VecDeque<GameState>> = Mutex::new(VecDeque::new());
+}
+
+/// Remembers the state of the game before a command is run.
+pub fn snapshot() {
+    let mut history = lock();
+    if history.len() == HISTORY_LENGTH {
+        history.pop_front();
+    }
+    history.push_back(GameState::capture());
+}
+
+/// Forgets every snapshot. Done when a new game starts.
+pub fn clear() {
+    lock().clear();
+}
+
+/// How many commands can be undone.
+pub fn available() -> usize {
+    lock().len()
+}
+
+/// Puts the game back to where it was before the last `moves` commands.
+pub fn undo(moves: usize) -> Result<(), UndoError> {
+    let mut history = lock();
+    if history.is_empty() {
+        return Err(UndoError::NothingToUndo);
+    }
+    if moves > history.len() {
+        return Err(UndoError::TooMany(history.len()));
+    }
+
+    let keep = history.len() - moves;
+    let snapshot = history.drain(keep..).next().expect("moves is at least 1");
+    debug!("Undoing {} moves, {} left", moves, history.len());
+    snapshot.restore();
+    Ok(())
+}
+
+/// Handles UNDO and UNDO n. `command` is an uppercase line of input. Returns
+/// false if it isn't UNDO.
+pub fn handle_command(command: &str) -> bool {
+    let words: Vec<&str> = command.split_whitespace().collect();
+    let moves = match words.as_slice() {
+        ["UNDO"] => 1,
+        ["UNDO", moves] => match moves.parse() {
+            Ok(moves) if moves > 0 => moves,
+            _ => {
+                output::write("UNDO takes the number of moves to take back, such as UNDO 3.\n");
+                return true;
+            }
+        },
+        _ => return false,
+    };
+
+    let text = match undo(moves) {
+        Ok(()) => format!("Took back {}.", count_moves(moves)),
+        Err(UndoError::NothingToUndo) => "There is nothing to undo.".to_string(),
+        Err(UndoError::TooMany(available)) => {
+            format!("Only {} can be taken back.", count_moves(available))
+        }
+    };
+    output::write(&text);
+    output::write("\n");
+    true
+}
+
+fn count_moves(moves: usize) -> String {
+    if moves == 1 {
+        "1 move".to_string()
+    } else {
+        format!("{} moves", moves)
+    }
+}
+
+fn lock() -> MutexGuard<'static, VecDeque<GameState>> {
+    HISTORY.lock().unwrap_or_else(|err| err.into_inner())
+}
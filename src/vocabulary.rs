@@ -0,0 +1,254 @@
+//! Vocabulary packs: other words for the ones the parser knows.
+//!
+//! The parser's vocabulary is the English one in parse.h, and its lexer only
+//! takes the letters A to Z, the digits 1 to 9 and '-'. A pack maps other
+//! words, in any language, to the English ones; each command is rewritten
+//! with it before the lexer sees it, so the pack's words come first.
+//!
+//! A pack is a TOML file with a table for each kind of word, as parse.h has
+//! them: `[buzzwords]`, `[verbs]`, `[directions]`, `[prepositions]`,
+//! `[adjectives]` and `[objects]`. What a word stands for must have a word of
+//! its table's kind in it; one under `[verbs]` needs one of the parser's
+//! verbs.
+//!
+//! ```toml
+//! [verbs]
+//! nimm = "take"
+//! "öffne" = "open"
+//!
+//! [directions]
+//! norden = "north"
+//! ```
+//!
+//! Words are matched without regard to case. A word can stand for more than
+//! one English word, like "pick up".
+//!
+//! A word can be under `[verbs]` and one other table. It is read as the verb
+//! where the parser would take one, before the command has a verb, and as
+//! the other word after that.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use serde::Deserialize;
+use toml;
+use lexicon::{self, WordKind};
+use replacement::vocab;
+
+/// Other words for the parser's words.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VocabularyPack {
+    // The English words by the lowercase word they replace and the table
+    // they're from, uppercase, as the lexer is given them.
+    words: HashMap<String, BTreeMap<WordKind, String>>,
+}
+
+/// A pack that can't be loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VocabularyError {
+    /// The file can't be read.
+    Io(String),
+
+    /// The file isn't valid TOML, or doesn't have the right tables.
+    Toml(String),
+
+    /// A word is empty, or has a space, '.' or ',' in it, so it would never be
+    /// typed as one word.
+    BadWord(String),
+
+    /// A word stands for something the lexer can't read. Holds the word.
+    BadEnglish(String),
+
+    /// A word stands for something without a word of its table's kind, like
+    /// a word under `[verbs]` that isn't for one of the parser's verbs. Holds
+    /// the word and the kind.
+    WrongKind(String, WordKind),
+
+    /// A word stands for two different things, other than a verb and one
+    /// more. Holds the word.
+    Conflict(String),
+}
+
+// The layout of a pack file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Source {
+    #[serde(default)]
+    buzzwords: BTreeMap<String, String>,
+    #[serde(default)]
+    verbs: BTreeMap<String, String>,
+    #[serde(default)]
+    directions: BTreeMap<String, String>,
+    #[serde(default)]
+    prepositions: BTreeMap<String, String>,
+    #[serde(default)]
+    adjectives: BTreeMap<String, String>,
+    #[serde(default)]
+    objects: BTreeMap<String, String>,
+}
+
+lazy_static! {
+    // The pack in use.
+    static ref CURRENT: Mutex<Option<Arc<VocabularyPack>>> = Mutex::new(None);
+}
+
+impl VocabularyPack {
+    /// Reads a pack file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<VocabularyPack, VocabularyError> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(source) => VocabularyPack::from_toml(&source),
+            Err(err) => Err(VocabularyError::Io(format!("{}: {}", path.display(), err))),
+        }
+    }
+
+    /// Reads a pack from TOML.
+    pub fn from_toml(source: &str) -> Result<VocabularyPack, VocabularyError> {
+        let source: Source = toml::from_str(source)
+            .map_err(|err| VocabularyError::Toml(err.to_string()))?;
+        let mut pack = VocabularyPack::default();
+        let tables = vec![
+            (WordKind::Buzzword, source.buzzwords),
+            (WordKind::Verb, source.verbs),
+            (WordKind::Direction, source.directions),
+            (WordKind::Preposition, source.prepositions),
+            (WordKind::Adjective, source.adjectives),
+            (WordKind::Object, source.objects),
+        ];
+        for (kind, table) in tables {
+            for (word, english) in table {
+                pack.add(kind, &word, &english)?;
+            }
+        }
+        Ok(pack)
+    }
+
+    /// Adds `word` as another word for `english`, which is a word of `kind`.
+    pub fn add(&mut self, kind: WordKind, word: &str, english: &str)
+               -> Result<(), VocabularyError> {
+        if word.is_empty() || word.contains(|c: char| is_separator(c) || c.is_whitespace()) {
+            return Err(VocabularyError::BadWord(word.to_string()));
+        }
+        let english: Vec<&str> = english.split_whitespace().collect();
+        if english.is_empty() || !english.iter().all(|word| vocab::encode(word).is_some()) {
+            return Err(VocabularyError::BadEnglish(word.to_string()));
+        }
+        if !english.iter().any(|word| lexicon::kinds(word).contains(&kind)) {
+            return Err(VocabularyError::WrongKind(word.to_string(), kind));
+        }
+
+        let english = english.join(" ").to_ascii_uppercase();
+        let word = word.to_lowercase();
+        let kinds = self.words.entry(word.clone()).or_default();
+        // A word can be a verb and one other kind of word, but not two others.
+        let conflict = kinds.iter().any(|(&other, existing)| {
+            *existing != english
+                && (other == kind || other != WordKind::Verb && kind != WordKind::Verb)
+        });
+        if conflict {
+            return Err(VocabularyError::Conflict(word));
+        }
+        kinds.insert(kind, english);
+        Ok(())
+    }
+
+    /// The English for a word as a word of `kind`, if it's in the pack.
+    pub fn english(&self, kind: WordKind, word: &str) -> Option<&str> {
+        self.words.get(&word.to_lowercase())?.get(&kind).map(String::as_str)
+    }
+
+    /// A command, with the words in the pack replaced by their English.
+    pub fn rewrite(&self, command: &str) -> String {
+        let mut rewritten = String::with_capacity(command.len());
+        let mut rest = command;
+        // Whether the command has a verb yet. The parser takes one verb, the
+        // first word it has for one, and reads the rest as other words.
+        let mut has_verb = false;
+        while !rest.is_empty() {
+            let end = rest.find(is_separator).unwrap_or(rest.len());
+            let (word, after) = rest.split_at(end);
+            let english = self.english_at(word, has_verb).unwrap_or(word);
+            rewritten.push_str(english);
+            has_verb = has_verb || english.split(' ').any(|word| {
+                lexicon::kinds(word).first() == Some(&WordKind::Verb)
+            });
+
+            let separator = after.chars().next();
+            if let Some(separator) = separator {
+                rewritten.push(separator);
+                // '.' and ',' start another command.
+                has_verb = has_verb && separator == ' ';
+            }
+            rest = &after[separator.map_or(0, char::len_utf8)..];
+        }
+        rewritten
+    }
+
+    // The English for a word in a command: its verb if the command doesn't
+    // have one yet, and otherwise the other word it stands for.
+    fn english_at(&self, word: &str, has_verb: bool) -> Option<&str> {
+        let kinds = self.words.get(&word.to_lowercase())?;
+        if !has_verb {
+            if let Some(verb) = kinds.get(&WordKind::Verb) {
+                return Some(verb);
+            }
+        }
+        kinds.iter().find(|&(&kind, _)| kind != WordKind::Verb).map(|(_, english)| english.as_str())
+    }
+}
+
+// The characters that end a word for the lexer.
+fn is_separator(c: char) -> bool {
+    c == ' ' || c == '.' || c == ','
+}
+
+/// Uses `pack` for the player's commands, or only the English words with
+/// `None`.
+pub fn set(pack: Option<VocabularyPack>) {
+    *lock() = pack.map(Arc::new);
+}
+
+/// The pack in use, if any.
+pub fn current() -> Option<Arc<VocabularyPack>> {
+    lock().clone()
+}
+
+/// A command, rewritten with the pack in use.
+pub fn rewrite(command: &str) -> String {
+    match current() {
+        Some(pack) => {
+            let rewritten = pack.rewrite(command);
+            if rewritten != command {
+                debug!("Rewrote {:?} as {:?}", command, rewritten);
+            }
+            rewritten
+        }
+        None => command.to_string(),
+    }
+}
+
+fn lock() -> MutexGuard<'static, Option<Arc<VocabularyPack>>> {
+    CURRENT.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VocabularyError::Io(ref err) => write!(f, "Unable to read the vocabulary: {}", err),
+            VocabularyError::Toml(ref err) => write!(f, "{}", err),
+            VocabularyError::BadWord(ref word) => write!(f, "Not a single word: {:?}", word),
+            VocabularyError::BadEnglish(ref word) => {
+                write!(f, "{:?} stands for something the parser can't read.", word)
+            }
+            VocabularyError::WrongKind(ref word, kind) => {
+                write!(f, "{:?} is under [{}], but doesn't stand for one of the parser's {}.",
+                       word, kind.section(), kind.section())
+            }
+            VocabularyError::Conflict(ref word) => {
+                write!(f, "{:?} stands for more than one thing.", word)
+            }
+        }
+    }
+}
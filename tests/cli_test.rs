@@ -0,0 +1,138 @@
+extern crate tempfile;
+
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use tempfile::TempDir;
+
+// Runs the zork binary in `dir`, with `input` on stdin.
+fn run(dir: &TempDir, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_zork"))
+        .args(args)
+        .current_dir(dir.path())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_help() {
+    let dir = TempDir::new().unwrap();
+    let output = run(&dir, &["--help"], "");
+    assert!(output.status.success());
+
+    let help = stdout(&output);
+    for option in &["--data", "--translation", "--vocabulary", "--log-dir", "--log-level", "--no-record", "--record-to",
+                    "--seed", "--save-dir", "--replay", "--batch", "--help"] {
+        assert!(help.contains(option), "{} is missing from --help", option);
+    }
+    assert!(fs::read_dir(dir.path()).unwrap().next().is_none());
+}
+
+#[test]
+fn test_bad_option() {
+    let dir = TempDir::new().unwrap();
+    let output = run(&dir, &["--seed", "lots"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Not a valid seed: lots"));
+}
+
+#[test]
+fn test_batch() {
+    let dir = TempDir::new().unwrap();
+    let output = run(&dir, &["--batch", "--no-record", "--log-level", "off"], "open mailbox\n");
+    assert!(output.status.success());
+
+    let text = stdout(&output);
+    assert!(!text.contains('>'));
+    assert!(text.ends_with("Opening the mailbox reveals:\n  A leaflet.\nThe game is over.\n\n"));
+    assert!(fs::read_dir(dir.path()).unwrap().next().is_none());
+}
+
+#[test]
+fn test_record_and_replay() {
+    let dir = TempDir::new().unwrap();
+    let moves = "swim\nswim\nswim\nswim\nswim\nsave\n";
+    let args = ["--batch", "--seed", "31", "--record-to", "moves.txt",
+                "--log-dir", "logs", "--save-dir", "saves"];
+    let first = stdout(&run(&dir, &args, moves));
+
+    let recording = fs::read_to_string(dir.path().join("moves.txt")).unwrap();
+    assert_eq!(recording, "# seed: 31\nSWIM\nSWIM\nSWIM\nSWIM\nSWIM\nSAVE\n");
+    assert!(dir.path().join("logs/log.txt").exists());
+    assert!(dir.path().join("logs/trace_log.txt").exists());
+    assert!(dir.path().join("saves/dsave.dat").exists());
+    assert!(!dir.path().join("playback").exists());
+
+    // The replay uses the recorded seed, and echoes the moves.
+    let replay = stdout(&run(&dir, &["--batch", "--no-record", "--replay", "moves.txt"], ""));
+    assert!(replay.contains("SWIM\n"));
+    assert_eq!(replay.replace("SWIM\n", "").replace("SAVE\n", ""), first);
+}
+
+#[test]
+fn test_replay_without_seed() {
+    // Recordings from before the seed was recorded replay with a fixed seed.
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("old.txt"), "SWIM\nSWIM\nSWIM\nSWIM\nSWIM\n").unwrap();
+    let args = ["--batch", "--no-record", "--log-level", "off", "--replay", "old.txt"];
+    let replay = stdout(&run(&dir, &args, ""));
+    assert_eq!(stdout(&run(&dir, &args, "")), replay);
+
+    let seeded = stdout(&run(&dir, &["--batch", "--no-record", "--log-level", "off", "--seed", "0"],
+                             "swim\nswim\nswim\nswim\nswim\n"));
+    assert_eq!(replay.replace("SWIM\n", ""), seeded);
+}
+
+#[test]
+fn test_missing_data_file() {
+    let dir = TempDir::new().unwrap();
+    let output = run(&dir, &["--batch", "--no-record", "--log-level", "off", "--data", "nowhere.dat"], "");
+    assert!(stdout(&output).starts_with("I can't open nowhere.dat.\n"));
+}
+
+#[test]
+fn test_translation() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("de.toml"), r#"
+[strings]
+"I can't open %s.\n" = "Ich kann %s nicht \u00f6ffnen.\n"
+"                       INITIALIZATION FAILURE" = "                       INITIALISIERUNGSFEHLER"
+"#).unwrap();
+    let args = ["--batch", "--no-record", "--log-level", "off", "--data", "nowhere.dat",
+                "--translation", "de.toml"];
+    let text = stdout(&run(&dir, &args, ""));
+    assert!(text.starts_with("Ich kann nowhere.dat nicht \u{f6}ffnen.\n"), "{}", text);
+    assert!(text.contains("INITIALISIERUNGSFEHLER\n"));
+    assert!(text.contains("seeming to float in the air."));
+
+    fs::write(dir.path().join("bad.toml"), "[strings]\n\"%d minute\" = \"Minute\"\n").unwrap();
+    let output = run(&dir, &["--translation", "bad.toml"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("doesn't take the same arguments"));
+}
+
+#[test]
+fn test_data_override() {
+    let dir = TempDir::new().unwrap();
+    let args = ["--batch", "--no-record", "--log-level", "off", "--data", "old.dat"];
+
+    // A database from another version of the game is refused.
+    fs::write(dir.path().join("old.dat"), [0, 1, 0, 0, 0, b'A']).unwrap();
+    assert!(stdout(&run(&dir, &args, "")).starts_with("old.dat is version 1.0A.\n"));
+
+    // A copy of the real one works just like the built-in one.
+    fs::copy("c_src/dtextc.dat", dir.path().join("old.dat")).unwrap();
+    let from_file = stdout(&run(&dir, &args, "open mailbox\n"));
+    let built_in = stdout(&run(&dir, &args[..4], "open mailbox\n"));
+    assert!(from_file.contains("A leaflet."));
+    assert_eq!(from_file, built_in);
+}
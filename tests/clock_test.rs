@@ -0,0 +1,51 @@
+extern crate zork;
+
+mod common;
+
+use zork::clock::{self, Clock, VirtualClock, WallClock};
+use zork::Game;
+use common::lock;
+
+#[test]
+fn test_virtual_clock() {
+    let mut clock = VirtualClock::new(90).starting_at(23, 58, 0);
+    assert_eq!(clock.time_of_day(), (23, 58, 0));
+    clock.tick();
+    assert_eq!(clock.time_of_day(), (23, 59, 30));
+    clock.tick();
+    assert_eq!(clock.time_of_day(), (0, 1, 0));
+}
+
+#[test]
+fn test_time_command() {
+    let _guard = lock();
+    clock::set_clock(Box::new(VirtualClock::new(60).starting_at(12, 0, 0)));
+
+    let mut game = Game::new().unwrap();
+    game.step("open mailbox").unwrap();
+    game.step("take leaflet").unwrap();
+    let turn = game.step("time").unwrap();
+    assert_eq!(turn.text, "You have been playing Dungeon for 3 minutes.\n");
+
+    for _ in 0..60 {
+        game.step("look").unwrap();
+    }
+    let turn = game.step("time").unwrap();
+    assert_eq!(turn.text, "You have been playing Dungeon for 1 hour and 4 minutes.\n");
+
+    drop(game);
+    clock::set_clock(Box::new(WallClock));
+}
+
+#[test]
+fn test_time_past_midnight() {
+    let _guard = lock();
+    clock::set_clock(Box::new(VirtualClock::new(120).starting_at(23, 59, 0)));
+
+    let mut game = Game::new().unwrap();
+    let turn = game.step("time").unwrap();
+    assert_eq!(turn.text, "You have been playing Dungeon for 2 minutes.\n");
+
+    drop(game);
+    clock::set_clock(Box::new(WallClock));
+}
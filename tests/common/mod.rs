@@ -0,0 +1,41 @@
+// What the test files share. Not every file uses all of it.
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+use std::sync::{Mutex, MutexGuard};
+
+// Only one game can run at a time, and the rest of the globals (the clock,
+// the generator, the input and output, the C common blocks) are shared too,
+// so the tests in a file take turns.
+static GAME_LOCK: Mutex<()> = Mutex::new(());
+
+pub fn lock() -> MutexGuard<'static, ()> {
+    GAME_LOCK.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+// A seeded generator for made-up inputs, so that they are the same on every
+// run.
+pub struct Random(u32);
+
+impl Random {
+    pub fn new(seed: u32) -> Random {
+        Random(seed)
+    }
+
+    // A number below `limit`.
+    pub fn below(&mut self, limit: usize) -> usize {
+        self.0 = self.0.wrapping_mul(1103515245).wrapping_add(12345);
+        (self.0 >> 16) as usize % limit
+    }
+}
+
+// Runs a C routine and the Rust one that replaces it, and checks that they
+// come out the same. Each closure sets up the game it runs in. `input` is
+// shown if they differ.
+pub fn same_as_c<I: Debug, T: Debug + PartialEq>(input: I, c: impl FnOnce() -> T,
+                                                 rust: impl FnOnce() -> T) -> T {
+    let expected = c();
+    let outcome = rust();
+    assert_eq!(outcome, expected, "{:?}", input);
+    outcome
+}
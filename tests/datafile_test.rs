@@ -0,0 +1,116 @@
+extern crate tempfile;
+extern crate zork;
+
+mod common;
+
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+use zork::datafile::{DataFile, DataFileError};
+use zork::files;
+use zork::Game;
+use common::lock;
+
+fn built_in() -> DataFile {
+    DataFile::read(files::BUILT_IN_DATA).unwrap()
+}
+
+// The text of the message at `pointer`.
+fn text(file: &DataFile, pointer: i32) -> &str {
+    &file.messages.entries.iter().find(|message| message.pointer == pointer).unwrap().text
+}
+
+#[test]
+fn test_read() {
+    let file = built_in();
+    assert_eq!((file.header.major, file.header.minor), (2, 7));
+    assert_eq!(file.header.max_score, 585);
+    assert_eq!(file.rooms.len(), 190);
+    assert_eq!(file.exits.travel.len(), 886);
+    assert_eq!(file.objects.len(), 216);
+    assert_eq!(file.messages.index.len(), 1022);
+    assert_eq!(text(&file, file.rooms[1].description_2), "West of House");
+    assert!(file.clock_events.iter().any(|event| event.running));
+}
+
+#[test]
+fn test_round_trip() {
+    let file = built_in();
+    assert!(file.write().unwrap() == files::BUILT_IN_DATA);
+
+    let source = file.to_toml();
+    let read = DataFile::from_toml(&source).unwrap();
+    assert_eq!(read, file);
+    assert!(read.write().unwrap() == files::BUILT_IN_DATA);
+}
+
+#[test]
+fn test_edit() {
+    let mut file = built_in();
+    let west = file.rooms[1].description_1;
+    let message = file.messages.entries.iter_mut().find(|message| message.pointer == west).unwrap();
+    message.text = message.text.replace("big white house", "big white house, much bigger than before,");
+
+    // Everything after it moves, and everything pointing to it moves too.
+    let edited = DataFile::read(&file.write().unwrap()).unwrap();
+    assert!(text(&edited, edited.rooms[1].description_1).contains("much bigger than before"));
+    assert_eq!(text(&edited, edited.rooms[2].description_2), "North of House");
+    let last = file.messages.index.len() - 1;
+    assert_eq!(text(&edited, edited.messages.index[last]), text(&file, file.messages.index[last]));
+
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("dtextc.dat");
+    fs::write(&path, file.write().unwrap()).unwrap();
+    let _guard = lock();
+    files::set_data_file(Some(&path));
+    let output = Game::new().and_then(|mut game| game.step("look"));
+    files::set_data_file(None::<&str>);
+    let text = output.unwrap().text;
+    assert!(text.contains("much bigger than before"));
+    assert!(text.contains("There is a small mailbox here."));
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(DataFile::read(&files::BUILT_IN_DATA[..100]), Err(DataFileError::Truncated));
+
+    let mut file = built_in();
+    file.rooms[1].description_2 = -30000;
+    assert_eq!(file.write(), Err(DataFileError::UnknownMessage(-30000)));
+
+    let mut file = built_in();
+    file.messages.entries[0].text = "Caf\u{e9}".to_string();
+    assert_eq!(file.write(), Err(DataFileError::BadText(-1)));
+
+    let mut file = built_in();
+    file.rooms[0].value = 40000;
+    assert_eq!(file.write(), Err(DataFileError::OutOfRange("room values", 40000)));
+}
+
+#[test]
+fn test_tool() {
+    let dir = TempDir::new().unwrap();
+    let source = dir.path().join("dtextc.toml");
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-data"))
+        .args(["decompile", "-o"])
+        .arg(&source)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(fs::read_to_string(&source).unwrap().contains("text = \"West of House\""));
+
+    let data = dir.path().join("dtextc.dat");
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-data"))
+        .arg("compile")
+        .arg(&source)
+        .arg("-o")
+        .arg(&data)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(fs::read(&data).unwrap() == files::BUILT_IN_DATA);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-data")).arg("compile").arg(&source).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Missing the file to write"));
+}
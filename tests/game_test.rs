@@ -0,0 +1,87 @@
+extern crate zork;
+
+mod common;
+
+use zork::{Game, GameError};
+use zork::output::BufferSink;
+use common::lock;
+
+#[test]
+fn test_intro() {
+    let _guard = lock();
+    let game = Game::new().unwrap();
+
+    let intro = game.intro();
+    assert!(intro.text.starts_with("Welcome to Dungeon."));
+    assert!(intro.text.contains("There is a small mailbox here."));
+    assert_eq!(intro.room, 2);
+    assert_eq!(intro.moves, 0);
+    assert!(!intro.game_over);
+}
+
+#[test]
+fn test_step() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    let turn = game.step("open mailbox").unwrap();
+    assert_eq!(turn.text, "Opening the mailbox reveals:\n  A leaflet.\n");
+    assert_eq!(turn.moves, 1);
+
+    let turn = game.step("north").unwrap();
+    assert!(turn.text.starts_with("You are facing the north side of a white house."));
+    assert_ne!(turn.room, 2);
+    assert_eq!(turn.moves, 2);
+}
+
+#[test]
+fn test_quit_ends_game() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    let turn = game.step("quit").unwrap();
+    assert!(turn.text.contains("Do you wish to leave the game?"));
+    assert!(!turn.game_over);
+
+    let turn = game.step("yes").unwrap();
+    assert!(turn.text.ends_with("The game is over.\n\n"));
+    assert!(turn.game_over);
+    assert!(game.is_over());
+    assert_eq!(game.step("look"), Err(GameError::GameOver));
+
+    // The process survives, and a new game can be started.
+    drop(game);
+    let game = Game::new().unwrap();
+    assert!(game.intro().text.starts_with("Welcome to Dungeon."));
+}
+
+#[test]
+fn test_one_game_at_a_time() {
+    let _guard = lock();
+    let game = Game::new().unwrap();
+    assert_eq!(Game::new().err(), Some(GameError::AlreadyRunning));
+
+    drop(game);
+    assert!(Game::new().is_ok());
+}
+
+#[test]
+fn test_extra_sink() {
+    let _guard = lock();
+    let transcript = BufferSink::new();
+    let mut game = Game::with_sink(Box::new(transcript.clone())).unwrap();
+
+    let mut expected = game.intro().text.clone();
+    expected.push_str(&game.step("open mailbox").unwrap().text);
+    expected.push_str(&game.step("take leaflet").unwrap().text);
+    assert_eq!(transcript.contents(), expected);
+
+    // The sink is released when the game ends.
+    drop(game);
+    expected.push_str("The game is over.\n\n");
+    assert_eq!(transcript.contents(), expected);
+
+    let game = Game::new().unwrap();
+    drop(game);
+    assert_eq!(transcript.contents(), expected);
+}
@@ -0,0 +1,96 @@
+extern crate tempfile;
+extern crate zork;
+
+mod common;
+
+use std::io::Cursor;
+use std::sync::mpsc;
+use std::thread;
+use tempfile::TempDir;
+use zork::{core, files};
+use zork::input::{self, ChannelSource, EndOfInput, InputSource, IterSource, ScriptSource};
+use zork::output::{self, BufferSink};
+use common::lock;
+
+// Plays a whole game with the given input, and returns everything printed.
+fn play(source: Box<dyn InputSource>) -> String {
+    let buffer = BufferSink::new();
+    let previous_sink = output::set_sink(Box::new(buffer.clone()));
+    let previous_source = input::set_source(source);
+
+    assert!(core::run_game());
+
+    input::set_source(previous_source);
+    output::set_sink(previous_sink);
+    buffer.take()
+}
+
+fn lines(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn test_iterator_runs_out() {
+    let _guard = lock();
+    let text = play(Box::new(IterSource::new(lines(&["open mailbox", "", "take leaflet"]))));
+
+    assert!(text.starts_with("Welcome to Dungeon."));
+    assert!(text.contains(">Opening the mailbox reveals:\n  A leaflet.\n"));
+    assert!(text.contains(">Taken.\n"));
+    assert!(text.ends_with(">The game is over.\n\n"));
+}
+
+#[test]
+fn test_script() {
+    let _guard = lock();
+    let script = Cursor::new("open mailbox\r\nread leaflet\n");
+    let text = play(Box::new(ScriptSource::new(script)));
+
+    assert!(text.contains("On-line information may be obtained with the commands HELP and INFO."));
+    assert!(text.ends_with("The game is over.\n\n"));
+}
+
+#[test]
+fn test_yesno_reads_from_source() {
+    let _guard = lock();
+    let (sender, receiver) = mpsc::channel();
+    sender.send("quit".to_string()).unwrap();
+    sender.send("y".to_string()).unwrap();
+    sender.send("look".to_string()).unwrap();
+    drop(sender);
+
+    let text = play(Box::new(ChannelSource::new(receiver)));
+    assert!(text.contains("Do you wish to leave the game?"));
+    assert!(!text.contains("West of House\n"));
+}
+
+#[test]
+fn test_save_at_end_of_input() {
+    let _guard = lock();
+    let dir = TempDir::new().unwrap();
+    files::set_save_dir(dir.path());
+
+    input::set_end_of_input(EndOfInput::SaveAndExit);
+    let text = play(Box::new(IterSource::new(lines(&["open mailbox"]))));
+    input::set_end_of_input(EndOfInput::Exit);
+
+    assert!(text.ends_with(">Saved.\nThe game is over.\n\n"));
+    assert!(dir.path().join(files::SAVE_FILE).exists());
+}
+
+#[test]
+fn test_settings_while_reading() {
+    let _guard = lock();
+    let (sender, receiver) = mpsc::channel();
+    let previous = input::set_source(Box::new(ChannelSource::new(receiver)));
+
+    // The reader waits for a line; the settings can still be used meanwhile.
+    let reader = thread::spawn(input::read_line);
+    assert!(input::shows_prompt());
+    assert_eq!(input::set_end_of_input(EndOfInput::SaveAndExit), EndOfInput::Exit);
+    assert_eq!(input::set_end_of_input(EndOfInput::Exit), EndOfInput::SaveAndExit);
+
+    sender.send("look".to_string()).unwrap();
+    assert_eq!(reader.join().unwrap().unwrap(), Some("look".to_string()));
+    input::set_source(previous);
+}
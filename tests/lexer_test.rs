@@ -0,0 +1,119 @@
+#![allow(static_mut_refs)]
+
+extern crate libc;
+extern crate zork;
+
+mod common;
+
+use std::ffi::CString;
+use libc::c_int;
+use zork::ffi::{self, Logical};
+use zork::replacement::np::{self, LexError, MAX_WORDS};
+use zork::replacement::vocab;
+use zork::Game;
+use common::{lock, same_as_c, Random};
+
+type Lexer = extern "C-unwind" fn(*const libc::c_char, *mut c_int, *mut c_int, Logical) -> Logical;
+
+extern "C-unwind" fn c_lex(input: *const libc::c_char, output: *mut c_int, op: *mut c_int,
+                    vbflag: Logical) -> Logical {
+    unsafe { ffi::c_lex_(input, output, op, vbflag) }
+}
+
+// Runs a lexer on the command at `start` (counting from 1, like prscon).
+// Returns whether it succeeded, its output and op, and the new prscon.
+fn run(lexer: Lexer, input: &str, start: c_int) -> (bool, [c_int; 40], c_int, c_int) {
+    let input = CString::new(input).unwrap();
+    let mut output = [-1; 40];
+    let mut op = -1;
+    unsafe {
+        ffi::parse_vec.parse_continue = start;
+    }
+    let lexed = lexer(input.as_ptr(), output.as_mut_ptr(), &mut op, Logical::FALSE).is_true();
+    (lexed, output, op, unsafe { ffi::parse_vec.parse_continue })
+}
+
+// Checks that both lexers read every command on the line the same way. What
+// a lexer leaves in its output and op only counts if it succeeded.
+fn compare(input: &str) {
+    let mut start = 1;
+    for _ in 0..input.len() + 1 {
+        let lex = |lexer: Lexer| {
+            let (lexed, output, op, next) = run(lexer, input, start);
+            (lexed, if lexed { Some((output, op)) } else { None }, next)
+        };
+        let (lexed, _, next) = same_as_c((input, start), || lex(c_lex), || lex(np::lex_));
+        if !lexed || next == 1 {
+            return;
+        }
+        start = next;
+    }
+    panic!("{:?} never ended", input);
+}
+
+#[test]
+fn test_same_as_c() {
+    let _guard = lock();
+    let inputs = [
+        "TAKE LAMP", "N", "OPEN THE MAILBOX", "  LOOK  ", "TAKE LAMP,N.OPEN DOOR",
+        "DISASSEMBLE THE ENCYCLOPEDIA", "ABCDEFGHIJKLMNOPQRSTUVWXYZ", "X-1 Y-9 ---",
+        "TAKE LAMP.", "TAKE LAMP,,N", ".", "", "GO 0", "take lamp", "TAKE LAMP!", "A B C. D",
+        "A B C D E F G H I J K L M N O P Q R S T", "ZZZZZZ 999999 ------",
+    ];
+    for input in inputs.iter() {
+        compare(input);
+    }
+
+    // And some random ones, short enough for the C lexer.
+    let alphabet = b"AEZ-19 0.,!";
+    let mut random = Random::new(12345);
+    for _ in 0..2000 {
+        let input: String = (0..random.below(40))
+            .map(|_| alphabet[random.below(alphabet.len())] as char)
+            .collect();
+        compare(&input);
+    }
+}
+
+#[test]
+fn test_words() {
+    let (lexed, next) = np::lex(b"DISASSEMBLE THE ENCYCLOPEDIA, N", 0);
+    let lexed = lexed.unwrap();
+    assert_eq!(lexed.words, vec!["DISASSEMBLE", "THE", "ENCYCLOPEDIA"]);
+    assert_eq!(lexed.tokens.len(), 6);
+    assert_eq!(&lexed.tokens[..2], &vocab::encode("DISASS").unwrap()[..]);
+    assert_eq!(next, 29);
+
+    assert_eq!(vocab::encode("A"), Some([1600, 0]));
+    assert_eq!(vocab::encode("ZORK1"), Some([26 * 1600 + 15 * 40 + 18, 11 * 1600 + 31 * 40]));
+    assert_eq!(vocab::encode("ZORK0"), None);
+    assert_eq!(vocab::encode(""), None);
+
+    assert_eq!(np::lex(b"TAKE 0", 0).0, Err(LexError::BadCharacter(b'0')));
+    assert_eq!(np::lex(b" , N", 0), (Err(LexError::Empty), 2));
+}
+
+#[test]
+fn test_too_many_words() {
+    let _guard = lock();
+    let words = vec!["A"; MAX_WORDS + 1].join(" ");
+    assert_eq!(np::lex(words.as_bytes(), 0).0, Err(LexError::TooManyWords));
+    let (lexed, output, _, _) = run(np::lex_, &words, 1);
+    assert!(!lexed);
+    assert!(output.iter().all(|&token| token == 0));
+
+    let words = vec!["A"; MAX_WORDS].join(" ");
+    assert_eq!(np::lex(words.as_bytes(), 0).0.unwrap().words.len(), MAX_WORDS);
+}
+
+#[test]
+fn test_game() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    let long = vec!["A"; 38].join(" ");
+    assert!(game.step(&long).unwrap().text.contains("I don't understand that."));
+
+    // The full words of the last command are kept.
+    assert!(game.step("open mailbox").unwrap().text.contains("Opening the mailbox reveals:"));
+    assert_eq!(np::last_words(), vec!["OPEN", "MAILBOX"]);
+}
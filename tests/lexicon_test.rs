@@ -0,0 +1,104 @@
+extern crate zork;
+
+use std::process::Command;
+use zork::lexicon::{self, WordKind};
+use zork::replacement::vocab;
+
+#[test]
+fn test_lookup() {
+    let lookup = lexicon::lookup("lanterns").unwrap();
+    assert_eq!(lookup.truncated, "LANTER");
+    assert_eq!(lookup.tokens, vocab::encode("lanter").unwrap());
+    assert_eq!(lookup.meanings.len(), 1);
+    assert_eq!(lookup.meanings[0].kind, WordKind::Object);
+    assert_eq!(lookup.meanings[0].lines, ["15 (LAMP), 16 (BLAMP), 22"]);
+    assert_eq!(lookup.collisions(), ["LANTERN"]);
+
+    // The parser tries the direction first.
+    let lookup = lexicon::lookup("Down").unwrap();
+    assert_eq!(lookup.word, "DOWN");
+    let kinds: Vec<WordKind> = lookup.meanings.iter().map(|meaning| meaning.kind).collect();
+    assert_eq!(kinds, [WordKind::Direction, WordKind::Preposition]);
+    assert!(lookup.collisions().is_empty());
+
+    let lookup = lexicon::lookup("put").unwrap();
+    assert_eq!(lookup.meanings[0].lines[..4], [
+        "PUT, INSERT, STUFF, PLACE",
+        "PUT <direct> IN <indirect>: action 144, the one to fall back on",
+        "    direct object: standard (search adventurer, search room, reachable)",
+        "    indirect object: search adventurer, search room, reachable",
+    ]);
+
+    // The lexer doesn't take '0', so these are never read.
+    let lookup = lexicon::lookup("pdp10").unwrap();
+    assert!(!lookup.readable);
+    assert_eq!(lookup.meanings[0].kind, WordKind::Object);
+    assert!(!lookup.meanings[0].readable);
+    assert!(lookup.to_string().contains("the game never reads PDP10"), "{}", lookup);
+    assert!(!lexicon::lookup("200").unwrap().readable);
+    assert!(lexicon::lookup("lantern0").unwrap().meanings[0].readable);
+    assert!(lexicon::lookup("lanterns").unwrap().readable);
+
+    assert!(lexicon::lookup("xyzzy").unwrap().meanings.is_empty());
+    assert_eq!(lexicon::lookup(""), None);
+    assert_eq!(lexicon::lookup("don't"), None);
+}
+
+#[test]
+fn test_every_word() {
+    // Every word can be found again.
+    for &kind in WordKind::ALL.iter() {
+        let listing = lexicon::listing(kind);
+        let word = listing.split_whitespace().next().unwrap().trim_end_matches(',');
+        let lookup = lexicon::lookup(word).unwrap();
+        assert!(lookup.meanings.iter().any(|meaning| meaning.kind == kind), "{}", word);
+    }
+}
+
+#[test]
+fn test_listing() {
+    assert_eq!(WordKind::from_section("verbs"), Some(WordKind::Verb));
+    assert_eq!(WordKind::from_section("verb"), None);
+    assert_eq!(WordKind::Buzzword.to_string(), "buzzword");
+
+    let verbs = lexicon::listing(WordKind::Verb);
+    assert!(verbs.contains("\nTAKE, GET, HOLD, CARRY, REMOVE\n    TAKE <direct>: action 132\n        \
+                            direct object: search adventurer, search room, reachable, \
+                            prefers TAKEBT|TRYBT\n"), "{}", verbs);
+    assert!(verbs.contains("(its entry has only 4 slots"));
+
+    let directions = lexicon::listing(WordKind::Direction);
+    assert!(directions.starts_with("N            1 (north)\n"), "{}", directions);
+    assert_eq!(lexicon::listing(WordKind::Buzzword).lines().count(), vocab::BUZZWORDS.len());
+
+    let objects = lexicon::listing(WordKind::Object);
+    assert!(objects.contains("\nPDP10        7 (MACHI)\n    (the lexer doesn't take '0'"),
+            "{}", objects);
+}
+
+#[test]
+fn test_tool() {
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-vocab"))
+        .args(["--search", "lanterns", "--decode", "26335,5005"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let text = String::from_utf8(output.stdout).unwrap();
+    assert!(text.starts_with("PROCEE\n\nLANTERNS is LANTER to the parser"), "{}", text);
+    assert!(text.contains("LANTERNS is the same word as LANTERN."), "{}", text);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-vocab")).arg("prepositions").output().unwrap();
+    let text = String::from_utf8(output.stdout).unwrap();
+    assert!(text.starts_with("Prepositions:\nOVER         1 (OVER)\n"), "{}", text);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-vocab")).arg("--collisions").output().unwrap();
+    assert!(output.status.success());
+    let text = String::from_utf8(output.stdout).unwrap();
+    assert!(text.contains("\n    direction DOWN, preposition DOWN\n"), "{}", text);
+    assert!(text.contains("\n    verb LIGHT, object LIGHT\n"), "{}", text);
+    assert_eq!(text.lines().count(), lexicon::collisions().len() + 1, "{}", text);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-vocab")).arg("nouns").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Not a section: nouns"));
+}
@@ -0,0 +1,107 @@
+extern crate serde_json;
+extern crate tempfile;
+extern crate zork;
+
+mod common;
+
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+use zork::ffi;
+use zork::map::{Map, MapExitKind};
+use zork::travel::{self, ExitKind, TravelError};
+use zork::Game;
+use common::lock;
+
+fn build() -> Map {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    let map = Map::build().unwrap();
+
+    // Building the map doesn't print anything.
+    assert!(!game.step("look").unwrap().text.contains("North of House"));
+    map
+}
+
+#[test]
+fn test_rooms() {
+    let map = build();
+    let west = &map.rooms[1];
+    assert_eq!(west.id, 2);
+    assert_eq!(west.name.as_deref(), Some("WHOUS"));
+    assert_eq!(west.description, "West of House");
+    assert_eq!(west.exits[0].direction, "north");
+    assert_eq!(west.exits[0].target, Some(3));
+
+    let east = &west.exits[3];
+    assert_eq!(east.kind, MapExitKind::No);
+    assert_eq!(east.target, None);
+    assert_eq!(east.message.as_ref().unwrap(), "The door is locked, and there is evidently no key.");
+
+    let troll_room = map.rooms.iter().find(|room| room.description == "Troll Room").unwrap();
+    let exit = troll_room.exits.iter().find(|exit| exit.kind == MapExitKind::Conditional).unwrap();
+    assert_eq!(exit.flag.as_ref().unwrap(), "trollf");
+}
+
+#[test]
+fn test_dot() {
+    let dot = build().to_dot();
+    assert!(dot.starts_with("digraph dungeon {\n"));
+    assert!(dot.contains("    r2 [label=\"West of House\"];\n"));
+    assert!(dot.contains("    r2 -> r3 [label=\"north\"];\n"));
+    assert!(dot.contains("    r5 -> r6 [label=\"west (window)\", style=bold];\n"));
+    assert!(dot.contains("[label=\"east (if trollf)\", style=dashed];\n"));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn test_json() {
+    let map = build();
+    let json: serde_json::Value = serde_json::from_str(&map.to_json()).unwrap();
+    assert_eq!(json["rooms"][1]["description"], "West of House");
+    assert_eq!(json["rooms"][4]["exits"][3]["kind"], "door");
+    assert_eq!(json["rooms"][4]["exits"][3]["door_description"], "window");
+
+    let read: Map = serde_json::from_str(&map.to_json()).unwrap();
+    assert_eq!(read, map);
+}
+
+#[test]
+fn test_no_such_door() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+
+    // Take the window out of the west exit from Behind House.
+    let window = travel::room_exits(5).unwrap()[3];
+    let door = match window.kind {
+        ExitKind::Door(door, _) => door,
+        kind => panic!("{:?}", kind),
+    };
+    let table = unsafe { ffi::exits.travel };
+    let index = (1..table.len())
+        .find(|&index| travel::decode(&table, index).ok().and_then(|exits| exits.first().cloned()) == Some(window))
+        .unwrap();
+    unsafe { ffi::exits.travel[index + 1] -= door };
+    let result = Map::build();
+    unsafe { ffi::exits.travel[index + 1] += door };
+    assert_eq!(result, Err(TravelError::NoSuchDoor(5, 0)));
+}
+
+#[test]
+fn test_tool() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("map.json");
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-map"))
+        .args(["--format", "json", "--output"])
+        .arg(&path)
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(json["rooms"][1]["description"], "West of House");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zork-map")).arg("--format=svg").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Not a valid format: svg"));
+}
@@ -0,0 +1,62 @@
+#![allow(static_mut_refs)]
+
+extern crate zork;
+
+mod common;
+
+use zork::ffi;
+use zork::ffi::objects::{ObjectFlags1, ObjectFlags2};
+use zork::Game;
+use common::lock;
+
+// The small mailbox at West of House.
+const MAILBOX: usize = 53;
+
+#[test]
+fn test_object_flags() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let mailbox = unsafe { ffi::objects.get(MAILBOX) };
+
+    assert!(mailbox.is_visible());
+    assert!(mailbox.is_container());
+    assert!(!mailbox.is_takeable());
+    assert!(!mailbox.is_open());
+    assert_eq!(mailbox.flags_1 & ObjectFlags1::all(), ObjectFlags1::VISIBT | ObjectFlags1::CONTBT);
+    assert_eq!(format!("{:?}", mailbox.flags_1), "VISIBT|CONTBT");
+    assert_eq!(format!("{:?}", mailbox.flags_2), "(empty)");
+
+    let leaflet = unsafe { ffi::objects.iter().find(|object| object.container == MAILBOX as i32) }
+        .expect("something is in the mailbox");
+    assert!(leaflet.is_takeable());
+    assert!(leaflet.is_readable());
+    assert!(leaflet.is_burnable());
+}
+
+#[test]
+fn test_set_flags() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    let mut mailbox = unsafe { ffi::objects.get(MAILBOX) };
+    mailbox.set_open(true);
+    assert!(mailbox.flags_2.contains(ObjectFlags2::OPENBT));
+    unsafe { ffi::objects.set(&mailbox) };
+    assert_eq!(unsafe { ffi::objects.flags_2[MAILBOX - 1] }, ObjectFlags2::OPENBT.bits());
+
+    // The game sees the mailbox as open.
+    let text = game.step("take leaflet").unwrap().text;
+    assert!(text.contains("Taken."), "{}", text);
+
+    mailbox.set_open(false);
+    mailbox.set_visible(false);
+    assert!(!mailbox.is_open());
+    assert!(!mailbox.is_visible());
+    assert!(mailbox.is_container());
+}
+
+#[test]
+fn test_flags_debug() {
+    assert_eq!(format!("{:?}", ObjectFlags1::TAKEBT | ObjectFlags1::VISIBT), "VISIBT|TAKEBT");
+    assert_eq!(format!("{:?}", ObjectFlags2::from_bits_retain(-32768)), "FINDBT");
+    assert_eq!(format!("{:?}", ObjectFlags2::from_bits_retain(1 << 20)), "0x100000");
+}
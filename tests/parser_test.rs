@@ -0,0 +1,179 @@
+#![allow(static_mut_refs)]
+
+extern crate libc;
+extern crate zork;
+
+mod common;
+
+use libc::c_int;
+use zork::ffi::{self, Logical, Orphans, ParsedWords};
+use zork::replacement::np;
+use zork::replacement::np1::{self, SparseError, Sparsed};
+use zork::replacement::vocab;
+use zork::Game;
+use common::{lock, same_as_c, Random};
+
+type Parser = extern "C-unwind" fn(*const c_int, c_int, Logical) -> c_int;
+
+extern "C-unwind" fn c_sparse(lbuf: *const c_int, llnt: c_int, vbflag: Logical) -> c_int {
+    unsafe { ffi::c_sparse_(lbuf, llnt, vbflag) }
+}
+
+// What a parser returned, and what it left in pv_, orphs_ and prsvec_.
+#[derive(Debug, PartialEq)]
+struct Outcome {
+    result: c_int,
+    parsed: ParsedWords,
+    orphans: Orphans,
+    action: c_int,
+    direct: c_int,
+}
+
+fn lex(command: &str) -> (Vec<c_int>, Vec<String>) {
+    let lexed = np::lex(command.as_bytes(), 0).0.unwrap();
+    (lexed.tokens, lexed.words)
+}
+
+// Runs a parser on the command, starting from `orphans`.
+fn run(parser: Parser, tokens: &[c_int], orphans: &Orphans) -> Outcome {
+    unsafe {
+        ffi::orphans = orphans.clone();
+        ffi::parse_vec.parse_a = 0;
+        ffi::parse_vec.parse_o = 0;
+        let result = parser(tokens.as_ptr(), tokens.len() as c_int - 1, Logical::FALSE);
+        Outcome {
+            result,
+            parsed: ffi::parsed_words.clone(),
+            orphans: ffi::orphans.clone(),
+            action: ffi::parse_vec.parse_a,
+            direct: ffi::parse_vec.parse_o,
+        }
+    }
+}
+
+// Checks that both parsers make the same of each command, each one starting
+// from the orphans the one before left. Returns how many commands each
+// parser result was seen for.
+fn compare(commands: &[String]) -> [usize; 3] {
+    let mut seen = [0; 3];
+    let mut orphans = unsafe { ffi::orphans.clone() };
+    let saved = orphans.clone();
+    for command in commands {
+        let (tokens, _) = lex(command);
+        let outcome = same_as_c((command, &orphans), || run(c_sparse, &tokens, &orphans),
+                                || run(np1::sparse_, &tokens, &orphans));
+        seen[(outcome.result + 1) as usize] += 1;
+        orphans = outcome.orphans;
+    }
+    unsafe {
+        ffi::orphans = saved;
+    }
+    seen
+}
+
+#[test]
+fn test_arrays() {
+    let [buzzwords, prepositions, directions, adjectives, verbs, objects] = ffi::vocab_arrays();
+    assert_eq!(vocab::buzzword_array(), buzzwords);
+    assert_eq!(vocab::verb_array(), verbs);
+    assert_eq!(vocab::direction_array(), directions);
+    assert_eq!(vocab::preposition_array(), prepositions);
+    assert_eq!(vocab::adjective_array(), adjectives);
+    assert_eq!(vocab::object_array(), objects);
+
+    assert_eq!(vocab::encode("proceed"), Some([26335, 5005]));
+    assert_eq!(vocab::encode("PDP10"), None);
+    assert_eq!(vocab::table_tokens("PDP10"), [25776, 50800]);
+    assert!(!vocab::is_readable([25776, 50800]));
+    assert!(vocab::is_readable([26335, 5005]));
+    assert_eq!(vocab::encode("h2o!"), None);
+    assert_eq!(vocab::decode([26335, 5005]), "PROCEE");
+    assert_eq!(vocab::decode([14400, 0]), "I");
+}
+
+#[test]
+fn test_same_as_c() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    // Words of every kind, some that aren't words, and some that name more
+    // than one thing.
+    let pool = [
+        "TAKE", "OPEN", "WALK", "GO", "THE", "AND", "N", "NORTH", "UP", "IN", "INTO", "WITH",
+        "OF", "ON", "AT", "PICK", "PUT", "LOOK", "GIVE", "MAILBOX", "LEAFLET", "DOOR", "HOUSE",
+        "LAMP", "LANTERN", "BRASS", "SWORD", "CASE", "TROPHY", "RUG", "IT", "BROWN", "SMALL",
+        "WHITE", "FRONT", "WINDOW", "KITCHEN", "BOTTLE", "GLASS", "WATER", "SACK", "XYZZY",
+        "FROBNITZ", "ALL", "LIGHT", "TRAP", "TRAP-DOOR", "CELLAR", "KNIFE", "ROPE", "WALL",
+    ];
+    let mut random = Random::new(4321);
+    let mut seen = [0; 3];
+
+    // Outside, inside with things lying around, in the dark, and in the dark
+    // with the lamp on.
+    let places: &[&[&str]] = &[
+        &[],
+        &["n", "e", "open window", "enter", "w", "move rug"],
+        &["open trap door", "d"],
+        &["u", "take lamp", "turn on lamp", "d"],
+    ];
+    for moves in places {
+        for command in moves.iter() {
+            game.step(command).unwrap();
+        }
+        let mut commands = vec![
+            "TAKE LAMP", "OPEN THE MAILBOX", "N", "WALK N", "WALK WITH N", "TAKE N", "PICK LAMP UP",
+            "PUT LEAFLET IN MAILBOX", "TAKE LAMP OF LAMP", "OF LAMP", "TAKE WITH WITH", "BROWN",
+            "TAKE BROWN", "MAILBOX", "THE", "TAKE LAMP SWORD CASE", "TAKE IT", "LOOK AT",
+            "GIVE", "XYZZY", "TAKE XYZZY",
+        ].iter().map(|command| command.to_string()).collect::<Vec<_>>();
+        for _ in 0..1500 {
+            let words = (0..1 + random.below(5)).map(|_| pool[random.below(pool.len())]).collect::<Vec<_>>();
+            commands.push(words.join(" "));
+        }
+        let counts = compare(&commands);
+        for (total, count) in seen.iter_mut().zip(counts.iter()) {
+            *total += count;
+        }
+    }
+    assert!(seen.iter().all(|&count| count > 0), "{:?}", seen);
+}
+
+#[test]
+fn test_errors() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let sparse = |command: &str| {
+        let (tokens, words) = lex(command);
+        np1::sparse(&tokens, &words)
+    };
+
+    assert_eq!(sparse("TAKE FROBNITZ"), Err(SparseError::UnknownWord("FROBNITZ".to_string())));
+    assert_eq!(sparse("OPEN THE MAILBOX"), Ok(Sparsed::Verb));
+    assert_eq!(unsafe { ffi::parsed_words.object_1 }, 53);
+    assert_eq!(sparse("NORTH"), Ok(Sparsed::Walk(1024)));
+    assert_eq!(sparse("TAKE LANTERN"), Err(SparseError::NotHere("LANTERN".to_string())));
+    assert_eq!(sparse("TAKE WITH ON"), Err(SparseError::TooManyPrepositions("ON".to_string())));
+    assert_eq!(sparse("OPEN BROWN"), Err(SparseError::DanglingAdjective("BROWN".to_string())));
+    assert_eq!(sparse("MAILBOX"), Err(SparseError::NoVerb(53)));
+    assert_eq!(sparse("THE"), Err(SparseError::Nothing));
+
+    // Like C, this fails even though it completes the orphan.
+    assert_eq!(sparse("LOOK AT"), Ok(Sparsed::Verb));
+    assert_eq!(sparse("MAILBOX"), Err(SparseError::OrphanObject));
+    assert_eq!(unsafe { ffi::parsed_words.object_1 }, 53);
+
+    // Words the lexer didn't read are shown as their tokens.
+    let tokens = vocab::encode("TAKE").unwrap().iter()
+        .chain(&vocab::encode("ENCYCLOPEDIA").unwrap()).cloned().collect::<Vec<_>>();
+    assert_eq!(np1::sparse(&tokens, &[]), Err(SparseError::UnknownWord("ENCYCL".to_string())));
+}
+
+#[test]
+fn test_game() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    assert!(game.step("take frobnitz").unwrap().text.contains("I don't understand that."));
+    assert!(game.step("take lantern").unwrap().text.contains("I can't see one here."));
+    assert!(game.step("mailbox").unwrap().text.contains("What should I do with the mailbox?"));
+    assert!(game.step("open").unwrap().text.contains("Opening the mailbox reveals:"));
+}
@@ -0,0 +1,59 @@
+extern crate zork;
+
+mod common;
+
+use zork::{random, recording, Game};
+use zork::replacement::supp::rnd_;
+use common::lock;
+
+#[test]
+fn test_same_seed_same_numbers() {
+    let _guard = lock();
+    random::set_seed(1234);
+    assert_eq!(random::seed(), 1234);
+    let first: Vec<i32> = (0..100).map(|_| rnd_(100)).collect();
+
+    random::set_seed(1234);
+    let second: Vec<i32> = (0..100).map(|_| rnd_(100)).collect();
+    assert_eq!(first, second);
+    assert!(first.iter().all(|&n| (0..100).contains(&n)));
+
+    random::set_seed(4321);
+    let third: Vec<i32> = (0..100).map(|_| rnd_(100)).collect();
+    assert_ne!(first, third);
+}
+
+#[test]
+fn test_bad_maxval() {
+    let _guard = lock();
+    assert_eq!(rnd_(0), 0);
+    assert_eq!(rnd_(-5), 0);
+    assert_eq!(rnd_(1), 0);
+}
+
+// SWIM away from water picks one of three jokes at random.
+fn play_seeded(seed: u64) -> Vec<String> {
+    random::set_seed(seed);
+    let mut game = Game::new().unwrap();
+    (0..20).map(|_| game.step("swim").unwrap().text).collect()
+}
+
+#[test]
+fn test_seeded_game_repeats() {
+    let _guard = lock();
+    let first = play_seeded(99);
+    assert_eq!(first, play_seeded(99));
+
+    let mut jokes = first.clone();
+    jokes.sort();
+    jokes.dedup();
+    assert!(jokes.len() > 1);
+}
+
+#[test]
+fn test_parse_seed() {
+    assert_eq!(recording::parse_seed("# seed: 42"), Some(42));
+    assert_eq!(recording::parse_seed("# seed: 42\r"), Some(42));
+    assert_eq!(recording::parse_seed("open mailbox"), None);
+    assert_eq!(recording::parse_seed("# seed: many"), None);
+}
@@ -39,23 +39,23 @@ fn test_replays() {
 
 fn test_replay(in_path: &PathBuf, out_path: &PathBuf, dirs: &Dirs) {
     // Run the test.
-    let output_res = replay_test::run_playback_test(&in_path, &dirs);
+    let output_res = replay_test::run_playback_test(in_path, dirs);
     
     // Copy the output logs over, before running anything that might panic.
-    replay_test::copy_logs(&dirs).unwrap();
+    replay_test::copy_logs(dirs).unwrap();
 
     let output = output_res.unwrap();
 
 
     // Read the replay output.
     let mut expected_output = String::new();
-    File::open(&out_path).unwrap().read_to_string(&mut expected_output).unwrap();
+    File::open(out_path).unwrap().read_to_string(&mut expected_output).unwrap();
 
     // Compare them.
     if output != expected_output {
         // Try to make a pretty diff.
         if output.len() > 50 {
-            match run_diff(&dirs, &output, &expected_output) {
+            match run_diff(dirs, &output, &expected_output) {
                 // If there was an error, fall through.
                 Err(e) => println!("Error while creating diff: {:?}", e),
                 Ok(diff_output) => {
@@ -88,7 +88,7 @@ fn run_diff(dirs: &Dirs, expected: &str, actual: &str) -> Result<String, io::Err
         .output()?;
     
 
-    return Ok(String::from_utf8_lossy(&child_output.stdout).into_owned());
+    Ok(String::from_utf8_lossy(&child_output.stdout).into_owned())
 }
 
 
@@ -0,0 +1,82 @@
+#![allow(static_mut_refs)]
+
+extern crate zork;
+
+mod common;
+
+use zork::ffi;
+use zork::ffi::rooms::RoomFlags;
+use zork::Game;
+use common::lock;
+
+// West of House.
+const WHOUS: usize = 2;
+// North of House.
+const NHOUS: usize = 3;
+
+#[test]
+fn test_room_flags() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let room = unsafe { ffi::rooms.get(WHOUS) };
+
+    assert_eq!(room.get_id(), WHOUS);
+    assert!(room.is_lit());
+    assert!(room.is_seen());
+    assert!(room.flags.contains(RoomFlags::RLAND));
+    assert!(!room.flags.contains(RoomFlags::RWATER));
+    assert!(!room.is_endgame());
+
+    let debug = format!("{:?}", room.flags);
+    assert!(debug.contains("RSEEN"), "{}", debug);
+    assert!(debug.contains("RLIGHT"), "{}", debug);
+}
+
+#[test]
+fn test_seen_after_moving() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    assert!(!unsafe { ffi::rooms.get(NHOUS) }.is_seen());
+
+    game.step("north").unwrap();
+    assert!(unsafe { ffi::rooms.get(NHOUS) }.is_seen());
+}
+
+#[test]
+fn test_iter() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let rooms = unsafe { &ffi::rooms };
+
+    assert!(!rooms.is_empty());
+    assert_eq!(rooms.iter().count(), rooms.len());
+    let ids: Vec<usize> = rooms.iter().map(|room| room.get_id()).collect();
+    assert_eq!(ids, (1..rooms.len() + 1).collect::<Vec<_>>());
+    assert!(rooms.iter().any(|room| room.is_endgame()));
+}
+
+#[test]
+fn test_set() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    let mut room = unsafe { ffi::rooms.get(NHOUS) };
+    room.flags.insert(RoomFlags::RSEEN);
+    unsafe { ffi::rooms.set(&room) };
+
+    assert!(unsafe { ffi::rooms.get(NHOUS) }.is_seen());
+    assert_eq!(unsafe { ffi::rooms.flags[NHOUS - 1] }, room.flags.bits());
+
+    // Seen rooms get the short description.
+    game.step("verbose").unwrap();
+    game.step("brief").unwrap();
+    let text = game.step("north").unwrap().text;
+    assert!(!text.contains("facing the north side"), "{}", text);
+}
+
+#[test]
+fn test_flags_debug() {
+    let flags = RoomFlags::RLIGHT | RoomFlags::RSEEN;
+    assert_eq!(format!("{:?}", flags), "RSEEN|RLIGHT");
+    assert_eq!(format!("{:?}", RoomFlags::empty()), "(empty)");
+    assert_eq!(format!("{:?}", RoomFlags::from_bits_retain(1 | 16)), "REND|0x1");
+}
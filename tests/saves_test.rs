@@ -0,0 +1,212 @@
+extern crate tempfile;
+extern crate zork;
+
+mod common;
+
+use std::fs::{self, File, OpenOptions};
+use std::path::Path;
+use tempfile::TempDir;
+use zork::files;
+use zork::saves::{self, SaveFile, FORMAT_NAME, FORMAT_VERSION};
+use zork::Game;
+use common::lock;
+
+// Saved by the C version after "open mailbox", "take leaflet" and "north", on
+// a little-endian machine.
+const LEGACY_SAVE: &str = "tests/data/legacy_dsave.dat";
+const FORMAT_1_SAVE: &str = "tests/data/format_1_dsave.dat";
+const FORMAT_2_SAVE: &str = "tests/data/format_2_dsave.dat";
+
+// Starts a game that keeps its saves in a new directory.
+fn start() -> (Game, TempDir) {
+    let dir = TempDir::new().unwrap();
+    files::set_save_dir(dir.path());
+    (Game::new().unwrap(), dir)
+}
+
+#[test]
+fn test_named_save_and_restore() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    game.step("open mailbox").unwrap();
+    game.step("take leaflet").unwrap();
+    let turn = game.step("save Leaflet").unwrap();
+    assert_eq!(turn.text, "Saved as \"leaflet\".\n");
+    // Like SAVE, it counts as a move.
+    assert_eq!(turn.moves, 3);
+    assert!(dir.path().join("leaflet.sav").exists());
+
+    game.step("drop leaflet").unwrap();
+    game.step("north").unwrap();
+    let turn = game.step("restore leaflet").unwrap();
+    assert_eq!(turn.text, "Restored.\n");
+    assert_eq!(turn.room, 2);
+
+    let turn = game.step("inventory").unwrap();
+    assert!(turn.text.contains("A leaflet."));
+}
+
+#[test]
+fn test_default_slot() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    assert_eq!(game.step("save").unwrap().text, "Saved.\n");
+    assert!(dir.path().join("dsave.dat").exists());
+    game.step("north").unwrap();
+    assert_eq!(game.step("restore").unwrap().room, 2);
+}
+
+#[test]
+fn test_list_saves() {
+    let _guard = lock();
+    let (mut game, _dir) = start();
+
+    assert_eq!(game.step("saves").unwrap().text, "There are no saved games.\n");
+
+    game.step("save start").unwrap();
+    game.step("north").unwrap();
+    game.step("save").unwrap();
+
+    let text = game.step("saves").unwrap().text;
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", text);
+    assert!(lines.iter().any(|line| line.starts_with("start ") && line.contains("Score: 0")));
+    assert!(lines.iter().any(|line| line.starts_with("(default) ")));
+    assert!(text.contains("West of House"));
+    assert!(text.contains("North of House"));
+}
+
+#[test]
+fn test_missing_save() {
+    let _guard = lock();
+    let (mut game, _dir) = start();
+
+    let text = game.step("restore nothing").unwrap().text;
+    assert!(text.ends_with("There is no saved game called \"nothing\".\n"), "{}", text);
+}
+
+#[test]
+fn test_corrupt_save() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    game.step("save broken").unwrap();
+    let path = dir.path().join("broken.sav");
+    let length = fs::metadata(&path).unwrap().len();
+    OpenOptions::new().write(true).open(&path).unwrap().set_len(length / 2).unwrap();
+
+    let text = game.step("restore broken").unwrap().text;
+    assert!(text.contains("The saved game is damaged: EOF while parsing"), "{}", text);
+
+    let text = game.step("saves").unwrap().text;
+    assert!(text.starts_with("broken ") && text.ends_with("(damaged)\n"), "{}", text);
+}
+
+#[test]
+fn test_bad_name() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    let text = game.step("save ../escape").unwrap().text;
+    assert!(text.contains("\"../escape\" can't be used as the name of a saved game."), "{}", text);
+    assert!(!dir.path().parent().unwrap().join("escape.sav").exists());
+}
+
+#[test]
+fn test_save_format() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    game.step("open mailbox").unwrap();
+    game.step("save").unwrap();
+
+    let save_file = SaveFile::read(&mut File::open(dir.path().join("dsave.dat")).unwrap()).unwrap();
+    assert_eq!(save_file.format, FORMAT_NAME);
+    assert_eq!(save_file.format_version, FORMAT_VERSION);
+    // SAVE itself counts as a move.
+    assert_eq!(save_file.game.state.game_state.moves, 2);
+    assert_eq!(save_file.game.state.player.current_room, 2);
+}
+
+#[test]
+fn test_old_formats() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    // Saved after "open mailbox" and "take leaflet" by versions of the game
+    // that wrote versions 1 and 2 of the format. Version 1 also kept the time
+    // the session started.
+    for &(path, version) in &[(FORMAT_1_SAVE, 1), (FORMAT_2_SAVE, 2)] {
+        assert!(fs::read_to_string(path).unwrap()
+                .starts_with(&format!("{{\"format\":\"zork-save\",\"format_version\":{},", version)));
+        fs::copy(path, dir.path().join("dsave.dat")).unwrap();
+
+        game.step("north").unwrap();
+        let turn = game.step("restore").unwrap();
+        assert_eq!(turn.text, "Restored.\n");
+        assert_eq!(turn.room, 2);
+        assert!(game.step("inventory").unwrap().text.contains("A leaflet."));
+    }
+
+    // They're read as the current format.
+    let save_file = SaveFile::read(&mut File::open(FORMAT_1_SAVE).unwrap()).unwrap();
+    assert_eq!(save_file.format_version, FORMAT_VERSION);
+    assert_eq!(save_file.game.state.game_state.moves, 3);
+    assert!(save_file.into_game().is_ok());
+}
+
+#[test]
+fn test_checksum() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    game.step("save cheat").unwrap();
+    let path = dir.path().join("cheat.sav");
+    let text = fs::read_to_string(&path).unwrap();
+    fs::write(&path, text.replace("\"deaths\":0", "\"deaths\":-5")).unwrap();
+
+    let text = game.step("restore cheat").unwrap().text;
+    assert!(text.ends_with("The saved game is damaged: the checksum doesn't match.\n"), "{}", text);
+}
+
+#[test]
+fn test_different_data() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    game.step("save other").unwrap();
+    let path = dir.path().join("other.sav");
+    let mut save_file = SaveFile::read(&mut File::open(&path).unwrap()).unwrap();
+    save_file.data_hash ^= 1;
+    save_file.write(&mut File::create(&path).unwrap()).unwrap();
+
+    let text = game.step("restore other").unwrap().text;
+    assert!(text.ends_with("The game was played with a different text database.\n"), "{}", text);
+}
+
+#[test]
+fn test_restore_legacy_save() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+    fs::copy(LEGACY_SAVE, dir.path().join("dsave.dat")).unwrap();
+
+    let turn = game.step("restore").unwrap();
+    assert_eq!(turn.text, "Restored.\n");
+    assert_eq!(turn.moves, 4);
+    assert!(game.step("inventory").unwrap().text.contains("A leaflet."));
+}
+
+#[test]
+fn test_import_legacy_save() {
+    let _guard = lock();
+    let (mut game, dir) = start();
+
+    let path = saves::import_legacy(Path::new(LEGACY_SAVE), Some("old")).unwrap();
+    assert_eq!(path, dir.path().join("old.sav"));
+    assert!(fs::read_to_string(&path).unwrap().starts_with('{'));
+
+    assert_eq!(game.step("restore old").unwrap().text, "Restored.\n");
+    assert!(game.step("look").unwrap().text.starts_with("You are facing the north side of a white house."));
+}
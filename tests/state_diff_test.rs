@@ -0,0 +1,95 @@
+extern crate log;
+extern crate zork;
+
+mod common;
+
+use std::sync::Mutex;
+use log::{LevelFilter, Log, Metadata, Record};
+use zork::ffi::Logical;
+use zork::state::GameState;
+use zork::state_diff;
+use zork::Game;
+use common::lock;
+
+// Keeps the messages logged to the state_diff target.
+struct DiffLogger {
+    messages: Mutex<Vec<String>>,
+}
+
+static LOGGER: DiffLogger = DiffLogger { messages: Mutex::new(Vec::new()) };
+
+impl Log for DiffLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target() == state_diff::LOG_TARGET
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.messages.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn test_diff() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    let before = GameState::capture();
+    assert!(state_diff::diff(&before, &before).is_empty());
+
+    game.step("open mailbox").unwrap();
+    game.step("take leaflet").unwrap();
+    game.step("north").unwrap();
+    let changes = state_diff::diff(&before, &GameState::capture());
+
+    assert!(changes.contains(&"adventurer 1 (PLAYER) aroom 2 -> 3".to_string()), "{:#?}", changes);
+    assert!(changes.contains(&"play.here 2 -> 3".to_string()));
+    assert!(changes.contains(&"state.moves 0 -> 3".to_string()));
+    assert!(changes.iter().any(|line| line.starts_with("object ") && line.contains("oadv 0 -> 1")));
+}
+
+#[test]
+fn test_flag_names() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let before = GameState::capture();
+    let mut after = before.clone();
+    after.game_flags.flags[0] = Logical::TRUE;
+    after.game_flags.switches[0] = 7;
+
+    let changes = state_diff::diff(&before, &after);
+    assert_eq!(changes, vec!["findex.trollf false -> true".to_string(),
+                             format!("findex.btief {} -> 7", before.game_flags.switches[0])]);
+}
+
+#[test]
+fn test_log_turn() {
+    let _guard = lock();
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let mut game = Game::new().unwrap();
+    game.step("north").unwrap();
+    game.step("look").unwrap();
+    drop(game);
+    log::set_max_level(LevelFilter::Off);
+
+    let messages = LOGGER.messages.lock().unwrap();
+    assert!(messages.iter().any(|message| {
+        message.starts_with("Turn 1:\n") && message.contains("\n  play.here 2 -> 3\n")
+    }), "{:#?}", messages);
+}
+
+#[test]
+fn test_object_flag_names() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    let before = GameState::capture();
+    game.step("open mailbox").unwrap();
+    let changes = state_diff::diff(&before, &GameState::capture());
+
+    // The mailbox.
+    assert!(changes.contains(&"object 53 oflag2 (empty) -> OPENBT".to_string()), "{:#?}", changes);
+}
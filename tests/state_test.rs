@@ -0,0 +1,43 @@
+extern crate serde_json;
+extern crate zork;
+
+mod common;
+
+use zork::state::GameState;
+use zork::Game;
+use common::lock;
+
+#[test]
+fn test_capture_and_restore() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    let start = GameState::capture();
+    assert_eq!(start.player.current_room, 2);
+
+    game.step("open mailbox").unwrap();
+    game.step("take leaflet").unwrap();
+    let later = GameState::capture();
+    assert_ne!(later, start);
+    assert_eq!(later.game_state.moves, 2);
+    assert_eq!(later.clone(), later);
+
+    start.restore();
+    assert_eq!(GameState::capture(), start);
+    assert!(game.step("inventory").unwrap().text.contains("You are empty handed."));
+}
+
+#[test]
+fn test_serialize() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    game.step("north").unwrap();
+
+    let state = GameState::capture();
+    let json = serde_json::to_string(&state).unwrap();
+    let read: GameState = serde_json::from_str(&json).unwrap();
+    assert_eq!(read, state);
+
+    // Flags are written as booleans.
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(value["player"]["tel_flag"].is_boolean());
+}
@@ -0,0 +1,163 @@
+#![allow(static_mut_refs)]
+
+extern crate libc;
+extern crate zork;
+
+mod common;
+
+use libc::c_int;
+use zork::ffi::{self, Logical};
+use zork::output;
+use zork::replacement::np;
+use zork::replacement::np1::{self, Sparsed};
+use zork::replacement::np3::{self, SyntaxError, TakeError};
+use zork::replacement::vocab;
+use zork::state::GameState;
+use zork::state_diff;
+use zork::Game;
+use common::{lock, same_as_c, Random};
+
+type Matcher = extern "C-unwind" fn() -> Logical;
+
+extern "C-unwind" fn c_synmch() -> Logical {
+    unsafe { ffi::c_synmch_() }
+}
+
+// Parses the command into pv_, as parse_() does before it calls synmch_().
+// Returns false if there is nothing for synmch_() to match.
+fn sparse(command: &str) -> bool {
+    let lexed = match np::lex(command.to_uppercase().as_bytes(), 0).0 {
+        Ok(lexed) => lexed,
+        Err(_) => return false,
+    };
+    unsafe {
+        ffi::parse_vec.parse_a = 0;
+        ffi::parse_vec.parse_o = 0;
+        ffi::parse_vec.parse_i = 0;
+    }
+    np1::sparse(&lexed.tokens, &lexed.words) == Ok(Sparsed::Verb)
+}
+
+// Runs a syntax matcher from the game `parsed`, and returns whether it
+// matched, what it printed, and how it changed the game.
+fn run(matcher: Matcher, parsed: &GameState) -> (bool, String, Vec<String>) {
+    parsed.restore();
+    let mut matched = false;
+    let text = output::capture(|| matched = matcher().is_true());
+    (matched, text, state_diff::diff(parsed, &GameState::capture()))
+}
+
+// Checks that both matchers make the same of the command, from the current
+// game, and then puts the game back. Returns whether they matched, or None if
+// the command didn't get that far.
+fn compare(command: &str) -> Option<bool> {
+    let saved = GameState::capture();
+    if !sparse(command) {
+        saved.restore();
+        return None;
+    }
+    let parsed = GameState::capture();
+    let (matched, _, _) = same_as_c((command, &parsed.parsed_words), || run(c_synmch, &parsed),
+                                    || run(np3::synmch_, &parsed));
+    saved.restore();
+    Some(matched)
+}
+
+#[test]
+fn test_replay_same_as_c() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    // Words to make up commands from.
+    let verbs = [
+        "TAKE", "DROP", "OPEN", "CLOSE", "READ", "LIGHT", "TURN", "PUT", "GIVE", "ATTACK",
+        "UNLOCK", "MOVE", "EAT", "DRINK", "FILL", "THROW", "EXAMINE", "TIE", "DIG", "CLIMB",
+        "WAVE", "BURN", "KILL", "PICK", "INFLATE", "POUR", "POKE", "LOOK", "PUMP",
+    ];
+    let others = [
+        "ON", "OFF", "IN", "WITH", "AT", "TO", "UP", "FROM", "UNDER", "LAMP", "SWORD",
+        "LEAFLET", "MAILBOX", "BOTTLE", "WATER", "SACK", "CASE", "RUG", "DOOR", "WINDOW",
+        "KNIFE", "ROPE", "GARLIC", "LUNCH", "TABLE", "HOUSE", "TRAP", "IT", "ME",
+    ];
+    let mut random = Random::new(2718);
+
+    // A walk through the start of the game, with commands that leave out
+    // their objects, leave orphans, and take things on the way.
+    let walkthrough = [
+        "open", "mailbox", "read leaflet", "take", "drop leaflet", "read", "take leaflet",
+        "n", "e", "open", "window", "enter", "take", "sack", "open sack", "take garlic",
+        "eat", "lunch", "drink water", "take bottle", "fill bottle", "pour water", "w",
+        "take lamp", "turn on lamp", "turn off lamp", "light", "lamp", "take sword",
+        "attack", "move rug", "open trap door", "put leaflet in case", "put", "garlic",
+        "case", "give garlic", "tie rope", "turn on lamp", "d", "kill", "throw garlic at",
+        "sword", "take garlic", "e", "dig", "climb", "w", "s", "take garlic",
+    ];
+    let mut seen = [0; 2];
+    for command in walkthrough.iter() {
+        if let Some(matched) = compare(command) {
+            seen[matched as usize] += 1;
+        }
+
+        // And some made up ones, from the same place.
+        for _ in 0..40 {
+            let mut words = vec![verbs[random.below(verbs.len())]];
+            words.extend((0..random.below(4)).map(|_| others[random.below(others.len())]));
+            if let Some(matched) = compare(&words.join(" ")) {
+                seen[matched as usize] += 1;
+            }
+        }
+
+        game.step(command).unwrap();
+    }
+    assert!(seen.iter().all(|&count| count > 10), "{:?}", seen);
+}
+
+#[test]
+fn test_errors() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+    let synmch = |command: &str| {
+        assert!(sparse(command), "{:?}", command);
+        np3::synmch()
+    };
+
+    assert_eq!(synmch("open mailbox"), Ok(()));
+    assert_eq!(unsafe { ffi::parse_vec.parse_o }, 53);
+    assert_eq!(synmch("open"), Err(SyntaxError::MissingDirect(0)));
+    assert_eq!(synmch("open mailbox mailbox"), Err(SyntaxError::NoSyntax));
+
+    game.step("open mailbox").unwrap();
+    game.step("take leaflet").unwrap();
+    game.step("n").unwrap();
+    game.step("e").unwrap();
+    game.step("open window").unwrap();
+    game.step("enter").unwrap();
+    game.step("w").unwrap();
+    game.step("take lamp").unwrap();
+    assert_eq!(synmch("put leaflet"), Err(SyntaxError::MissingIndirect(0)));
+    assert_eq!(synmch("drop rug"), Err(SyntaxError::Take(TakeError::InRoom(17))));
+    assert!(game.step("drop rug").unwrap().text.contains("You don't have the carpet."));
+
+    // "read" takes the thing it reads, if it's lying here.
+    game.step("drop leaflet").unwrap();
+    let text = game.step("read leaflet").unwrap().text;
+    assert!(text.starts_with("Taken."), "{:?}", text);
+}
+
+#[test]
+fn test_syntaxes() {
+    // "pump" runs into "put", so its indirect object is made of put's words.
+    let pump = vocab::VERBS.iter().find(|verb| verb.names == ["pump"]).unwrap();
+    assert_eq!(pump.read_syntaxes().len(), 1);
+    assert_eq!(pump.read_syntaxes()[0].slot_len(), 7);
+
+    let put = np1::verb(vocab::VERBS.iter().take_while(|verb| verb.names[0] != "put")
+        .map(|verb| {
+            let mut array = Vec::new();
+            verb.encode(&mut array);
+            array.len() as c_int
+        })
+        .sum::<c_int>() + 1).unwrap();
+    assert_eq!(put.names[0], "put");
+    assert_eq!(put.read_syntaxes(), put.syntaxes);
+}
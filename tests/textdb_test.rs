@@ -0,0 +1,85 @@
+#![allow(static_mut_refs)]
+
+extern crate zork;
+
+mod common;
+
+use zork::ffi;
+use zork::textdb::{self, TextDb};
+use zork::Game;
+use common::lock;
+
+const KEY: &[u8] = b"IanLanceTaylorJr";
+
+// Encodes messages the way dtextc.dat does, each at a multiple of 8 bytes.
+fn encode(messages: &[&str]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for message in messages {
+        data.extend(message.bytes());
+        data.push(0);
+        while data.len() % 8 != 0 {
+            data.push(0);
+        }
+    }
+    data.iter().enumerate().map(|(x, &byte)| byte ^ KEY[x & 0xf] ^ (x & 0xff) as u8).collect()
+}
+
+#[test]
+fn test_decode() {
+    let mut data = b"header".to_vec();
+    data.extend(encode(&["Hello, #.", "sailor", "Bye"]));
+    // Message 1 is "Hello, #."; message 2 is "Bye", 24 bytes in.
+    let db = TextDb::new(&data, 6, vec![-1, -4, 0]);
+
+    assert_eq!(db.message(1), "Hello, #.");
+    assert_eq!(db.message(2), "Bye");
+    assert_eq!(db.message(-3), "sailor");
+    assert_eq!(db.message(3), "");
+    assert_eq!(db.message(0), "");
+    assert_eq!(db.get(4), None);
+    assert_eq!(db.get(-100), None);
+
+    assert_eq!(db.substitute(1, &[-3]), "Hello, sailor.");
+    assert_eq!(db.substitute(1, &[]), "Hello, #.");
+    assert_eq!(db.substitute(1, &[0, -3]), "Hello, #.");
+
+    assert!(db.is_blank(0));
+    assert!(db.is_blank(3));
+    assert!(!db.is_blank(1));
+    assert!(!db.is_blank(-3));
+
+    // The lookup comes first, and each `#` takes the next substitution.
+    let lookup = |id| if id == 2 { Some("Ahoy".to_string()) } else { None };
+    let mut substitutions = vec![2, -3].into_iter();
+    assert_eq!(db.substitute_text("#, ", &mut substitutions, lookup), "Ahoy, ");
+    assert_eq!(db.substitute_text("#!#", &mut substitutions, lookup), "sailor!#");
+}
+
+#[test]
+fn test_game_messages() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let db = textdb::current();
+
+    let west_of_house = unsafe { ffi::rooms.get(2) };
+    assert_eq!(db.message(west_of_house.description_2), "West of House");
+    // "... flood control dam #3 ..." has no substitution.
+    assert!(db.message(66).contains("dam #3,"));
+
+    let lamp = unsafe { ffi::objects.get(15) }.description_2;
+    let object = unsafe { ffi::objects.get(53) }.description_2;
+    assert_eq!(db.substitute(378, &[lamp, object]),
+               format!("Kicking a {} {}.", db.message(lamp), db.message(object)));
+    assert_eq!(db.substitute(378, &[lamp]), format!("Kicking a {} #.", db.message(lamp)));
+}
+
+#[test]
+fn test_rspeak() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    // The window behind the house is substituted into the room description.
+    game.step("north").unwrap();
+    let text = game.step("east").unwrap().text;
+    assert!(text.contains("there is a window which is slightly ajar."), "{}", text);
+}
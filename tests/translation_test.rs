@@ -0,0 +1,78 @@
+extern crate zork;
+
+mod common;
+
+use zork::datafile::DataFile;
+use zork::files;
+use zork::translation::{self, Translation, TranslationError};
+use zork::Game;
+use common::lock;
+
+// Plays the moves with a translation, and returns what the last one printed.
+fn play(source: &str, moves: &[&str]) -> String {
+    let _guard = lock();
+    translation::set(Some(Translation::from_toml(source).unwrap()));
+    let mut game = Game::new().unwrap();
+    let mut text = String::new();
+    for command in moves {
+        text = game.step(command).unwrap().text;
+    }
+    drop(game);
+    translation::set(None);
+    text
+}
+
+#[test]
+fn test_messages() {
+    // 419 is "Time passes...".
+    let text = play("[messages]\n419 = \"Die Zeit vergeht...\"\n", &["wait"]);
+    assert!(text.contains("Die Zeit vergeht..."));
+
+    // Untranslated messages are in English.
+    let text = play("[messages]\n419 = \"Die Zeit vergeht...\"\n", &["open mailbox"]);
+    assert!(text.contains("Opening the mailbox reveals:"));
+}
+
+#[test]
+fn test_substitutions() {
+    // 410 is "Opening the # reveals:", with the mailbox's name in the #.
+    let file = DataFile::read(files::BUILT_IN_DATA).unwrap();
+    let mailbox = file.objects[52].description_2;
+    let source = format!("[messages]\n410 = \"Im # liegt:\"\n{} = \"kleinen Briefkasten\"\n", mailbox);
+    let text = play(&source, &["open mailbox"]);
+    assert!(text.contains("Im kleinen Briefkasten liegt:"), "{}", text);
+}
+
+#[test]
+fn test_strings() {
+    let source = r#"
+[strings]
+"Your score " = "Ihr Punktestand "
+"is" = "ist"
+" %d [total of %d points], in %d move" = " %d [von %d Punkten], nach %d Zug"
+"#;
+    let text = play(source, &["score"]);
+    assert!(text.contains("Ihr Punktestand ist 0 [von 585 Punkten], nach 1 Zug."), "{}", text);
+
+    let text = play("", &["score"]);
+    assert!(text.contains("Your score is 0 [total of 585 points], in 1 move."), "{}", text);
+}
+
+#[test]
+fn test_errors() {
+    let err = Translation::from_toml("[messages]\nzero = \"nichts\"\n").unwrap_err();
+    assert_eq!(err, TranslationError::BadMessage("zero".to_string()));
+
+    // Replacements must take the same arguments.
+    let err = Translation::from_toml("[strings]\n\"%d minute\" = \"%s Minute\"\n").unwrap_err();
+    assert_eq!(err, TranslationError::BadString("%d minute".to_string()));
+    let err = Translation::from_toml("[strings]\n\"%.6s\" = \"%s\"\n").unwrap_err();
+    assert_eq!(err, TranslationError::BadString("%.6s".to_string()));
+    let err = Translation::from_toml("[strings]\n\"%d\" = \"%d %d\"\n").unwrap_err();
+    assert_eq!(err, TranslationError::BadString("%d".to_string()));
+    assert!(Translation::from_toml("[strings]\n\"%1d hour\" = \"%-3d Stunde, 100%%\"\n").is_ok());
+
+    let translation = Translation::from_toml("[messages]\n-5 = \"Fertig.\"\n").unwrap();
+    assert_eq!(translation.message(-5), Some("Fertig."));
+    assert_eq!(translation.message(5), None);
+}
@@ -0,0 +1,118 @@
+#![allow(static_mut_refs)]
+
+extern crate zork;
+
+mod common;
+
+use zork::ffi;
+use zork::travel::{self, Direction, Exit, ExitKind, TravelError};
+use zork::Game;
+use common::lock;
+
+const WHOUS: usize = 2;
+const NHOUS: usize = 3;
+const SHOUS: usize = 4;
+const EHOUS: usize = 5;
+const KITCH: usize = 6;
+const FORE1: usize = 31;
+const WINDO: i32 = 63;
+
+fn exit(direction: Direction, kind: ExitKind, target: usize) -> Exit {
+    Exit { direction, kind, target: target as i32, action: 0 }
+}
+
+#[test]
+fn test_west_of_house() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let exits = travel::room_exits(WHOUS).unwrap();
+
+    assert_eq!(&exits[..3], &[
+        exit(Direction::North, ExitKind::Normal, NHOUS),
+        exit(Direction::South, ExitKind::Normal, SHOUS),
+        exit(Direction::West, ExitKind::Normal, FORE1),
+    ]);
+    // "The door is locked, and there is evidently no key."
+    assert_eq!(exits[3].direction, Direction::East);
+    assert_eq!(exits[3].target, 0);
+    match exits[3].kind {
+        ExitKind::No(message) => assert_ne!(message, 0),
+        kind => panic!("expected a no exit, got {:?}", kind),
+    }
+    assert_eq!(exits.len(), 4);
+}
+
+#[test]
+fn test_door() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let exits = travel::room_exits(EHOUS).unwrap();
+
+    assert!(exits.contains(&exit(Direction::West, ExitKind::Door(WINDO, 0), KITCH)), "{:?}", exits);
+    assert!(exits.contains(&exit(Direction::Enter, ExitKind::Door(WINDO, 0), KITCH)), "{:?}", exits);
+}
+
+// Every exit the decoder finds is the one findxt_() finds.
+#[test]
+fn test_matches_findxt() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    let mut conditional = 0;
+
+    for room in 1..unsafe { ffi::rooms.len() } + 1 {
+        let exits = travel::room_exits(room).unwrap();
+        for &direction in Direction::ALL.iter() {
+            let found = unsafe { ffi::findxt_(direction.code(), room as i32) }.is_true();
+            let exit = exits.iter().find(|exit| exit.direction == direction);
+            assert_eq!(found, exit.is_some(), "room {} {:?}", room, direction);
+            let exit = match exit {
+                Some(exit) => exit,
+                None => continue,
+            };
+
+            let current = unsafe { &ffi::current_exit };
+            assert_eq!(exit.target, current.room, "room {} {:?}", room, direction);
+            let (kind, message, object) = match exit.kind {
+                ExitKind::Normal => (1, None, None),
+                ExitKind::No(message) => (2, Some(message), None),
+                ExitKind::Conditional(flag, message) => {
+                    conditional += 1;
+                    (3, Some(message), Some(flag))
+                }
+                ExitKind::Door(object, message) => (4, Some(message), Some(object)),
+            };
+            assert_eq!(kind, current.kind, "room {} {:?}", room, direction);
+            if let Some(message) = message {
+                assert_eq!(message, current.message, "room {} {:?}", room, direction);
+            }
+            if let Some(object) = object {
+                assert_eq!(object, current.object, "room {} {:?}", room, direction);
+                assert_eq!(exit.action, current.action, "room {} {:?}", room, direction);
+            }
+        }
+    }
+    assert!(conditional > 0);
+}
+
+#[test]
+fn test_decode() {
+    // North to room 3, then (last) a door east to room 6 through object 63,
+    // with message 100 and action 2.
+    let travel = [1024 | 3, 3072 | (3 << 8) | 6 | 32768, 100, (2 << 8) | 63];
+    assert_eq!(travel::decode(&travel, 1), Ok(vec![
+        exit(Direction::North, ExitKind::Normal, 3),
+        Exit { direction: Direction::East, kind: ExitKind::Door(63, 100), target: 6, action: 2 },
+    ]));
+    assert_eq!(travel::decode(&travel, 0), Ok(vec![]));
+    assert_eq!(travel::decode(&travel[..3], 1), Err(TravelError::OutOfRange(2)));
+    assert_eq!(travel::decode(&[3 | 32768], 1), Err(TravelError::BadDirection(1, 0)));
+    assert_eq!(Direction::from_code(9216), Some(Direction::Up));
+    assert_eq!(Direction::Up.name(), "up");
+}
+
+#[test]
+fn test_no_such_room() {
+    let _guard = lock();
+    let _game = Game::new().unwrap();
+    assert_eq!(travel::room_exits(0), Err(TravelError::NoSuchRoom(0)));
+}
@@ -0,0 +1,105 @@
+extern crate zork;
+
+mod common;
+
+use zork::state::GameState;
+use zork::state_diff;
+use zork::Game;
+use common::lock;
+
+#[test]
+fn test_undo_one_move() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    game.step("open mailbox").unwrap();
+    game.step("take leaflet").unwrap();
+    let turn = game.step("undo").unwrap();
+    assert_eq!(turn.text, "Took back 1 move.\n");
+    assert_eq!(turn.moves, 1);
+
+    assert!(game.step("inventory").unwrap().text.contains("You are empty handed."));
+    assert_eq!(game.step("take leaflet").unwrap().text, "Taken.\n");
+}
+
+#[test]
+fn test_undo_several_moves() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    game.step("north").unwrap();
+    game.step("east").unwrap();
+    assert_ne!(game.step("open window").unwrap().room, 2);
+
+    let turn = game.step("undo 3").unwrap();
+    assert_eq!(turn.text, "Took back 3 moves.\n");
+    assert_eq!(turn.room, 2);
+    assert_eq!(turn.moves, 0);
+
+    // The history is used up.
+    assert_eq!(game.step("undo").unwrap().text, "There is nothing to undo.\n");
+}
+
+#[test]
+fn test_undo_whole_state() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    game.step("north").unwrap();
+    let before = GameState::capture();
+    game.step("east").unwrap();
+    game.step("open window").unwrap();
+    game.step("undo 2").unwrap();
+
+    // The snapshot is taken after the line is read, so it has the undone
+    // command's line. The next line read replaces it.
+    let mut after = GameState::capture();
+    after.input_buffer = before.input_buffer.clone();
+    let changes = state_diff::diff(&before, &after);
+    assert!(changes.is_empty(), "{:?}", changes);
+}
+
+#[test]
+fn test_undo_commands_on_one_line() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    game.step("open mailbox").unwrap();
+    game.step("take leaflet, north, east").unwrap();
+
+    // Each command on the line is a move of its own.
+    let turn = game.step("undo").unwrap();
+    assert_eq!(turn.text, "Took back 1 move.\n");
+    assert_ne!(turn.room, 2);
+    assert!(game.step("inventory").unwrap().text.contains("A leaflet."));
+
+    let turn = game.step("undo 3").unwrap();
+    assert_eq!(turn.text, "Took back 3 moves.\n");
+    assert_eq!(turn.room, 2);
+    assert!(game.step("inventory").unwrap().text.contains("You are empty handed."));
+}
+
+#[test]
+fn test_undo_too_many() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    assert_eq!(game.step("undo").unwrap().text, "There is nothing to undo.\n");
+    game.step("north").unwrap();
+    game.step("north").unwrap();
+    assert_eq!(game.step("undo 5").unwrap().text, "Only 2 moves can be taken back.\n");
+    assert_eq!(game.step("undo two").unwrap().text,
+               "UNDO takes the number of moves to take back, such as UNDO 3.\n");
+    assert_eq!(game.step("undo 2").unwrap().room, 2);
+}
+
+#[test]
+fn test_new_game_forgets_history() {
+    let _guard = lock();
+    let mut game = Game::new().unwrap();
+
+    game.step("north").unwrap();
+    drop(game);
+    let mut game = Game::new().unwrap();
+    assert_eq!(game.step("undo").unwrap().text, "There is nothing to undo.\n");
+}
@@ -0,0 +1,51 @@
+extern crate libc;
+extern crate zork;
+
+use std::fs;
+use libc::c_int;
+use zork::ffi;
+use zork::replacement::vocab;
+
+// The numbers in an array of parse.h as it was before the arrays were
+// generated. `declaration` is what comes just before the array's initializer.
+fn old_array(parse_h: &str, declaration: &str) -> Vec<c_int> {
+    let start = parse_h.find(declaration).unwrap_or_else(|| panic!("no {}", declaration));
+    let rest = &parse_h[start..];
+    let rest = &rest[rest.find("= {").unwrap() + 3..];
+    let body = &rest[..rest.find('}').unwrap()];
+
+    let mut numbers = Vec::new();
+    for line in body.lines() {
+        let line = line.split("//").next().unwrap();
+        numbers.extend(line.split(',').map(str::trim).filter(|number| !number.is_empty())
+            .map(|number| number.parse::<c_int>().unwrap()));
+    }
+    numbers
+}
+
+#[test]
+fn test_generated_arrays() {
+    let parse_h = fs::read_to_string("tests/data/parse.h").unwrap();
+    let old = [
+        old_array(&parse_h, "} buzvoc_"),
+        old_array(&parse_h, "} prpvoc_"),
+        old_array(&parse_h, "} dirvoc_"),
+        old_array(&parse_h, "integer avoc[]"),
+        old_array(&parse_h, "integer vvoc[]"),
+        old_array(&parse_h, "integer ovoc[]"),
+    ];
+    assert_eq!(old.iter().map(Vec::len).collect::<Vec<_>>(), vec![20, 45, 75, 405, 935, 1019]);
+
+    // The arrays the C code was built with, from vocab.h.
+    let generated = ffi::vocab_arrays();
+    for (old, generated) in old.iter().zip(generated.iter()) {
+        assert_eq!(&old[..], *generated);
+    }
+
+    assert_eq!(old[0], vocab::buzzword_array());
+    assert_eq!(old[1], vocab::preposition_array());
+    assert_eq!(old[2], vocab::direction_array());
+    assert_eq!(old[3], vocab::adjective_array());
+    assert_eq!(old[4], vocab::verb_array());
+    assert_eq!(old[5], vocab::object_array());
+}
@@ -0,0 +1,100 @@
+extern crate zork;
+
+mod common;
+
+use zork::lexicon::WordKind;
+use zork::vocabulary::{self, VocabularyError, VocabularyPack};
+use zork::Game;
+use common::lock;
+
+const GERMAN: &str = r#"
+[buzzwords]
+den = "the"
+
+[verbs]
+"öffne" = "open"
+nimm = "take"
+lies = "read"
+aufheben = "pick up"
+licht = "light"
+
+[directions]
+norden = "north"
+
+[objects]
+briefkasten = "mailbox"
+zettel = "leaflet"
+licht = "lamp"
+"#;
+
+#[test]
+fn test_rewrite() {
+    let pack = VocabularyPack::from_toml(GERMAN).unwrap();
+    assert_eq!(pack.english(WordKind::Direction, "Norden"), Some("NORTH"));
+    assert_eq!(pack.english(WordKind::Verb, "Norden"), None);
+    assert_eq!(pack.rewrite("öFFNE DEN BRIEFKASTEN"), "OPEN THE MAILBOX");
+    assert_eq!(pack.rewrite("NIMM ZETTEL,LIES ZETTEL. NORDEN"), "TAKE LEAFLET,READ LEAFLET. NORTH");
+    assert_eq!(pack.rewrite("ZETTEL AUFHEBEN"), "LEAFLET PICK UP");
+
+    // A word that is a verb too is only read as one where the parser takes
+    // a verb.
+    assert_eq!(pack.rewrite("LICHT LICHT"), "LIGHT LAMP");
+    assert_eq!(pack.rewrite("NIMM LICHT. LICHT"), "TAKE LAMP. LIGHT");
+    assert_eq!(pack.rewrite("TAKE LICHT"), "TAKE LAMP");
+
+    // Words that aren't in the pack are left alone.
+    assert_eq!(pack.rewrite("OPEN  WINDOW "), "OPEN  WINDOW ");
+}
+
+#[test]
+fn test_game() {
+    let _guard = lock();
+    vocabulary::set(Some(VocabularyPack::from_toml(GERMAN).unwrap()));
+    let mut game = Game::new().unwrap();
+    let opened = game.step("öffne den briefkasten").unwrap().text;
+    let taken = game.step("nimm zettel").unwrap().text;
+    let north = game.step("norden").unwrap().text;
+    drop(game);
+    vocabulary::set(None);
+
+    assert!(opened.contains("Opening the mailbox reveals:"), "{}", opened);
+    assert!(taken.contains("Taken."), "{}", taken);
+    assert!(north.contains("You are facing the north side of a white house."), "{}", north);
+}
+
+#[test]
+fn test_unknown_word() {
+    let _guard = lock();
+    vocabulary::set(Some(VocabularyPack::from_toml(GERMAN).unwrap()));
+    let mut game = Game::new().unwrap();
+    let unknown = game.step("nimm schlüssel").unwrap();
+    let opened = game.step("öffne den briefkasten").unwrap().text;
+    drop(game);
+    vocabulary::set(None);
+
+    // A word with an umlaut that isn't in the pack doesn't end the game.
+    assert_eq!(unknown.text, "I don't understand that.\n");
+    assert!(opened.contains("Opening the mailbox reveals:"), "{}", opened);
+}
+
+#[test]
+fn test_errors() {
+    let err = VocabularyPack::from_toml("[verbs]\n\"nimm mit\" = \"take\"\n").unwrap_err();
+    assert_eq!(err, VocabularyError::BadWord("nimm mit".to_string()));
+
+    let err = VocabularyPack::from_toml("[verbs]\nnimm = \"täke\"\n").unwrap_err();
+    assert_eq!(err, VocabularyError::BadEnglish("nimm".to_string()));
+
+    let err = VocabularyPack::from_toml("[verbs]\nnimm = \"xyzzq\"\n").unwrap_err();
+    assert_eq!(err, VocabularyError::WrongKind("nimm".to_string(), WordKind::Verb));
+    let err = VocabularyPack::from_toml("[objects]\nnimm = \"take\"\n").unwrap_err();
+    assert_eq!(err, VocabularyError::WrongKind("nimm".to_string(), WordKind::Object));
+
+    let err = VocabularyPack::from_toml("[adjectives]\nlicht = \"brass\"\n\
+                                         [objects]\nlicht = \"lamp\"\n")
+        .unwrap_err();
+    assert_eq!(err, VocabularyError::Conflict("licht".to_string()));
+
+    let err = VocabularyPack::from_toml("[nouns]\nlicht = \"lamp\"\n").unwrap_err();
+    assert!(matches!(err, VocabularyError::Toml(_)));
+}
@@ -1,4 +1,3 @@
-#![feature(never_type)]
 
 ///
 /// This binary will first go to the root directory (../../). Then, it will copy
@@ -14,6 +13,7 @@ extern crate itertools;
 extern crate regex;
 extern crate tempdir;
 extern crate timeout_readwrite;
+extern crate zork;
 
 #[macro_use]
 extern crate failure_derive;
@@ -34,6 +34,7 @@ use std::process::{Child, Command, Stdio};
 use std::time::Duration;
 use tempdir::TempDir;
 use timeout_readwrite::{TimeoutReader, TimeoutWriter};
+use zork::recording;
 
 type Result<T> = std::result::Result<T, failure::Error>;
 
@@ -389,8 +390,17 @@ pub fn run_playback_test(playback_path: &PathBuf, dirs: &Dirs) -> Result<String>
         lines.remove(len - 1);
     }
 
+    // The first line may record the random seed the game was played with.
+    // Older files don't have it, and are replayed with a fixed seed.
+    let seed = lines.first().and_then(|line| recording::parse_seed(line));
+    if seed.is_some() {
+        lines.remove(0);
+    }
+    let args = ["--seed".to_string(), seed.unwrap_or(recording::LEGACY_SEED).to_string()];
+
     // Run zork to get the output.
     let mut child = Command::new(&dirs.executable_path)
+        .args(&args)
         .current_dir(&dirs.zork_run_dir)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
@@ -575,7 +585,7 @@ fn get_id_numbers(paths: &Vec<PathBuf>) -> Result<Vec<usize>> {
     Ok(numbers)
 }
 
-fn find_missing_replay_file(in_paths: &Vec<PathBuf>, out_paths: &Vec<PathBuf>) -> Result<!> {
+fn find_missing_replay_file(in_paths: &Vec<PathBuf>, out_paths: &Vec<PathBuf>) -> Result<()> {
     let mut in_numbers = get_id_numbers(&in_paths)?;
     let mut out_numbers = get_id_numbers(&out_paths)?;
     in_numbers.sort();